@@ -20,7 +20,7 @@ use std::sync::Arc;
 
 use tokio::sync::RwLock;
 #[cfg(feature = "multi_instance_runtime")]
-use ylong_runtime::builder::RuntimeBuilder;
+use ylong_runtime::builder::{RuntimeBuilder, ScheduleAlgo};
 #[cfg(feature = "multi_instance_runtime")]
 use ylong_runtime::executor::Runtime;
 #[cfg(feature = "sync")]
@@ -56,6 +56,14 @@ pub fn ylong_runtime_set_threads(threads_count: usize) -> Runtime {
         .unwrap()
 }
 
+#[cfg(feature = "multi_instance_runtime")]
+pub fn ylong_runtime_set_schedule_algo(schedule_algo: ScheduleAlgo) -> Runtime {
+    RuntimeBuilder::new_multi_thread()
+        .schedule_algo(schedule_algo)
+        .build()
+        .unwrap()
+}
+
 pub fn tokio_runtime() -> tokio::runtime::Runtime {
     tokio::runtime::Builder::new_multi_thread()
         .enable_all()
@@ -95,3 +95,35 @@ mod ylong_schedule_bench {
     ylong_schedule_task!(ylong_task_10_30, 10, 30);
     ylong_schedule_task!(ylong_task_120_30, 120, 30);
 }
+
+#[cfg(all(test, feature = "multi_instance_runtime"))]
+mod ylong_schedule_algo_bench {
+    extern crate test;
+
+    use std::hint::black_box;
+
+    use test::Bencher;
+    use ylong_runtime::builder::ScheduleAlgo;
+    use ylong_runtime::task::yield_now;
+
+    pub use crate::task_helpers::{fibbo, ylong_runtime_set_schedule_algo};
+
+    tokio_schedule_task!(
+        ylong_runtime_set_schedule_algo(ScheduleAlgo::LifoSlot),
+        ylong_lifo_slot_task_120_15,
+        120,
+        15
+    );
+    tokio_schedule_task!(
+        ylong_runtime_set_schedule_algo(ScheduleAlgo::FifoGlobal),
+        ylong_fifo_global_task_120_15,
+        120,
+        15
+    );
+    tokio_schedule_task!(
+        ylong_runtime_set_schedule_algo(ScheduleAlgo::RoundRobin { budget: 8 }),
+        ylong_round_robin_task_120_15,
+        120,
+        15
+    );
+}
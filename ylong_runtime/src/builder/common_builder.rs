@@ -13,8 +13,9 @@
 
 cfg_not_ffrt! {
     use std::time::Duration;
-    use crate::builder::CallbackHook;
-    use crate::builder::ScheduleAlgo;
+    use crate::builder::WorkerHook;
+    use crate::builder::{BlockingRejectPolicy, PanicPolicy, ScheduleAlgo, TaskPanicHook};
+    use crate::task::coop::DEFAULT_BUDGET;
     const BLOCKING_PERMANENT_THREAD_NUM: u8 = 0;
 }
 
@@ -39,15 +40,29 @@ pub(crate) struct CommonBuilder {
     /// Core affinity, default set to true
     pub(crate) is_affinity: bool,
 
+    /// Cpus that the blocking threads are restricted to, `None` means no
+    /// restriction
+    pub(crate) blocking_cpu_set: Option<Vec<usize>>,
+
     /// How long the blocking thread will be kept alive after becoming idle
     pub(crate) keep_alive_time: Option<Duration>,
 
     /// Maximum thread number for blocking thread pool
     pub(crate) max_blocking_pool_size: Option<u8>,
 
+    /// Maximum number of tasks waiting inside the blocking queue, `None` means
+    /// unbounded
+    pub(crate) blocking_queue_size: Option<usize>,
+
+    /// Policy applied when the blocking queue is full
+    pub(crate) blocking_reject_policy: BlockingRejectPolicy,
+
     /// Schedule policy, default set to FIFO
     pub(crate) schedule_algo: ScheduleAlgo,
 
+    /// Budget of each poll of a task, `None` means unconstrained
+    pub(crate) task_budget: Option<u32>,
+
     /// Maximum number of permanent threads
     pub(crate) blocking_permanent_thread_num: u8,
 
@@ -55,10 +70,25 @@ pub(crate) struct CommonBuilder {
     pub(crate) stack_size: Option<usize>,
 
     /// A callback function to be called after starting a worker thread
-    pub(crate) after_start: Option<CallbackHook>,
+    pub(crate) after_start: Option<WorkerHook>,
 
     /// A callback function to be called before stopping a worker thread
-    pub(crate) before_stop: Option<CallbackHook>,
+    pub(crate) before_stop: Option<WorkerHook>,
+
+    /// A callback function to be called before an async worker parks
+    pub(crate) on_park: Option<WorkerHook>,
+
+    /// A callback function to be called after an async worker unparks
+    pub(crate) on_unpark: Option<WorkerHook>,
+
+    /// Policy applied when a task panics
+    pub(crate) panic_policy: PanicPolicy,
+
+    /// A callback function to be called when a task panics
+    pub(crate) task_panic_hook: Option<TaskPanicHook>,
+
+    /// Seed of the random numbers used by the workers, `None` means random
+    pub(crate) rng_seed: Option<u64>,
 }
 
 #[cfg(feature = "ffrt")]
@@ -77,13 +107,22 @@ impl CommonBuilder {
         CommonBuilder {
             worker_name: None,
             is_affinity: false,
+            blocking_cpu_set: None,
             blocking_permanent_thread_num: BLOCKING_PERMANENT_THREAD_NUM,
             max_blocking_pool_size: None,
+            blocking_queue_size: None,
+            blocking_reject_policy: BlockingRejectPolicy::Error,
             schedule_algo: ScheduleAlgo::FifoBound,
+            task_budget: Some(DEFAULT_BUDGET),
             stack_size: None,
             after_start: None,
             before_stop: None,
+            on_park: None,
+            on_unpark: None,
+            panic_policy: PanicPolicy::Ignore,
+            task_panic_hook: None,
             keep_alive_time: None,
+            rng_seed: None,
         }
     }
 }
@@ -94,7 +133,8 @@ macro_rules! impl_common {
         use std::sync::Arc;
         use std::time::Duration;
 
-        use crate::builder::ScheduleAlgo;
+        use crate::builder::{BlockingRejectPolicy, PanicPolicy, ScheduleAlgo, WorkerInfo};
+        use crate::task::{PanicPayload, TaskInfo};
 
         impl $self {
             /// Sets the name prefix for all worker threads.
@@ -109,30 +149,137 @@ macro_rules! impl_common {
                 self
             }
 
+            /// Restricts the threads of the blocking pool to the given cpus, so that
+            /// they could be kept away from the cores of the latency-critical
+            /// workers. Each thread could run on any cpu of the set.
+            ///
+            /// The threads are not restricted by default. An empty set is ignored.
+            /// Cpus are identified by their indexes starting from 0.
+            pub fn blocking_cpu_set(mut self, cpus: Vec<usize>) -> Self {
+                self.common.blocking_cpu_set = if cpus.is_empty() { None } else { Some(cpus) };
+                self
+            }
+
             /// Sets the schedule policy.
             pub fn schedule_algo(mut self, schedule_algo: ScheduleAlgo) -> Self {
                 self.common.schedule_algo = schedule_algo;
                 self
             }
 
+            /// Sets the budget of each poll of a task, which is the number of
+            /// operations on IO resources, channels, locks and timers that the task
+            /// could perform in one poll. Once the budget is used up, these operations
+            /// return pending and the task gets rescheduled, so that other tasks would
+            /// not get starved.
+            ///
+            /// The default budget is 128. Setting it to 0 turns off the budgeting.
+            pub fn task_budget(mut self, budget: u32) -> Self {
+                self.common.task_budget = if budget == 0 { None } else { Some(budget) };
+                self
+            }
+
             /// Sets the callback function to be called when a worker thread starts.
+            ///
+            /// This replaces the callback set by `on_worker_start`.
             pub fn after_start<F>(mut self, f: F) -> Self
             where
                 F: Fn() + Send + Sync + 'static,
             {
-                self.common.after_start = Some(Arc::new(f));
+                self.common.after_start = Some(Arc::new(move |_: &WorkerInfo| f()));
                 self
             }
 
             /// Sets the callback function to be called when a worker thread stops.
+            ///
+            /// This replaces the callback set by `on_worker_stop`.
             pub fn before_stop<F>(mut self, f: F) -> Self
             where
                 F: Fn() + Send + Sync + 'static,
+            {
+                self.common.before_stop = Some(Arc::new(move |_: &WorkerInfo| f()));
+                self
+            }
+
+            /// Sets the callback function to be called on a worker thread, either an
+            /// async worker or a thread of the blocking pool, right after it starts.
+            /// The callback receives the index and the kind of the thread, which
+            /// could be used to set up the thread, such as its priority.
+            ///
+            /// This replaces the callback set by `after_start`.
+            pub fn on_worker_start<F>(mut self, f: F) -> Self
+            where
+                F: Fn(&WorkerInfo) + Send + Sync + 'static,
+            {
+                self.common.after_start = Some(Arc::new(f));
+                self
+            }
+
+            /// Sets the callback function to be called on a worker thread, either an
+            /// async worker or a thread of the blocking pool, right before it stops.
+            ///
+            /// This replaces the callback set by `before_stop`.
+            pub fn on_worker_stop<F>(mut self, f: F) -> Self
+            where
+                F: Fn(&WorkerInfo) + Send + Sync + 'static,
             {
                 self.common.before_stop = Some(Arc::new(f));
                 self
             }
 
+            /// Sets the callback function to be called on an async worker right before
+            /// it parks because it runs out of tasks. Threads of the blocking pool do
+            /// not call it.
+            pub fn on_thread_park<F>(mut self, f: F) -> Self
+            where
+                F: Fn(&WorkerInfo) + Send + Sync + 'static,
+            {
+                self.common.on_park = Some(Arc::new(f));
+                self
+            }
+
+            /// Sets the callback function to be called on an async worker right after
+            /// it gets unparked, which pairs with the one set by `on_thread_park`.
+            pub fn on_thread_unpark<F>(mut self, f: F) -> Self
+            where
+                F: Fn(&WorkerInfo) + Send + Sync + 'static,
+            {
+                self.common.on_unpark = Some(Arc::new(f));
+                self
+            }
+
+            /// Sets the policy applied when a task spawned onto the runtime panics.
+            /// The default policy is [`PanicPolicy::Ignore`].
+            pub fn on_task_panic(mut self, policy: PanicPolicy) -> Self {
+                self.common.panic_policy = policy;
+                self
+            }
+
+            /// Sets the callback function to be called when a task spawned onto the
+            /// runtime panics. The hook is called on the thread running the task,
+            /// before the policy set by `on_task_panic` is applied.
+            pub fn task_panic_hook<F>(mut self, f: F) -> Self
+            where
+                F: Fn(&TaskInfo, &PanicPayload) + Send + Sync + 'static,
+            {
+                self.common.task_panic_hook = Some(Arc::new(f));
+                self
+            }
+
+            /// Sets the seed of the random numbers used by the runtime, which decide
+            /// the order that an idle worker steals tasks from the others and the
+            /// branch order of a `select!` running on the runtime. Each worker gets
+            /// its own sequence derived from the seed and its index.
+            ///
+            /// Setting the same seed makes these choices reproducible across runs,
+            /// which helps reproducing concurrency bugs. Since threads still
+            /// interleave freely on a multi-thread runtime, the whole execution is
+            /// only reproducible on a current-thread runtime, see
+            /// `CurrentThreadBuilder::record_schedule`.
+            pub fn rng_seed(mut self, seed: u64) -> Self {
+                self.common.rng_seed = Some(seed);
+                self
+            }
+
             /// Sets the maximum number of permanent threads in blocking thread pool
             pub fn blocking_permanent_thread_num(
                 mut self,
@@ -157,6 +304,25 @@ macro_rules! impl_common {
                 self
             }
 
+            /// Sets the maximum number of tasks that could wait inside the blocking
+            /// queue once all threads of the blocking pool are busy. Tasks spawned
+            /// afterwards are handled by the policy set by `blocking_reject_policy`.
+            ///
+            /// The queue is unbounded by default. Setting it to 0 makes the tasks
+            /// never wait inside the queue.
+            pub fn blocking_queue_size(mut self, blocking_queue_size: usize) -> Self {
+                self.common.blocking_queue_size = Some(blocking_queue_size);
+                self
+            }
+
+            /// Sets the policy applied when a blocking task is spawned while the
+            /// blocking queue is full. The default policy is
+            /// [`BlockingRejectPolicy::Error`].
+            pub fn blocking_reject_policy(mut self, policy: BlockingRejectPolicy) -> Self {
+                self.common.blocking_reject_policy = policy;
+                self
+            }
+
             /// Sets how long will the thread be kept alive inside the blocking pool
             /// after it becomes idle.
             pub fn keep_alive_time(mut self, keep_alive_time: Duration) -> Self {
@@ -15,27 +15,136 @@ use std::io;
 
 use crate::builder::common_builder::impl_common;
 use crate::builder::CommonBuilder;
-use crate::executor::current_thread::CurrentThreadSpawner;
-use crate::executor::{AsyncHandle, Runtime};
+use crate::executor::current_thread::{
+    CurrentThreadScheduler, CurrentThreadSpawner, ScheduleTrace, Tracer,
+};
+use crate::executor::{AsyncHandle, Runtime, RuntimeInner};
+use crate::task::PanicHandler;
 
 /// RuntimeBuilder struct with current_thread settings.
 pub struct CurrentThreadBuilder {
     pub(crate) common: CommonBuilder,
+    /// Whether the time of the runtime starts paused
+    #[cfg(feature = "time")]
+    pub(crate) start_paused: bool,
+    /// Whether to record the poll order of the tasks
+    pub(crate) record_schedule: bool,
+    /// A recorded poll order of the tasks to follow
+    pub(crate) replay_schedule: Option<ScheduleTrace>,
 }
 
 impl CurrentThreadBuilder {
     pub(crate) fn new() -> Self {
         CurrentThreadBuilder {
             common: CommonBuilder::new(),
+            #[cfg(feature = "time")]
+            start_paused: false,
+            record_schedule: false,
+            replay_schedule: None,
         }
     }
 
+    /// Sets whether to record the order in which the runtime polls its tasks.
+    /// By default it's false.
+    ///
+    /// The recorded order could be fetched by [`Runtime::schedule_trace`], and
+    /// then passed to [`CurrentThreadBuilder::replay_schedule`] to reproduce a
+    /// failure caused by the order. Set [`rng_seed`] as well to make the random
+    /// choices reproducible too.
+    ///
+    /// [`rng_seed`]: CurrentThreadBuilder::rng_seed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::builder::RuntimeBuilder;
+    ///
+    /// let runtime = RuntimeBuilder::new_current_thread()
+    ///     .rng_seed(42)
+    ///     .record_schedule(true)
+    ///     .build()
+    ///     .unwrap();
+    /// let handle = runtime.spawn(async { 1 });
+    /// assert_eq!(runtime.block_on(handle).unwrap(), 1);
+    /// let trace = runtime.schedule_trace().unwrap();
+    /// assert_eq!(trace.polls(), &[0]);
+    /// ```
+    pub fn record_schedule(mut self, record: bool) -> Self {
+        self.record_schedule = record;
+        self
+    }
+
+    /// Makes the runtime poll its tasks in the order of a recorded trace, so
+    /// that a failure caused by the order could be reproduced. The poll
+    /// order of the replay gets recorded as well.
+    ///
+    /// While replaying, the runtime only polls the next task of the trace, and
+    /// waits for it if it has not been spawned or woken yet. If the program
+    /// diverges from the recorded run, it might wait for a task that would
+    /// never be woken. Tasks that have finished are skipped. Once the trace is
+    /// used up, the tasks are polled in FIFO order as usual.
+    pub fn replay_schedule(mut self, trace: ScheduleTrace) -> Self {
+        self.replay_schedule = Some(trace);
+        self
+    }
+
+    /// Sets whether the time of the runtime starts paused. By default it's
+    /// false.
+    ///
+    /// With paused time, the runtime becomes a deterministic test runtime for
+    /// time related logic: the time only moves forward through
+    /// [`time::advance`], or jumps straight to the next timer's deadline when
+    /// there is no task to run, so sleeps, timeouts and timers complete
+    /// without waiting for the real time. See [`time::pause`] for more details.
+    ///
+    /// [`time::advance`]: crate::time::advance
+    /// [`time::pause`]: crate::time::pause
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use ylong_runtime::builder::RuntimeBuilder;
+    /// use ylong_runtime::time;
+    ///
+    /// let runtime = RuntimeBuilder::new_current_thread()
+    ///     .start_paused(true)
+    ///     .build()
+    ///     .unwrap();
+    /// let res = runtime.block_on(async {
+    ///     time::timeout(Duration::from_secs(3600), std::future::pending::<()>()).await
+    /// });
+    /// assert!(res.is_err());
+    /// ```
+    #[cfg(feature = "time")]
+    pub fn start_paused(mut self, start_paused: bool) -> Self {
+        self.start_paused = start_paused;
+        self
+    }
+
     /// Initializes the runtime and returns its instance.
     pub fn build(&mut self) -> io::Result<Runtime> {
-        let async_spawner = CurrentThreadSpawner::new();
-        Ok(Runtime {
+        let mut async_spawner = CurrentThreadSpawner::new();
+        async_spawner.task_budget = self.common.task_budget;
+        async_spawner.rng_seed = self.common.rng_seed;
+        let mut scheduler =
+            CurrentThreadScheduler::with_panic_handler(PanicHandler::new(&self.common));
+        if self.record_schedule || self.replay_schedule.is_some() {
+            scheduler.tracer = Some(Tracer::new(self.replay_schedule.clone()));
+        }
+        async_spawner.scheduler = Arc::new(scheduler);
+        #[cfg(all(feature = "time", not(feature = "ffrt")))]
+        if self.start_paused {
+            async_spawner.handle.time.pause();
+        }
+        #[cfg(not(feature = "ffrt"))]
+        let blocking_spawner = crate::builder::initialize_blocking_spawner(&self.common)?;
+        Ok(Runtime::from_inner(RuntimeInner {
             async_spawner: AsyncHandle::CurrentThread(async_spawner),
-        })
+            #[cfg(not(feature = "ffrt"))]
+            blocking_spawner: Some(blocking_spawner),
+        }))
     }
 }
 
@@ -37,11 +37,13 @@ pub use current_thread_builder::CurrentThreadBuilder;
 pub use multi_thread_builder::MultiThreadBuilder;
 
 pub(crate) use crate::builder::common_builder::CommonBuilder;
+use crate::task::{PanicPayload, TaskInfo};
 
 cfg_not_ffrt!(
     use crate::error::ScheduleError;
     use crate::executor::async_pool::AsyncPoolSpawner;
     use crate::executor::blocking_pool::BlockPoolSpawner;
+    use crate::executor::BlockingInfo;
     use std::io;
 );
 
@@ -49,11 +51,110 @@ cfg_not_ffrt!(
 /// life-cycle
 pub type CallbackHook = Arc<dyn Fn() + Send + Sync + 'static>;
 
+/// A callback function to be executed in different stages of a worker's
+/// life-cycle, which receives the information of the worker
+pub type WorkerHook = Arc<dyn Fn(&WorkerInfo) + Send + Sync + 'static>;
+
+/// A callback function to be executed when a task panics
+pub type TaskPanicHook = Arc<dyn Fn(&TaskInfo, &PanicPayload) + Send + Sync + 'static>;
+
+/// A callback function to be executed when a worker is detected stuck inside a
+/// single poll
+#[cfg(not(feature = "ffrt"))]
+pub type BlockingDetectionHook = Arc<dyn Fn(&BlockingInfo) + Send + Sync + 'static>;
+
+/// Kind of a thread spawned by the runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkerKind {
+    /// A worker of the async thread pool, which polls the async tasks.
+    Async,
+    /// A thread of the blocking pool, which runs the blocking tasks.
+    Blocking,
+}
+
+/// Information of a thread spawned by the runtime, which is passed to the
+/// worker hooks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorkerInfo {
+    index: usize,
+    kind: WorkerKind,
+}
+
+impl WorkerInfo {
+    pub(crate) fn new(index: usize, kind: WorkerKind) -> Self {
+        WorkerInfo { index, kind }
+    }
+
+    /// Gets the index of the thread. Async workers are indexed from 0 to the
+    /// number of workers, while the threads of the blocking pool are indexed
+    /// in the order they are spawned.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Gets the kind of the thread.
+    pub fn kind(&self) -> WorkerKind {
+        self.kind
+    }
+}
+
 /// Schedule Policy.
+///
+/// The policy only takes effect on the multi-thread runtime.
 #[derive(Debug, Clone, Copy, PartialOrd, PartialEq, Eq)]
 pub enum ScheduleAlgo {
     /// Bounded local queues which adopts FIFO order.
     FifoBound,
+    /// Bounded local queues with a LIFO slot for each worker. A task spawned
+    /// or woken up by a worker is polled next by the same worker, which suits
+    /// message-passing workloads. The slot could only be used a few times in a
+    /// row, so that tasks inside the local queue would not get starved.
+    LifoSlot,
+    /// All tasks are pushed into the global queue and polled in strict FIFO
+    /// order. Workers neither keep tasks of their own nor steal tasks from
+    /// each other, which makes the policy suitable for fairness testing.
+    FifoGlobal,
+    /// Bounded local queues. Each worker takes turns between its local queue
+    /// and the global queue, and polls up to `budget` tasks from one queue
+    /// before turning to the other one.
+    RoundRobin {
+        /// Number of tasks polled from one queue in a row. Zero is treated as
+        /// one.
+        budget: u32,
+    },
+}
+
+/// Policy applied when a task panics.
+///
+/// Whatever the policy is, the panic is caught and the JoinHandle of the task
+/// returns a `Panic` error carrying the payload of the panic.
+#[derive(Debug, Clone, Copy, Default, PartialOrd, PartialEq, Eq)]
+pub enum PanicPolicy {
+    /// Keeps the runtime running.
+    #[default]
+    Ignore,
+    /// Aborts the process.
+    Abort,
+    /// Shuts down the runtime in the background, same as
+    /// `Runtime::shutdown_background`. Tasks of the global blocking pool could
+    /// not shut the runtime down.
+    ShutdownRuntime,
+}
+
+/// Policy applied when a blocking task is spawned while the blocking queue is
+/// full.
+///
+/// The blocking queue only holds the tasks that are waiting for a thread, so
+/// the policy takes effect once all threads of the blocking pool are busy and
+/// the queue has reached the size set by `blocking_queue_size`.
+#[derive(Debug, Clone, Copy, PartialOrd, PartialEq, Eq)]
+pub enum BlockingRejectPolicy {
+    /// Rejects the task. Its JoinHandle returns a `BlockQueueFull` error.
+    Error,
+    /// Runs the task on the thread that spawns it.
+    CallerRuns,
+    /// Blocks the thread that spawns the task until the queue has room for it.
+    Wait,
 }
 
 /// Builder to build the runtime. Provides methods to customize the runtime,
@@ -143,7 +244,7 @@ cfg_not_ffrt! {
 mod test {
     use crate::builder::RuntimeBuilder;
     #[cfg(not(feature = "ffrt"))]
-    use crate::builder::ScheduleAlgo;
+    use crate::builder::{BlockingRejectPolicy, ScheduleAlgo};
 
     /// UT test cases for RuntimeBuilder::new_multi_thread()
     ///
@@ -160,6 +261,9 @@ mod test {
     /// 9. Checks if the object stack_size property is None
     /// 10. Checks if the object after_start property is None
     /// 11. Checks if the object before_stop property is None
+    /// 12. Checks if the object blocking_queue_size property is None
+    /// 13. Checks if the object blocking_reject_policy property is
+    ///     BlockingRejectPolicy::Error
     #[test]
     fn ut_thread_pool_builder_new() {
         let thread_pool_builder = RuntimeBuilder::new_multi_thread();
@@ -168,6 +272,11 @@ mod test {
         {
             assert_eq!(thread_pool_builder.common.blocking_permanent_thread_num, 0);
             assert_eq!(thread_pool_builder.common.max_blocking_pool_size, None);
+            assert_eq!(thread_pool_builder.common.blocking_queue_size, None);
+            assert_eq!(
+                thread_pool_builder.common.blocking_reject_policy,
+                BlockingRejectPolicy::Error
+            );
             assert_eq!(thread_pool_builder.common.keep_alive_time, None);
             assert_eq!(thread_pool_builder.core_thread_size, None);
             assert_eq!(thread_pool_builder.common.stack_size, None);
@@ -294,6 +403,35 @@ mod ylong_executor_test {
         assert!(!thread_pool_builder.common.is_affinity);
     }
 
+    /// UT test cases for MultiThreadBuilder::worker_cpu_set() and
+    /// RuntimeBuilder::blocking_cpu_set()
+    ///
+    /// # Brief
+    /// 1. Set the cpu sets of the workers and the blocking threads, check if
+    ///    they are modified values
+    /// 2. Set empty cpu sets, check if they are ignored
+    /// 3. numa_aware_steal set to true, check if it is a modified value
+    #[test]
+    fn ut_thread_pool_builder_cpu_set() {
+        let thread_pool_builder = RuntimeBuilder::new_multi_thread()
+            .worker_cpu_set(vec![2, 3])
+            .blocking_cpu_set(vec![0, 1]);
+        assert_eq!(thread_pool_builder.worker_cpu_set, Some(vec![2, 3]));
+        assert_eq!(
+            thread_pool_builder.common.blocking_cpu_set,
+            Some(vec![0, 1])
+        );
+
+        let thread_pool_builder = RuntimeBuilder::new_multi_thread()
+            .worker_cpu_set(vec![])
+            .blocking_cpu_set(vec![]);
+        assert_eq!(thread_pool_builder.worker_cpu_set, None);
+        assert_eq!(thread_pool_builder.common.blocking_cpu_set, None);
+
+        let thread_pool_builder = RuntimeBuilder::new_multi_thread().numa_aware_steal(true);
+        assert!(thread_pool_builder.numa_aware_steal);
+    }
+
     /// UT test cases for RuntimeBuilder::blocking_permanent_thread_num()
     ///
     /// # Brief        
@@ -398,11 +536,71 @@ mod ylong_executor_test {
     ///
     /// # Brief
     /// 1. schedule_algo set to FifoBound, check if it is the modified value
+    /// 2. schedule_algo set to other policies, check if it is the modified
+    ///    value
     #[cfg(not(feature = "ffrt"))]
     #[test]
     fn ut_thread_pool_builder_schedule_algo_test() {
         let schedule_algo = ScheduleAlgo::FifoBound;
         let thread_pool_builder = RuntimeBuilder::new_multi_thread().schedule_algo(schedule_algo);
         assert_eq!(thread_pool_builder.common.schedule_algo, schedule_algo);
+
+        for schedule_algo in [
+            ScheduleAlgo::LifoSlot,
+            ScheduleAlgo::FifoGlobal,
+            ScheduleAlgo::RoundRobin { budget: 8 },
+        ] {
+            let thread_pool_builder =
+                RuntimeBuilder::new_multi_thread().schedule_algo(schedule_algo);
+            assert_eq!(thread_pool_builder.common.schedule_algo, schedule_algo);
+        }
+    }
+
+    /// UT test cases for RuntimeBuilder::task_budget()
+    ///
+    /// # Brief
+    /// 1. Check the default task budget
+    /// 2. task_budget set to 64, check if it is the modified value
+    /// 3. task_budget set to 0, check if the budget is turned off
+    #[cfg(not(feature = "ffrt"))]
+    #[test]
+    fn ut_thread_pool_builder_task_budget_test() {
+        let thread_pool_builder = RuntimeBuilder::new_multi_thread();
+        assert_eq!(thread_pool_builder.common.task_budget, Some(128));
+
+        let thread_pool_builder = RuntimeBuilder::new_multi_thread().task_budget(64);
+        assert_eq!(thread_pool_builder.common.task_budget, Some(64));
+
+        let thread_pool_builder = RuntimeBuilder::new_multi_thread().task_budget(0);
+        assert_eq!(thread_pool_builder.common.task_budget, None);
+    }
+
+    /// UT test cases for RuntimeBuilder::max_worker_num() and auto_scale()
+    ///
+    /// # Brief
+    /// 1. Check the default values
+    /// 2. max_worker_num set to 0 and 65, check if they are bounded to 1 and 64
+    /// 3. auto_scale set to (2, 8), check if both values are set
+    /// 4. auto_scale set to (10, 8), check if the min value is bounded by the
+    ///    max value
+    #[cfg(not(feature = "ffrt"))]
+    #[test]
+    fn ut_thread_pool_builder_auto_scale_test() {
+        let thread_pool_builder = RuntimeBuilder::new_multi_thread();
+        assert_eq!(thread_pool_builder.max_thread_size, None);
+        assert_eq!(thread_pool_builder.min_thread_size, None);
+
+        let thread_pool_builder = RuntimeBuilder::new_multi_thread().max_worker_num(0);
+        assert_eq!(thread_pool_builder.max_thread_size, Some(1));
+        let thread_pool_builder = RuntimeBuilder::new_multi_thread().max_worker_num(65);
+        assert_eq!(thread_pool_builder.max_thread_size, Some(64));
+
+        let thread_pool_builder = RuntimeBuilder::new_multi_thread().auto_scale(2, 8);
+        assert_eq!(thread_pool_builder.max_thread_size, Some(8));
+        assert_eq!(thread_pool_builder.min_thread_size, Some(2));
+
+        let thread_pool_builder = RuntimeBuilder::new_multi_thread().auto_scale(10, 8);
+        assert_eq!(thread_pool_builder.max_thread_size, Some(8));
+        assert_eq!(thread_pool_builder.min_thread_size, Some(8));
     }
 }
@@ -19,14 +19,21 @@ cfg_ffrt!(
     use std::collections::HashMap;
     use libc::{c_uint, c_ulong};
     use std::time::Duration;
-    use crate::builder::ScheduleAlgo;
+    use crate::builder::{BlockingRejectPolicy, PanicPolicy, ScheduleAlgo, WorkerInfo};
+    use crate::task::{PanicPayload, TaskInfo};
 );
 
 #[cfg(not(feature = "ffrt"))]
 use crate::builder::common_builder::impl_common;
 use crate::builder::CommonBuilder;
+#[cfg(not(feature = "ffrt"))]
+use crate::executor::watchdog::BlockingDetection;
+#[cfg(not(feature = "ffrt"))]
+use crate::executor::BlockingInfo;
 #[cfg(feature = "multi_instance_runtime")]
-use crate::executor::{AsyncHandle, Runtime};
+use crate::executor::{AsyncHandle, Runtime, RuntimeInner};
+#[cfg(all(not(feature = "ffrt"), feature = "metrics"))]
+use crate::metrics::PollStatsConfig;
 
 pub(crate) static GLOBAL_BUILDER: Mutex<Option<MultiThreadBuilder>> = Mutex::new(None);
 
@@ -39,9 +46,34 @@ pub struct MultiThreadBuilder {
     /// Maximum thread number for core thread pool
     pub(crate) core_thread_size: Option<usize>,
 
+    #[cfg(not(feature = "ffrt"))]
+    /// Thread number the core thread pool could grow to
+    pub(crate) max_thread_size: Option<usize>,
+
+    #[cfg(not(feature = "ffrt"))]
+    /// Thread number the core thread pool could shrink to when auto-scaling,
+    /// `None` means auto-scaling is disabled
+    pub(crate) min_thread_size: Option<usize>,
+
     #[cfg(feature = "ffrt")]
     /// Thread number for each qos
     pub(crate) thread_num_by_qos: HashMap<Qos, u32>,
+
+    #[cfg(not(feature = "ffrt"))]
+    /// Cpus that the workers are placed on, `None` means all the cpus
+    pub(crate) worker_cpu_set: Option<Vec<usize>>,
+
+    #[cfg(not(feature = "ffrt"))]
+    /// Whether the workers steal from the workers on the same NUMA node first
+    pub(crate) numa_aware_steal: bool,
+
+    #[cfg(not(feature = "ffrt"))]
+    /// Blocking detection settings of the workers
+    pub(crate) blocking_detection: BlockingDetection,
+
+    #[cfg(all(not(feature = "ffrt"), feature = "metrics"))]
+    /// Poll statistics settings of the workers
+    pub(crate) poll_stats: PollStatsConfig,
 }
 
 impl MultiThreadBuilder {
@@ -50,8 +82,20 @@ impl MultiThreadBuilder {
             common: CommonBuilder::new(),
             #[cfg(not(feature = "ffrt"))]
             core_thread_size: None,
+            #[cfg(not(feature = "ffrt"))]
+            max_thread_size: None,
+            #[cfg(not(feature = "ffrt"))]
+            min_thread_size: None,
             #[cfg(feature = "ffrt")]
             thread_num_by_qos: HashMap::new(),
+            #[cfg(not(feature = "ffrt"))]
+            worker_cpu_set: None,
+            #[cfg(not(feature = "ffrt"))]
+            numa_aware_steal: false,
+            #[cfg(not(feature = "ffrt"))]
+            blocking_detection: BlockingDetection::default(),
+            #[cfg(all(not(feature = "ffrt"), feature = "metrics"))]
+            poll_stats: PollStatsConfig::default(),
         }
     }
 
@@ -132,6 +176,14 @@ impl MultiThreadBuilder {
         self
     }
 
+    /// Restricts the threads of the blocking pool to the given cpus.
+    ///
+    /// # Note
+    /// This method does nothing now under ffrt feature.
+    pub fn blocking_cpu_set(self, _cpus: Vec<usize>) -> Self {
+        self
+    }
+
     /// Sets the schedule policy.
     ///
     /// # Note
@@ -140,6 +192,14 @@ impl MultiThreadBuilder {
         self
     }
 
+    /// Sets the budget of each poll of a task.
+    ///
+    /// # Note
+    /// This method does nothing now under ffrt feature.
+    pub fn task_budget(self, _budget: u32) -> Self {
+        self
+    }
+
     /// Sets the callback function to be called when a worker thread starts.
     ///
     /// # Note
@@ -162,6 +222,72 @@ impl MultiThreadBuilder {
         self
     }
 
+    /// Sets the callback function to be called when a worker thread starts,
+    /// which receives the information of the thread.
+    ///
+    /// # Note
+    /// This method does nothing now under ffrt feature.
+    pub fn on_worker_start<F>(self, _f: F) -> Self
+    where
+        F: Fn(&WorkerInfo) + Send + Sync + 'static,
+    {
+        self
+    }
+
+    /// Sets the callback function to be called when a worker thread stops,
+    /// which receives the information of the thread.
+    ///
+    /// # Note
+    /// This method does nothing now under ffrt feature.
+    pub fn on_worker_stop<F>(self, _f: F) -> Self
+    where
+        F: Fn(&WorkerInfo) + Send + Sync + 'static,
+    {
+        self
+    }
+
+    /// Sets the callback function to be called before a worker thread parks.
+    ///
+    /// # Note
+    /// This method does nothing now under ffrt feature.
+    pub fn on_thread_park<F>(self, _f: F) -> Self
+    where
+        F: Fn(&WorkerInfo) + Send + Sync + 'static,
+    {
+        self
+    }
+
+    /// Sets the callback function to be called after a worker thread unparks.
+    ///
+    /// # Note
+    /// This method does nothing now under ffrt feature.
+    pub fn on_thread_unpark<F>(self, _f: F) -> Self
+    where
+        F: Fn(&WorkerInfo) + Send + Sync + 'static,
+    {
+        self
+    }
+
+    /// Sets the policy applied when a task spawned onto the runtime panics.
+    ///
+    /// # Note
+    /// This method does nothing now under ffrt feature.
+    pub fn on_task_panic(self, _policy: PanicPolicy) -> Self {
+        self
+    }
+
+    /// Sets the callback function to be called when a task spawned onto the
+    /// runtime panics.
+    ///
+    /// # Note
+    /// This method does nothing now under ffrt feature.
+    pub fn task_panic_hook<F>(self, _f: F) -> Self
+    where
+        F: Fn(&TaskInfo, &PanicPayload) + Send + Sync + 'static,
+    {
+        self
+    }
+
     /// Sets the maximum number of permanent threads in blocking thread pool
     ///
     /// # Note
@@ -181,6 +307,24 @@ impl MultiThreadBuilder {
         self
     }
 
+    /// Sets the maximum number of tasks that could wait inside the blocking
+    /// queue.
+    ///
+    /// # Note
+    /// This method does nothing now under ffrt feature.
+    pub fn blocking_queue_size(self, _blocking_queue_size: usize) -> Self {
+        self
+    }
+
+    /// Sets the policy applied when a blocking task is spawned while the
+    /// blocking queue is full.
+    ///
+    /// # Note
+    /// This method does nothing now under ffrt feature.
+    pub fn blocking_reject_policy(self, _policy: BlockingRejectPolicy) -> Self {
+        self
+    }
+
     /// Sets how long will the thread be kept alive inside the blocking pool
     /// after it becomes idle.
     ///
@@ -214,6 +358,22 @@ impl MultiThreadBuilder {
     pub fn worker_stack_size(self, stack_size: usize) -> Self {
         self.stack_size_by_qos(Qos::Default, stack_size)
     }
+
+    /// Sets the max number of core worker threads.
+    ///
+    /// # Note
+    /// This method does nothing now under ffrt feature.
+    pub fn max_worker_num(self, _max_pool_size: usize) -> Self {
+        self
+    }
+
+    /// Enables auto-scaling of the core worker threads.
+    ///
+    /// # Note
+    /// This method does nothing now under ffrt feature.
+    pub fn auto_scale(self, _min_pool_size: usize, _max_pool_size: usize) -> Self {
+        self
+    }
 }
 
 #[cfg(not(feature = "ffrt"))]
@@ -221,12 +381,14 @@ impl MultiThreadBuilder {
     /// Initializes the runtime and returns its instance.
     #[cfg(feature = "multi_instance_runtime")]
     pub fn build(&mut self) -> io::Result<Runtime> {
-        use crate::builder::initialize_async_spawner;
+        use crate::builder::{initialize_async_spawner, initialize_blocking_spawner};
         let async_spawner = initialize_async_spawner(self)?;
+        let blocking_spawner = initialize_blocking_spawner(&self.common)?;
 
-        Ok(Runtime {
+        Ok(Runtime::from_inner(RuntimeInner {
             async_spawner: AsyncHandle::MultiThread(async_spawner),
-        })
+            blocking_spawner: Some(blocking_spawner),
+        }))
     }
 
     /// Sets the number of core worker threads.
@@ -253,6 +415,203 @@ impl MultiThreadBuilder {
         }
         self
     }
+
+    /// Sets the max number of core worker threads, which the runtime could
+    /// grow to through [`Runtime::set_worker_num`].
+    ///
+    /// The boundary of thread number is 1-64. The default value is the number
+    /// set by [`MultiThreadBuilder::worker_num`], which means the runtime
+    /// could not grow. If the value is smaller than the number of core worker
+    /// threads, the latter is used.
+    ///
+    /// [`Runtime::set_worker_num`]: crate::executor::Runtime::set_worker_num
+    ///
+    /// # Examples
+    /// ```
+    /// use crate::ylong_runtime::builder::RuntimeBuilder;
+    ///
+    /// let runtime = RuntimeBuilder::new_multi_thread()
+    ///     .worker_num(2)
+    ///     .max_worker_num(8);
+    /// ```
+    pub fn max_worker_num(mut self, max_pool_size: usize) -> Self {
+        self.max_thread_size = Some(max_pool_size.clamp(1, 64));
+        self
+    }
+
+    /// Enables auto-scaling of the core worker threads.
+    ///
+    /// The runtime grows when all the workers are busy while there are still
+    /// tasks waiting in the queues, and shrinks when some workers keep idle
+    /// for a while. The number of workers is kept between `min_pool_size` and
+    /// `max_pool_size`, and the number set by
+    /// [`MultiThreadBuilder::worker_num`] is used as the initial value.
+    ///
+    /// Workers retired by shrinking are parked, and get reused when the
+    /// runtime grows again.
+    ///
+    /// The boundary of thread number is 1-64. If `min_pool_size` is larger
+    /// than `max_pool_size`, the latter is used.
+    ///
+    /// # Examples
+    /// ```
+    /// use crate::ylong_runtime::builder::RuntimeBuilder;
+    ///
+    /// let runtime = RuntimeBuilder::new_multi_thread()
+    ///     .worker_num(2)
+    ///     .auto_scale(1, 8);
+    /// ```
+    pub fn auto_scale(mut self, min_pool_size: usize, max_pool_size: usize) -> Self {
+        let max_pool_size = max_pool_size.clamp(1, 64);
+        self.max_thread_size = Some(max_pool_size);
+        self.min_thread_size = Some(min_pool_size.clamp(1, max_pool_size));
+        self
+    }
+
+    /// Sets the cpus that the workers are placed on.
+    ///
+    /// If [`is_affinity`] is turned on, each worker gets bound to a single cpu
+    /// of the set, taken in turns by the worker index. Otherwise, each worker
+    /// could run on any cpu of the set. An empty set is ignored. Cpus are
+    /// identified by their indexes starting from 0.
+    ///
+    /// Together with [`blocking_cpu_set`], it isolates the workers from the
+    /// cores running the blocking tasks and other housekeeping threads.
+    ///
+    /// [`is_affinity`]: MultiThreadBuilder::is_affinity
+    /// [`blocking_cpu_set`]: MultiThreadBuilder::blocking_cpu_set
+    ///
+    /// # Examples
+    /// ```
+    /// use crate::ylong_runtime::builder::RuntimeBuilder;
+    ///
+    /// let runtime = RuntimeBuilder::new_multi_thread()
+    ///     .worker_num(4)
+    ///     .is_affinity(true)
+    ///     .worker_cpu_set(vec![2, 3, 4, 5])
+    ///     .blocking_cpu_set(vec![0, 1]);
+    /// ```
+    pub fn worker_cpu_set(mut self, cpus: Vec<usize>) -> Self {
+        self.worker_cpu_set = if cpus.is_empty() { None } else { Some(cpus) };
+        self
+    }
+
+    /// Sets whether an idle worker steals tasks from the workers on the same
+    /// NUMA node before turning to the others, which keeps the data of the
+    /// tasks close to the cpu running them.
+    ///
+    /// It only takes effect on the workers whose cpus all belong to one known
+    /// node, which requires binding the workers through [`is_affinity`] or
+    /// [`worker_cpu_set`]. The default value is false.
+    ///
+    /// [`is_affinity`]: MultiThreadBuilder::is_affinity
+    /// [`worker_cpu_set`]: MultiThreadBuilder::worker_cpu_set
+    pub fn numa_aware_steal(mut self, numa_aware: bool) -> Self {
+        self.numa_aware_steal = numa_aware;
+        self
+    }
+
+    /// Enables the blocking detection. A watchdog thread checks the workers
+    /// periodically, and reports the ones that have been inside a single poll
+    /// for longer than `threshold`, which usually means that the task runs
+    /// blocking code. Each blocking poll is reported once through the hook set
+    /// by [`MultiThreadBuilder::blocking_detection_hook`].
+    ///
+    /// The detection is disabled by default.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use crate::ylong_runtime::builder::RuntimeBuilder;
+    ///
+    /// let runtime = RuntimeBuilder::new_multi_thread()
+    ///     .blocking_detection(Duration::from_millis(100))
+    ///     .blocking_detection_hook(|info| {
+    ///         println!(
+    ///             "worker {} is blocked by task {} {:?}",
+    ///             info.worker_index(),
+    ///             info.task_id(),
+    ///             info.task_name()
+    ///         )
+    ///     });
+    /// ```
+    pub fn blocking_detection(mut self, threshold: Duration) -> Self {
+        self.blocking_detection.threshold = Some(threshold);
+        self
+    }
+
+    /// Sets the callback function to be called by the watchdog thread when a
+    /// worker is detected stuck inside a single poll.
+    pub fn blocking_detection_hook<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&BlockingInfo) + Send + Sync + 'static,
+    {
+        self.blocking_detection.hook = Some(Arc::new(f));
+        self
+    }
+
+    /// Sets whether the watchdog thread hands the tasks inside the local queue
+    /// of a blocked worker over to the other workers, so that these tasks keep
+    /// progressing. The task inside the LIFO slot of the worker stays there.
+    ///
+    /// The default value is false.
+    pub fn blocking_detection_offload(mut self, offload: bool) -> Self {
+        self.blocking_detection.offload = offload;
+        self
+    }
+}
+
+#[cfg(all(not(feature = "ffrt"), feature = "metrics"))]
+impl MultiThreadBuilder {
+    /// Enables the poll-time and scheduling-latency histograms of each worker,
+    /// which could be read through [`Metrics::worker_poll_time_histogram`] and
+    /// [`Metrics::worker_schedule_latency_histogram`].
+    ///
+    /// `buckets` are the upper bounds of the histogram buckets, and an extra
+    /// bucket counts the durations beyond the largest bound. The bounds do not
+    /// need to be sorted. The histograms are disabled by default.
+    ///
+    /// [`Metrics::worker_poll_time_histogram`]: crate::Metrics::worker_poll_time_histogram
+    /// [`Metrics::worker_schedule_latency_histogram`]: crate::Metrics::worker_schedule_latency_histogram
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use crate::ylong_runtime::builder::RuntimeBuilder;
+    ///
+    /// let runtime = RuntimeBuilder::new_multi_thread().metrics_poll_histogram(vec![
+    ///     Duration::from_micros(100),
+    ///     Duration::from_millis(1),
+    ///     Duration::from_millis(10),
+    /// ]);
+    /// ```
+    pub fn metrics_poll_histogram(mut self, buckets: Vec<Duration>) -> Self {
+        self.poll_stats.set_histogram_bounds(buckets);
+        self
+    }
+
+    /// Enables counting the polls that take longer than `threshold` on each
+    /// worker, which could be read through [`Metrics::worker_long_poll_count`].
+    /// A long poll usually means that the task runs blocking code. The counters
+    /// are disabled by default.
+    ///
+    /// [`Metrics::worker_long_poll_count`]: crate::Metrics::worker_long_poll_count
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use crate::ylong_runtime::builder::RuntimeBuilder;
+    ///
+    /// let runtime =
+    ///     RuntimeBuilder::new_multi_thread().metrics_long_poll_threshold(Duration::from_millis(10));
+    /// ```
+    pub fn metrics_long_poll_threshold(mut self, threshold: Duration) -> Self {
+        self.poll_stats.long_poll_threshold = Some(threshold);
+        self
+    }
 }
 
 #[cfg(not(feature = "ffrt"))]
@@ -261,6 +620,8 @@ impl_common!(MultiThreadBuilder);
 #[cfg(feature = "full")]
 #[cfg(test)]
 mod test {
+    use std::sync::atomic::Ordering::Acquire;
+
     use crate::builder::RuntimeBuilder;
     use crate::executor::{global_default_async, AsyncHandle};
 
@@ -282,10 +643,10 @@ mod test {
         assert!(ret.is_ok());
 
         let async_pool = global_default_async();
-        match &async_pool.async_spawner {
+        match &async_pool.handle.inner.async_spawner {
             AsyncHandle::CurrentThread(_) => unreachable!(),
             AsyncHandle::MultiThread(x) => {
-                assert_eq!(x.inner.total, 6);
+                assert_eq!(x.inner.total.load(Acquire), 6);
             }
         }
 
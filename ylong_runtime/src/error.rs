@@ -13,8 +13,10 @@
 
 //! Defines errors that would be returned by the runtime during execution.
 
+use std::any::Any;
 use std::fmt::{Debug, Display, Formatter};
 use std::io;
+use std::sync::Mutex;
 
 /// Schedule errors during the execution.
 pub struct ScheduleError {
@@ -32,6 +34,7 @@ impl Display for ScheduleError {
         match self.repr {
             Repr::Simple(kind) => write!(f, "{}", kind.as_str()),
             Repr::Custom(ref c) => write!(f, "{:?}: {}", c.kind, c.error),
+            Repr::Panic(ref p) => write!(f, "{:?}: {}", ErrorKind::Panic, p.message),
         }
     }
 }
@@ -39,6 +42,7 @@ impl Display for ScheduleError {
 enum Repr {
     Simple(ErrorKind),
     Custom(Box<Custom>),
+    Panic(Box<Panic>),
 }
 
 impl Debug for Repr {
@@ -46,6 +50,10 @@ impl Debug for Repr {
         match *self {
             Repr::Simple(kind) => f.debug_tuple("Kind").field(&kind).finish(),
             Repr::Custom(ref c) => std::fmt::Debug::fmt(&c, f),
+            Repr::Panic(ref p) => f
+                .debug_struct("Panic")
+                .field("message", &p.message)
+                .finish(),
         }
     }
 }
@@ -56,6 +64,12 @@ struct Custom {
     error: Box<dyn std::error::Error + Send + Sync>,
 }
 
+struct Panic {
+    message: String,
+    // the payload is only taken out by value, the mutex makes the error `Sync`
+    payload: Mutex<Box<dyn Any + Send + 'static>>,
+}
+
 /// All error types that could be returned during execution.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub enum ErrorKind {
@@ -63,6 +77,8 @@ pub enum ErrorKind {
     TaskShutdown,
     /// Blocking pool thread spawn error
     BlockSpawnErr,
+    /// Blocking queue full error
+    BlockQueueFull,
     /// Creating netpollor thread error
     NetSpawnErr,
     /// Task already canceled error
@@ -71,6 +87,8 @@ pub enum ErrorKind {
     TaskStateInvalid,
     /// Panic during execution error
     Panic,
+    /// Runtime already shut down error
+    RuntimeShutdown,
     /// Any other type errors
     Other,
 }
@@ -80,10 +98,12 @@ impl ErrorKind {
         match *self {
             ErrorKind::TaskShutdown => "task already get shutdown",
             ErrorKind::BlockSpawnErr => "blocking pool thread initialization failed",
+            ErrorKind::BlockQueueFull => "blocking queue is full",
             ErrorKind::NetSpawnErr => "net poller thread initialization failed",
             ErrorKind::TaskCanceled => "task already canceled",
             ErrorKind::TaskStateInvalid => "task state invalid",
             ErrorKind::Panic => "panic error",
+            ErrorKind::RuntimeShutdown => "runtime already get shutdown",
             ErrorKind::Other => "other error",
         }
     }
@@ -158,6 +178,7 @@ impl ScheduleError {
         match self.repr {
             Repr::Simple(..) => None,
             Repr::Custom(c) => Some(c.error),
+            Repr::Panic(p) => Some(p.message.into()),
         }
     }
 
@@ -181,6 +202,77 @@ impl ScheduleError {
         match self.repr {
             Repr::Simple(kind) => kind,
             Repr::Custom(ref c) => c.kind,
+            Repr::Panic(..) => ErrorKind::Panic,
+        }
+    }
+
+    /// Creates an error from the payload of a panic caught in a task.
+    pub(crate) fn panic(payload: Box<dyn Any + Send + 'static>) -> ScheduleError {
+        let message = if let Some(s) = payload.downcast_ref::<&'static str>() {
+            s.to_string()
+        } else if let Some(s) = payload.downcast_ref::<String>() {
+            s.clone()
+        } else {
+            String::from("panic happen")
+        };
+        ScheduleError {
+            repr: Repr::Panic(Box::new(Panic {
+                message,
+                payload: Mutex::new(payload),
+            })),
+        }
+    }
+
+    /// Checks whether the error is caused by a panicking task, and carries the
+    /// payload of the panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let handle = ylong_runtime::spawn(async { panic!("boom") });
+    /// let err = ylong_runtime::block_on(handle).unwrap_err();
+    /// assert!(err.is_panic());
+    /// ```
+    pub fn is_panic(&self) -> bool {
+        matches!(self.repr, Repr::Panic(..))
+    }
+
+    /// Gets the payload of the panic that causes the error, which could be
+    /// re-raised by `std::panic::resume_unwind`.
+    ///
+    /// # Panics
+    /// Panics if the error does not carry a panic payload, see
+    /// [`ScheduleError::is_panic`].
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// let handle = ylong_runtime::spawn(async { panic!("boom") });
+    /// if let Err(err) = ylong_runtime::block_on(handle) {
+    ///     // re-raises the panic on the current thread
+    ///     std::panic::resume_unwind(err.into_panic());
+    /// }
+    /// ```
+    pub fn into_panic(self) -> Box<dyn Any + Send + 'static> {
+        self.try_into_panic()
+            .expect("the error does not carry a panic payload")
+    }
+
+    /// Gets the payload of the panic that causes the error. Returns the error
+    /// itself if it does not carry a panic payload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let handle = ylong_runtime::spawn(async { std::panic::panic_any(1_usize) });
+    /// let err = ylong_runtime::block_on(handle).unwrap_err();
+    /// let payload = err.try_into_panic().unwrap();
+    /// assert_eq!(*payload.downcast::<usize>().unwrap(), 1);
+    /// ```
+    pub fn try_into_panic(self) -> Result<Box<dyn Any + Send + 'static>, ScheduleError> {
+        match self.repr {
+            Repr::Panic(p) => Ok(p.payload.into_inner().unwrap_or_else(|e| e.into_inner())),
+            _ => Err(self),
         }
     }
 }
@@ -287,4 +379,34 @@ mod test {
             "task shutdown"
         );
     }
+
+    /// UT test cases for ScheduleError created from a panic
+    ///
+    /// # Brief
+    /// 1. Create errors from payloads of different types
+    /// 2. Check the kind, the format and the message of the errors
+    /// 3. Take the payloads out of the errors
+    #[test]
+    fn ut_schedule_error_panic() {
+        let error = ScheduleError::panic(Box::new("static panic"));
+        assert_eq!(error.kind(), ErrorKind::Panic);
+        assert!(error.is_panic());
+        assert_eq!(format!("{error}"), "Panic: static panic");
+        assert_eq!(format!("{error:?}"), "Panic { message: \"static panic\" }");
+        let payload = error.into_panic();
+        assert_eq!(*payload.downcast_ref::<&str>().unwrap(), "static panic");
+
+        let error = ScheduleError::panic(Box::new(String::from("string panic")));
+        assert_eq!(format!("{error}"), "Panic: string panic");
+        assert_eq!(format!("{}", error.into_inner().unwrap()), "string panic");
+
+        let error = ScheduleError::panic(Box::new(1_usize));
+        assert_eq!(format!("{error}"), "Panic: panic happen");
+        let payload = error.try_into_panic().unwrap();
+        assert_eq!(*payload.downcast_ref::<usize>().unwrap(), 1);
+
+        let error = ScheduleError::new(ErrorKind::Panic, "panic happen");
+        assert!(!error.is_panic());
+        assert_eq!(error.try_into_panic().unwrap_err().kind(), ErrorKind::Panic);
+    }
 }
@@ -13,36 +13,50 @@
 
 use std::cell::RefCell;
 use std::future::Future;
-use std::sync::atomic::AtomicBool;
-use std::sync::atomic::Ordering::{Acquire, SeqCst};
-use std::sync::{Arc, Condvar, Mutex, RwLock};
+use std::sync::atomic::Ordering::{AcqRel, Acquire, Release, SeqCst};
+use std::sync::atomic::{AtomicBool, AtomicUsize};
+use std::sync::{Arc, Condvar, Mutex, RwLock, Weak};
 use std::time::Duration;
 use std::{cmp, io, thread};
 
 use super::driver::{Driver, Handle};
-use super::parker::Parker;
+use super::parker::{ParkHooks, Parker};
 use super::queue::{GlobalQueue, LocalQueue, LOCAL_QUEUE_CAP};
 use super::sleeper::Sleeper;
 use super::worker::{get_current_ctx, run_worker, Worker};
 use super::{worker, Schedule};
 use crate::builder::multi_thread_builder::MultiThreadBuilder;
-use crate::builder::CallbackHook;
+use crate::builder::{ScheduleAlgo, WorkerHook, WorkerInfo, WorkerKind};
+use crate::error::ErrorKind;
+use crate::executor::watchdog::{watchdog_proc, BlockingDetection, PollWatch};
 use crate::executor::worker::WorkerContext;
-use crate::fastrand::fast_random;
-use crate::task::{JoinHandle, Task, TaskBuilder, VirtualTableType};
+use crate::executor::RuntimeInner;
+use crate::fastrand::{self, fast_random};
+#[cfg(feature = "metrics")]
+use crate::metrics::{PollStats, PollStatsConfig, TaskRegistry};
+use crate::task::coop::DEFAULT_BUDGET;
+use crate::task::{
+    JoinHandle, PanicHandler, PanicPayload, Qos, Task, TaskBuilder, TaskInfo, VirtualTableType,
+};
 #[cfg(not(target_os = "macos"))]
-use crate::util::core_affinity::set_current_affinity;
+use crate::util::core_affinity::{get_cpu_node, set_current_affinity, set_current_affinity_set};
 use crate::util::num_cpus::get_cpu_num;
 
 const ASYNC_THREAD_QUIT_WAIT_TIME: Duration = Duration::from_secs(3);
 pub(crate) const GLOBAL_POLL_INTERVAL: u8 = 61;
+// Interval between two checks of the auto-scaling thread
+const SCALE_CHECK_INTERVAL: Duration = Duration::from_millis(20);
+// Number of checks in a row finding idle workers before the pool shrinks
+const SCALE_DOWN_CHECKS: usize = 50;
 
 pub(crate) struct MultiThreadScheduler {
     /// Async pool shutdown state
     is_cancel: AtomicBool,
-    /// Number of total workers
-    pub(crate) num_workers: usize,
-    /// Join Handles for all threads in the executor
+    /// Number of running workers, workers with a larger index get retired
+    num_workers: AtomicUsize,
+    /// Max number of workers the executor could grow to
+    pub(crate) max_workers: usize,
+    /// Join Handles for all threads in the executor, indexed by worker index
     handles: RwLock<Vec<Parker>>,
     /// Used for idle and wakeup logic.
     pub(crate) sleeper: Sleeper,
@@ -51,8 +65,28 @@ pub(crate) struct MultiThreadScheduler {
     /// A set of all the local queues in the executor
     locals: Vec<LocalQueue>,
     pub(crate) handle: Arc<Handle>,
+    /// Schedule policy of the executor
+    pub(crate) schedule_algo: ScheduleAlgo,
+    /// Budget of each poll of a task
+    pub(crate) task_budget: Option<u32>,
+    /// The runtime that owns the executor, set after the runtime is built
+    runtime: RwLock<Weak<RuntimeInner>>,
+    /// Handles the panics of the tasks
+    pub(crate) panic_handler: PanicHandler,
     #[cfg(feature = "metrics")]
     steal_times: std::sync::atomic::AtomicU64,
+    /// Polls being run by the workers, empty if the blocking detection is
+    /// disabled
+    pub(crate) poll_watches: Vec<PollWatch>,
+    /// Live tasks of the executor
+    #[cfg(feature = "metrics")]
+    pub(crate) registry: TaskRegistry,
+    /// Poll statistics settings of the workers
+    #[cfg(feature = "metrics")]
+    pub(crate) poll_stats_config: PollStatsConfig,
+    /// NUMA nodes of the workers indexed by worker index, empty if the
+    /// NUMA-aware stealing is disabled
+    numa_nodes: Vec<Option<usize>>,
 }
 
 impl Schedule for MultiThreadScheduler {
@@ -62,32 +96,81 @@ impl Schedule for MultiThreadScheduler {
             self.wake_up_rand_one(false);
         }
     }
+
+    fn on_task_panic(&self, info: &TaskInfo, payload: &PanicPayload) {
+        self.panic_handler.handle(info, payload);
+    }
+
+    #[cfg(feature = "metrics")]
+    fn registry(&self) -> Option<&TaskRegistry> {
+        Some(&self.registry)
+    }
 }
 
 impl MultiThreadScheduler {
+    #[cfg(test)]
     pub(crate) fn new(thread_num: usize, handle: Arc<Handle>) -> Self {
+        Self::with_max_workers(thread_num, thread_num, handle)
+    }
+
+    /// Creates a scheduler with `thread_num` running workers, which could grow
+    /// up to `max_num` workers.
+    pub(crate) fn with_max_workers(thread_num: usize, max_num: usize, handle: Arc<Handle>) -> Self {
+        let max_num = cmp::max(thread_num, max_num);
         let mut locals = Vec::new();
-        for _ in 0..thread_num {
+        for _ in 0..max_num {
             locals.push(LocalQueue::new());
         }
 
         Self {
             is_cancel: AtomicBool::new(false),
-            num_workers: thread_num,
+            num_workers: AtomicUsize::new(thread_num),
+            max_workers: max_num,
             handles: RwLock::new(Vec::new()),
-            sleeper: Sleeper::new(thread_num),
+            sleeper: Sleeper::new(thread_num, max_num),
             global: GlobalQueue::new(),
             locals,
             handle,
+            schedule_algo: ScheduleAlgo::FifoBound,
+            task_budget: Some(DEFAULT_BUDGET),
+            runtime: RwLock::new(Weak::new()),
+            panic_handler: PanicHandler::default(),
+            poll_watches: Vec::new(),
             #[cfg(feature = "metrics")]
             steal_times: std::sync::atomic::AtomicU64::new(0),
+            #[cfg(feature = "metrics")]
+            registry: TaskRegistry::default(),
+            #[cfg(feature = "metrics")]
+            poll_stats_config: PollStatsConfig::default(),
+            numa_nodes: Vec::new(),
         }
     }
 
+    pub(crate) fn set_runtime(&self, runtime: Weak<RuntimeInner>) {
+        self.panic_handler.set_runtime(runtime.clone());
+        *self.runtime.write().unwrap() = runtime;
+    }
+
+    pub(crate) fn get_runtime(&self) -> Option<Arc<RuntimeInner>> {
+        self.runtime.read().unwrap().upgrade()
+    }
+
     pub(crate) fn is_cancel(&self) -> bool {
         self.is_cancel.load(Acquire)
     }
 
+    /// Gets the number of running workers.
+    #[inline]
+    pub(crate) fn num_workers(&self) -> usize {
+        self.num_workers.load(Acquire)
+    }
+
+    /// Checks whether the worker is retired because the pool has shrunk.
+    #[inline]
+    pub(crate) fn is_retired(&self, worker_index: usize) -> bool {
+        worker_index >= self.num_workers()
+    }
+
     pub(crate) fn set_cancel(&self) {
         self.is_cancel.store(true, SeqCst);
     }
@@ -157,6 +240,24 @@ impl MultiThreadScheduler {
         self.sleeper.pop_worker_by_id(worker_index);
     }
 
+    pub(crate) fn turn_to_retired(&self, worker_inner: &mut worker::Inner) {
+        if worker_inner.is_searching {
+            worker_inner.is_searching = false;
+            self.sleeper.dec_searching_num();
+        }
+        self.sleeper.retire_worker();
+
+        // the tasks of the retired worker have been pushed into the global queue
+        if !self.has_no_work() {
+            self.wake_up_rand_one(false);
+        }
+    }
+
+    #[inline]
+    pub(crate) fn turn_from_retired(&self) {
+        self.sleeper.resume_worker();
+    }
+
     pub(crate) fn create_local_queue(&self, index: usize) -> LocalQueue {
         // this index is bounded by total worker num
         let local_run_queue = self.locals.get(index).unwrap();
@@ -165,11 +266,38 @@ impl MultiThreadScheduler {
         }
     }
 
+    /// Gets the poll watch of the worker if the blocking detection is enabled.
+    #[inline]
+    pub(crate) fn poll_watch(&self, index: usize) -> Option<&PollWatch> {
+        self.poll_watches.get(index)
+    }
+
+    /// Moves the tasks inside the local queue of the worker to the global
+    /// queue, and wakes up the other workers to run them.
+    pub(crate) fn offload_local_queue(&self, index: usize) {
+        let src = match self.locals.get(index) {
+            Some(src) => src,
+            None => return,
+        };
+        // the watchdog owns the temporary queue, so it could pop from it
+        let tmp = LocalQueue::new();
+        let mut count = 0;
+        while let Some(task) = src.steal_into(&tmp) {
+            self.global.push_back(task);
+            count += 1;
+            while let Some(task) = tmp.pop_front() {
+                self.global.push_back(task);
+                count += 1;
+            }
+        }
+        for _ in 0..cmp::min(count, self.num_workers()) {
+            self.wake_up_rand_one(false);
+        }
+    }
+
     pub(crate) fn has_no_work(&self) -> bool {
-        // check if local queues are empty
-        for index in 0..self.num_workers {
-            // this index is bounded by total worker num
-            let item = self.locals.get(index).unwrap();
+        // check if local queues are empty, including the ones of retired workers
+        for item in self.locals.iter() {
             if !item.is_empty() {
                 return false;
             }
@@ -180,12 +308,22 @@ impl MultiThreadScheduler {
 
     // The returned value indicates whether or not to wake up another worker
     fn enqueue_under_ctx(&self, mut task: Task, worker_ctx: &WorkerContext, lifo: bool) -> bool {
-        // if the current context is another runtime, push it to the global queue
-        if !std::ptr::eq(&self.global, &worker_ctx.worker.scheduler.global) {
+        // if the current context is another runtime or a retired worker, push it to the
+        // global queue
+        if !std::ptr::eq(&self.global, &worker_ctx.worker.scheduler.global)
+            || self.is_retired(worker_ctx.worker.index)
+        {
             self.global.push_back(task);
             return true;
         }
 
+        let lifo = match self.schedule_algo {
+            // Tasks spawned or woken up by the worker get into the lifo slot, while the task
+            // rescheduling itself goes to the back of the queue to let others run
+            ScheduleAlgo::LifoSlot => !lifo,
+            _ => lifo,
+        };
+
         if lifo {
             let mut lifo_slot = worker_ctx.worker.lifo.borrow_mut();
             let prev_task = lifo_slot.take();
@@ -212,6 +350,25 @@ impl MultiThreadScheduler {
     // 1. The task has been inserted into the global queue
     // 2. The lifo slot is taken, we push the old task into the local queue
     pub(crate) fn enqueue(&self, task: Task, lifo: bool) -> bool {
+        // The pool has been shut down, so the task would never be executed
+        if self.is_cancel() {
+            task.cancel_queued();
+            return false;
+        }
+
+        // Under strict FIFO policy, all tasks are ordered by the global queue
+        if self.schedule_algo == ScheduleAlgo::FifoGlobal {
+            self.global.push_back(task);
+            return true;
+        }
+
+        // Tasks with a non-default qos are ordered by the global queue, so they
+        // could be picked up by any worker according to their qos level
+        if task.0.header().qos != Qos::Default {
+            self.global.push_back(task);
+            return true;
+        }
+
         let cur_worker = get_current_ctx();
 
         // currently we are inside a runtime's context
@@ -230,6 +387,21 @@ impl MultiThreadScheduler {
         let count = worker_inner.count;
         let local_run_queue = &worker_inner.run_queue;
 
+        // Tasks with a qos higher than the default level are always polled first
+        if self.global.has_urgent() {
+            if let Some(task) = self.global.pop_front() {
+                return Some(task);
+            }
+        }
+
+        match self.schedule_algo {
+            ScheduleAlgo::FifoGlobal => return self.global.pop_front(),
+            ScheduleAlgo::RoundRobin { budget } => {
+                return self.get_task_round_robin(worker_inner, budget)
+            }
+            _ => {}
+        }
+
         // For every 61 times of execution, dequeue a task from the global queue first.
         // Otherwise, dequeue a task from the local queue. However, if the local queue
         // has no task, dequeue a task from the global queue instead.
@@ -243,7 +415,7 @@ impl MultiThreadScheduler {
             }
             let task = self
                 .global
-                .pop_batch(self.num_workers, local_run_queue, limit);
+                .pop_batch(self.num_workers(), local_run_queue, limit);
             match task {
                 Some(task) => Some(task),
                 None => local_run_queue.pop_front(),
@@ -255,12 +427,32 @@ impl MultiThreadScheduler {
                 None => {
                     let limit = local_run_queue.remaining() as usize;
                     self.global
-                        .pop_batch(self.num_workers, local_run_queue, limit)
+                        .pop_batch(self.num_workers(), local_run_queue, limit)
                 }
             }
         }
     }
 
+    // Takes turns between the local queue and the global queue, polling up to
+    // `budget` tasks from one of them in a row. If the preferred queue is empty,
+    // the other one is used instead.
+    fn get_task_round_robin(&self, worker_inner: &mut worker::Inner, budget: u32) -> Option<Task> {
+        let budget = cmp::max(budget, 1);
+        let local_run_queue = &worker_inner.run_queue;
+
+        if (worker_inner.count / budget) & 1 == 0 {
+            local_run_queue.pop_front().or_else(|| {
+                let limit = local_run_queue.remaining() as usize;
+                self.global
+                    .pop_batch(self.num_workers(), local_run_queue, limit)
+            })
+        } else {
+            self.global
+                .pop_front()
+                .or_else(|| local_run_queue.pop_front())
+        }
+    }
+
     fn get_task_from_searching(&self, worker_inner: &mut worker::Inner) -> Option<Task> {
         const STEAL_TIME: usize = 3;
 
@@ -302,6 +494,11 @@ impl MultiThreadScheduler {
             driver.run_once();
         }
         worker_ctx.wake_yield();
+        // Workers have no tasks of their own under strict FIFO policy, so there is
+        // nothing to steal
+        if self.schedule_algo == ScheduleAlgo::FifoGlobal {
+            return self.global.pop_front();
+        }
         if !worker_inner.run_queue.is_empty() {
             return None;
         }
@@ -312,34 +509,55 @@ impl MultiThreadScheduler {
     fn steal(&self, destination: &LocalQueue) -> Option<Task> {
         let num = self.locals.len();
         let start = (fast_random() >> 56) as usize;
+        // if the NUMA node of the current worker is known, the workers on the same
+        // node are searched in the first round, and the others in the second round
+        let node = self.numa_node(destination);
+        let rounds = if node.is_some() { 2 } else { 1 };
+
+        for round in 0..rounds {
+            for i in 0..num {
+                let i = (start + i) % num;
+                // skip the current worker's local queue
+                // this index is bounded by total worker num
+                let target = self.locals.get(i).unwrap();
+
+                if std::ptr::eq(target, destination) {
+                    continue;
+                }
+                if node.is_some() && (self.numa_nodes[i] == node) != (round == 0) {
+                    continue;
+                }
 
-        for i in 0..num {
-            let i = (start + i) % num;
-            // skip the current worker's local queue
-            // this index is bounded by total worker num
-            let target = self.locals.get(i).unwrap();
-
-            if std::ptr::eq(target, destination) {
-                continue;
-            }
-
-            if let Some(task) = target.steal_into(destination) {
-                #[cfg(feature = "metrics")]
-                self.steal_times
-                    .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                if let Some(task) = target.steal_into(destination) {
+                    #[cfg(feature = "metrics")]
+                    self.steal_times
+                        .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
 
-                return Some(task);
+                    return Some(task);
+                }
             }
         }
 
         // if there is no task to steal, we check global queue for tasks
         self.global.pop_batch(
-            self.num_workers,
+            self.num_workers(),
             destination,
             destination.remaining() as usize,
         )
     }
 
+    // Gets the NUMA node of the worker owning the local queue.
+    fn numa_node(&self, local: &LocalQueue) -> Option<usize> {
+        if self.numa_nodes.is_empty() {
+            return None;
+        }
+        let index = self
+            .locals
+            .iter()
+            .position(|queue| Arc::ptr_eq(&queue.inner, &local.inner))?;
+        self.numa_nodes[index]
+    }
+
     cfg_metrics!(
         pub(crate) fn get_handles(&self) -> &RwLock<Vec<Parker>> {
             &self.handles
@@ -365,21 +583,34 @@ impl Drop for AsyncPoolSpawner {
 }
 
 pub(crate) struct Inner {
-    /// Number of total threads
-    pub(crate) total: usize,
+    /// Number of total threads, including the retired ones
+    pub(crate) total: AtomicUsize,
     /// Core-affinity setting of the threads
     #[cfg_attr(target_os = "macos", allow(unused))]
     is_affinity: bool,
+    /// Cpus that the threads are placed on
+    #[cfg_attr(target_os = "macos", allow(unused))]
+    cpu_set: Option<Vec<usize>>,
     /// Handle for shutting down the pool
     shutdown_handle: Arc<(Mutex<usize>, Condvar)>,
+    /// Whether all the threads have exited and the parkers are released
+    released: AtomicBool,
     /// A callback func to be called after thread starts
-    after_start: Option<CallbackHook>,
+    after_start: Option<WorkerHook>,
     /// A callback func to be called before thread stops
-    before_stop: Option<CallbackHook>,
+    before_stop: Option<WorkerHook>,
+    /// A callback func to be called before thread parks
+    on_park: Option<WorkerHook>,
+    /// A callback func to be called after thread unparks
+    on_unpark: Option<WorkerHook>,
     /// Name of the worker threads
     worker_name: Option<String>,
     /// Stack size of each thread
     stack_size: Option<usize>,
+    /// Seed of the random numbers of the threads
+    rng_seed: Option<u64>,
+    /// Driver shared by the workers
+    driver: Arc<Mutex<Driver>>,
     /// Workers
     #[cfg(feature = "metrics")]
     workers: Mutex<Vec<Arc<Worker>>>,
@@ -389,9 +620,38 @@ fn get_cpu_core() -> usize {
     cmp::max(1, get_cpu_num() as usize)
 }
 
+// Gets the cpus that the worker of the given index is placed on, `None` means
+// that the worker is not restricted.
+#[cfg(not(target_os = "macos"))]
+fn worker_cpus(cpu_set: Option<&[usize]>, is_affinity: bool, index: usize) -> Option<Vec<usize>> {
+    match cpu_set {
+        Some(cpus) if is_affinity => Some(vec![cpus[index % cpus.len()]]),
+        Some(cpus) => Some(cpus.to_vec()),
+        None if is_affinity => Some(vec![index % get_cpu_core()]),
+        None => None,
+    }
+}
+
+// Gets the NUMA node that all the cpus belong to, `None` if the cpus span
+// several nodes or the node is unknown.
+#[cfg(not(target_os = "macos"))]
+fn cpus_numa_node(cpus: &[usize]) -> Option<usize> {
+    let mut nodes = cpus.iter().map(|cpu| get_cpu_node(*cpu));
+    let node = nodes.next()??;
+    if nodes.all(|other| other == Some(node)) {
+        Some(node)
+    } else {
+        None
+    }
+}
+
 fn async_thread_proc(inner: Arc<Inner>, worker: Arc<Worker>, handle: Arc<Handle>) {
+    if let Some(seed) = inner.rng_seed {
+        fastrand::set_thread_seed(seed.wrapping_add(worker.index as u64));
+    }
+    let info = WorkerInfo::new(worker.index, WorkerKind::Async);
     if let Some(f) = inner.after_start.clone() {
-        f();
+        f(&info);
     }
 
     run_worker(worker, handle);
@@ -400,12 +660,167 @@ fn async_thread_proc(inner: Arc<Inner>, worker: Arc<Worker>, handle: Arc<Handle>
     *finished += 1;
 
     // the last thread wakes up the main thread
-    if *finished >= inner.total {
+    if *finished >= inner.total.load(Acquire) {
         cvar.notify_one();
     }
 
     if let Some(f) = inner.before_stop.clone() {
-        f();
+        f(&info);
+    }
+}
+
+// Adjusts the number of workers according to the load of the pool. The pool
+// grows when all the workers are busy while there are still tasks waiting in
+// the queues, and shrinks when some workers keep idle for a while.
+fn auto_scale_proc(inner: Weak<Inner>, scheduler: Weak<MultiThreadScheduler>, min_num: usize) {
+    let mut idle_checks = 0;
+    loop {
+        thread::sleep(SCALE_CHECK_INTERVAL);
+        let (inner, scheduler) = match (inner.upgrade(), scheduler.upgrade()) {
+            (Some(inner), Some(scheduler)) => (inner, scheduler),
+            _ => return,
+        };
+        if scheduler.is_cancel() {
+            return;
+        }
+
+        let num = scheduler.num_workers();
+        let idle_num = scheduler.sleeper.idle_num();
+        if idle_num == 0 && !scheduler.has_no_work() {
+            idle_checks = 0;
+            if num < scheduler.max_workers {
+                let _ = inner.resize(&scheduler, num + 1);
+            }
+        } else if idle_num > 0 && num > min_num {
+            idle_checks += 1;
+            if idle_checks >= SCALE_DOWN_CHECKS {
+                idle_checks = 0;
+                let _ = inner.resize(&scheduler, num - 1);
+            }
+        } else {
+            idle_checks = 0;
+        }
+    }
+}
+
+impl Inner {
+    // Spawns the worker thread of the given index. The caller should hold the
+    // shutdown lock.
+    fn spawn_worker(
+        self: &Arc<Self>,
+        scheduler: &Arc<MultiThreadScheduler>,
+        index: usize,
+    ) -> io::Result<()> {
+        let local_queue = scheduler.create_local_queue(index);
+        let parker = if self.on_park.is_some() || self.on_unpark.is_some() {
+            let hooks = ParkHooks::new(
+                WorkerInfo::new(index, WorkerKind::Async),
+                self.on_park.clone(),
+                self.on_unpark.clone(),
+            );
+            Parker::with_hooks(self.driver.clone(), hooks)
+        } else {
+            Parker::new(self.driver.clone())
+        };
+        let local_run_queue = Box::new(worker::Inner::new(local_queue, parker));
+        let worker = Arc::new(Worker {
+            index,
+            scheduler: scheduler.clone(),
+            inner: RefCell::new(local_run_queue),
+            lifo: RefCell::new(None),
+            yielded: RefCell::new(Vec::new()),
+            #[cfg(feature = "metrics")]
+            poll_stats: PollStats::new(&scheduler.poll_stats_config),
+        });
+
+        let work_arc_handle = scheduler.handle.clone();
+        // set up thread attributes
+        let mut builder = thread::Builder::new();
+
+        if let Some(worker_name) = self.worker_name.clone() {
+            builder = builder.name(format!("async-{index}-{worker_name}"));
+        } else {
+            builder = builder.name(format!("async-{index}"));
+        }
+
+        if let Some(stack_size) = self.stack_size {
+            builder = builder.stack_size(stack_size);
+        }
+
+        // the parker should be reachable before the worker starts running
+        let parker = worker.inner.borrow().parker.clone();
+        scheduler.handles.write().unwrap().push(parker);
+        #[cfg(feature = "metrics")]
+        self.workers.lock().unwrap().push(worker.clone());
+
+        let inner = self.clone();
+        #[cfg(not(target_os = "macos"))]
+        let res = match worker_cpus(self.cpu_set.as_deref(), self.is_affinity, index) {
+            Some(cpus) => builder.spawn(move || {
+                let _ = match cpus.as_slice() {
+                    [cpu] => set_current_affinity(*cpu),
+                    cpus => set_current_affinity_set(cpus),
+                };
+                async_thread_proc(inner, worker, work_arc_handle);
+            }),
+            None => builder.spawn(move || {
+                async_thread_proc(inner, worker, work_arc_handle);
+            }),
+        };
+
+        #[cfg(target_os = "macos")]
+        let res = builder.spawn(move || {
+            async_thread_proc(inner, worker, work_arc_handle);
+        });
+
+        match res {
+            Ok(_) => {
+                self.total.fetch_add(1, AcqRel);
+                Ok(())
+            }
+            Err(e) => {
+                scheduler.handles.write().unwrap().pop();
+                #[cfg(feature = "metrics")]
+                self.workers.lock().unwrap().pop();
+                Err(e)
+            }
+        }
+    }
+
+    // Sets the number of running workers. Retired workers get resumed and new
+    // workers get spawned if needed. Returns the number after being bounded.
+    fn resize(
+        self: &Arc<Self>,
+        scheduler: &Arc<MultiThreadScheduler>,
+        num: usize,
+    ) -> io::Result<usize> {
+        let num = num.clamp(1, scheduler.max_workers);
+        // holds the lock, so that no thread gets spawned after the pool is shut down
+        let (lock, _) = &*self.shutdown_handle;
+        let _finished = lock.lock().unwrap();
+        if scheduler.is_cancel() {
+            return Err(io::Error::other("the async pool has been shut down"));
+        }
+
+        let prev = scheduler.num_workers.swap(num, AcqRel);
+        let spawned = scheduler.handles.read().unwrap().len();
+        for index in spawned..num {
+            scheduler.sleeper.resume_worker();
+            if let Err(e) = self.spawn_worker(scheduler, index) {
+                scheduler.sleeper.retire_worker();
+                scheduler.num_workers.store(index, Release);
+                return Err(e);
+            }
+        }
+
+        // wakes up the retired workers to resume, or the running ones to retire
+        let handles = scheduler.handles.read().unwrap();
+        for index in cmp::min(prev, num)..cmp::max(prev, num) {
+            if let Some(parker) = handles.get(index) {
+                parker.unpark(scheduler.handle.clone());
+            }
+        }
+        Ok(num)
     }
 }
 
@@ -413,89 +828,121 @@ impl AsyncPoolSpawner {
     pub(crate) fn new(builder: &MultiThreadBuilder) -> io::Result<Self> {
         let (handle, driver) = Driver::initialize();
 
-        let thread_num = builder.core_thread_size.unwrap_or_else(get_cpu_core);
+        let mut thread_num = builder.core_thread_size.unwrap_or_else(get_cpu_core);
+        if let Some(min_num) = builder.min_thread_size {
+            thread_num = cmp::max(thread_num, min_num);
+            if let Some(max_num) = builder.max_thread_size {
+                thread_num = cmp::min(thread_num, max_num);
+            }
+        }
+        let max_num = builder.max_thread_size.unwrap_or(thread_num);
+        let mut scheduler = MultiThreadScheduler::with_max_workers(thread_num, max_num, handle);
+        scheduler.schedule_algo = builder.common.schedule_algo;
+        scheduler.task_budget = builder.common.task_budget;
+        scheduler.panic_handler = PanicHandler::new(&builder.common);
+        if builder.blocking_detection.threshold.is_some() {
+            scheduler.poll_watches = (0..max_num).map(|_| PollWatch::default()).collect();
+        }
+        #[cfg(feature = "metrics")]
+        {
+            scheduler.poll_stats_config = builder.poll_stats.clone();
+        }
+        #[cfg(not(target_os = "macos"))]
+        if builder.numa_aware_steal {
+            let nodes = (0..scheduler.locals.len())
+                .map(|index| {
+                    let cpus = worker_cpus(
+                        builder.worker_cpu_set.as_deref(),
+                        builder.common.is_affinity,
+                        index,
+                    )?;
+                    cpus_numa_node(&cpus)
+                })
+                .collect::<Vec<_>>();
+            if nodes.iter().any(Option::is_some) {
+                scheduler.numa_nodes = nodes;
+            }
+        }
         let spawner = AsyncPoolSpawner {
             inner: Arc::new(Inner {
-                total: thread_num,
+                total: AtomicUsize::new(0),
                 is_affinity: builder.common.is_affinity,
+                cpu_set: builder.worker_cpu_set.clone(),
                 shutdown_handle: Arc::new((Mutex::new(0), Condvar::new())),
+                released: AtomicBool::new(false),
                 after_start: builder.common.after_start.clone(),
                 before_stop: builder.common.before_stop.clone(),
+                on_park: builder.common.on_park.clone(),
+                on_unpark: builder.common.on_unpark.clone(),
                 worker_name: builder.common.worker_name.clone(),
                 stack_size: builder.common.stack_size,
+                rng_seed: builder.common.rng_seed,
+                driver,
                 #[cfg(feature = "metrics")]
                 workers: Mutex::new(Vec::with_capacity(thread_num)),
             }),
-            exe_mng_info: Arc::new(MultiThreadScheduler::new(thread_num, handle)),
+            exe_mng_info: Arc::new(scheduler),
         };
-        spawner.create_async_thread_pool(driver)?;
+        spawner.create_async_thread_pool(thread_num)?;
+        if let Some(min_num) = builder.min_thread_size {
+            spawner.create_auto_scale_thread(min_num)?;
+        }
+        if builder.blocking_detection.threshold.is_some() {
+            spawner.create_watchdog_thread(builder.blocking_detection.clone())?;
+        }
         Ok(spawner)
     }
 
-    fn create_async_thread_pool(&self, driver: Arc<Mutex<Driver>>) -> io::Result<()> {
-        let mut workers = vec![];
-        for index in 0..self.inner.total {
-            let local_queue = self.exe_mng_info.create_local_queue(index);
-            let local_run_queue =
-                Box::new(worker::Inner::new(local_queue, Parker::new(driver.clone())));
-            workers.push(Arc::new(Worker {
-                index,
-                scheduler: self.exe_mng_info.clone(),
-                inner: RefCell::new(local_run_queue),
-                lifo: RefCell::new(None),
-                yielded: RefCell::new(Vec::new()),
-            }))
+    fn create_async_thread_pool(&self, thread_num: usize) -> io::Result<()> {
+        let (lock, _) = &*self.inner.shutdown_handle;
+        let _finished = lock.lock().unwrap();
+        for index in 0..thread_num {
+            self.inner.spawn_worker(&self.exe_mng_info, index)?;
         }
+        Ok(())
+    }
 
-        for (worker_id, worker) in workers.drain(..).enumerate() {
-            let work_arc_handle = self.exe_mng_info.handle.clone();
-            #[cfg(feature = "metrics")]
-            self.inner.workers.lock().unwrap().push(worker.clone());
-            // set up thread attributes
-            let mut builder = thread::Builder::new();
-
-            if let Some(worker_name) = self.inner.worker_name.clone() {
-                builder = builder.name(format!("async-{worker_id}-{worker_name}"));
-            } else {
-                builder = builder.name(format!("async-{worker_id}"));
-            }
-
-            if let Some(stack_size) = self.inner.stack_size {
-                builder = builder.stack_size(stack_size);
-            }
-
-            let parker = worker.inner.borrow().parker.clone();
-            self.exe_mng_info.handles.write().unwrap().push(parker);
-
-            let inner = self.inner.clone();
+    fn create_auto_scale_thread(&self, min_num: usize) -> io::Result<()> {
+        let mut builder = thread::Builder::new();
+        if let Some(worker_name) = self.inner.worker_name.clone() {
+            builder = builder.name(format!("async-scaler-{worker_name}"));
+        } else {
+            builder = builder.name("async-scaler".to_string());
+        }
 
-            #[cfg(not(target_os = "macos"))]
-            if self.inner.is_affinity {
-                builder.spawn(move || {
-                    let cpu_core_num = get_cpu_core();
-                    let cpu_id = worker_id % cpu_core_num;
-                    let _ = set_current_affinity(cpu_id);
-                    async_thread_proc(inner, worker, work_arc_handle);
-                })?;
-            } else {
-                builder.spawn(move || {
-                    async_thread_proc(inner, worker, work_arc_handle);
-                })?;
-            }
+        let inner = Arc::downgrade(&self.inner);
+        let scheduler = Arc::downgrade(&self.exe_mng_info);
+        builder.spawn(move || auto_scale_proc(inner, scheduler, min_num))?;
+        Ok(())
+    }
 
-            #[cfg(target_os = "macos")]
-            builder.spawn(move || {
-                async_thread_proc(inner, worker, work_arc_handle);
-            })?;
+    fn create_watchdog_thread(&self, detection: BlockingDetection) -> io::Result<()> {
+        let mut builder = thread::Builder::new();
+        if let Some(worker_name) = self.inner.worker_name.clone() {
+            builder = builder.name(format!("async-watchdog-{worker_name}"));
+        } else {
+            builder = builder.name("async-watchdog".to_string());
         }
+
+        let scheduler = Arc::downgrade(&self.exe_mng_info);
+        builder.spawn(move || watchdog_proc(scheduler, detection))?;
         Ok(())
     }
 
+    /// Sets the number of running workers of the pool. Returns the number
+    /// after being bounded by the max number of workers.
+    pub(crate) fn set_worker_num(&self, num: usize) -> io::Result<usize> {
+        self.inner.resize(&self.exe_mng_info, num)
+    }
+
     pub(crate) fn spawn<T>(&self, builder: &TaskBuilder, task: T) -> JoinHandle<T::Output>
     where
         T: Future + Send + 'static,
         T::Output: Send + 'static,
     {
+        if self.exe_mng_info.is_cancel() {
+            return Task::create_rejected_task(builder, task, ErrorKind::RuntimeShutdown.into());
+        }
         let exe_scheduler = Arc::downgrade(&self.exe_mng_info);
         let (task, join_handle) =
             Task::create_task(builder, exe_scheduler, task, VirtualTableType::Ylong);
@@ -553,25 +1000,24 @@ impl AsyncPoolSpawner {
     {
         let exe_scheduler = Arc::downgrade(&self.exe_mng_info);
         let raw_task = Task::create_raw_task(builder, exe_scheduler, task, VirtualTableType::Ylong);
-        let handle = JoinHandle::new(raw_task);
+        let handle = JoinHandle::new(raw_task, builder);
         let task = Task(raw_task);
         self.exe_mng_info.schedule(task, false);
         handle
     }
 
-    /// Waits 3 seconds for threads to finish before releasing the async pool.
+    /// Waits for threads to finish before releasing the async pool.
     /// If threads could not finish before releasing, there could be possible
     /// memory leak.
-    fn release_wait(&self) -> Result<(), ()> {
+    fn release_wait(&self, timeout: Duration) -> Result<(), ()> {
         self.exe_mng_info.cancel();
         let pair = self.inner.shutdown_handle.clone();
-        let total = self.inner.total;
         let (lock, cvar) = &*pair;
         let finished = lock.lock().unwrap();
+        // no more threads could be spawned after the pool is canceled
+        let total = self.inner.total.load(Acquire);
         let res = cvar
-            .wait_timeout_while(finished, ASYNC_THREAD_QUIT_WAIT_TIME, |&mut finished| {
-                finished < total
-            })
+            .wait_timeout_while(finished, timeout, |&mut finished| finished < total)
             .unwrap();
         // if time limit has been reached, the unfinished threads would not get released
         if res.1.timed_out() {
@@ -581,15 +1027,38 @@ impl AsyncPoolSpawner {
         }
     }
 
+    /// Shuts down the async pool. Tasks left in the queues get canceled, and
+    /// new tasks are rejected. Returns whether all the threads have exited
+    /// within the timeout.
+    pub(crate) fn shutdown(&self, timeout: Duration) -> bool {
+        if let Ok(()) = self.release_wait(timeout) {
+            self.release_parkers();
+            true
+        } else {
+            false
+        }
+    }
+
+    // Releases the parkers of the workers after all the threads have exited.
+    // Only the first call takes effect.
+    fn release_parkers(&self) {
+        if self.inner.released.swap(true, AcqRel) {
+            return;
+        }
+        let mut join_handle = self.exe_mng_info.handles.write().unwrap();
+        #[allow(clippy::mem_replace_with_default)]
+        let mut worker_handles = std::mem::replace(join_handle.as_mut(), vec![]);
+        drop(join_handle);
+        for parker in worker_handles.drain(..) {
+            parker.release();
+        }
+    }
+
     pub(crate) fn release(&self) {
-        if let Ok(()) = self.release_wait() {
-            let mut join_handle = self.exe_mng_info.handles.write().unwrap();
-            #[allow(clippy::mem_replace_with_default)]
-            let mut worker_handles = std::mem::replace(join_handle.as_mut(), vec![]);
-            drop(join_handle);
-            for parker in worker_handles.drain(..) {
-                parker.release();
-            }
+        // the pool might have been shut down by the user already, but without
+        // waiting for the threads to exit
+        if !self.inner.released.load(Acquire) {
+            self.shutdown(ASYNC_THREAD_QUIT_WAIT_TIME);
         }
     }
 
@@ -609,19 +1078,29 @@ impl AsyncPoolSpawner {
 pub(crate) mod test {
     use std::future::Future;
     use std::pin::Pin;
+    use std::sync::atomic::AtomicBool;
     use std::sync::atomic::Ordering::{Acquire, Release};
     use std::sync::mpsc::channel;
+    #[cfg(feature = "multi_instance_runtime")]
+    use std::sync::mpsc::Sender;
     use std::sync::{Arc, Mutex};
     use std::task::{Context, Poll};
     use std::thread;
+    use std::time::Duration;
 
     use crate::builder::RuntimeBuilder;
+    #[cfg(feature = "multi_instance_runtime")]
+    use crate::builder::ScheduleAlgo;
+    #[cfg(not(target_os = "macos"))]
+    use crate::executor::async_pool::worker_cpus;
     use crate::executor::async_pool::{get_cpu_core, AsyncPoolSpawner, MultiThreadScheduler};
     use crate::executor::driver::Driver;
     use crate::executor::parker::Parker;
     use crate::executor::queue::LocalQueue;
+    #[cfg(feature = "multi_instance_runtime")]
+    use crate::executor::Handle;
     use crate::executor::{worker, Schedule};
-    use crate::task::{JoinHandle, Task, TaskBuilder, VirtualTableType};
+    use crate::task::{JoinHandle, Qos, Task, TaskBuilder, VirtualTableType};
 
     pub struct TestFuture {
         value: usize,
@@ -958,7 +1437,7 @@ pub(crate) mod test {
         let thread_pool_builder = RuntimeBuilder::new_multi_thread();
         let async_pool_spawner = AsyncPoolSpawner::new(&thread_pool_builder).unwrap();
         assert_eq!(
-            async_pool_spawner.inner.total,
+            async_pool_spawner.inner.total.load(Acquire),
             thread_pool_builder
                 .core_thread_size
                 .unwrap_or_else(get_cpu_core)
@@ -974,6 +1453,30 @@ pub(crate) mod test {
         assert!(!async_pool_spawner.exe_mng_info.is_cancel.load(Acquire));
     }
 
+    /// UT test cases for `AsyncPoolSpawner::release` after a shutdown without
+    /// waiting.
+    ///
+    /// # Brief
+    /// 1. Create an async_pool_spawner and shut it down with a zero timeout
+    /// 2. Call release()
+    /// 3. Check that the parkers of the workers are released
+    #[test]
+    fn ut_async_pool_spawner_release_after_shutdown() {
+        let thread_pool_builder = RuntimeBuilder::new_multi_thread().worker_num(2);
+        let async_pool_spawner = AsyncPoolSpawner::new(&thread_pool_builder).unwrap();
+        async_pool_spawner.shutdown(Duration::ZERO);
+        assert!(async_pool_spawner.exe_mng_info.is_cancel());
+
+        async_pool_spawner.release();
+        assert!(async_pool_spawner.inner.released.load(Acquire));
+        assert!(async_pool_spawner
+            .exe_mng_info
+            .handles
+            .read()
+            .unwrap()
+            .is_empty());
+    }
+
     /// UT test cases for `create_async_thread_pool`.
     ///
     /// # Brief
@@ -986,6 +1489,56 @@ pub(crate) mod test {
         let _ = AsyncPoolSpawner::new(&thread_pool_builder.is_affinity(false)).unwrap();
     }
 
+    /// UT test cases for `worker_cpus`.
+    ///
+    /// # Brief
+    /// 1. Check that a worker is not restricted without any affinity setting
+    /// 2. Check that each worker gets a single cpu of the set in turns when
+    ///    `is_affinity` is set
+    /// 3. Check that each worker gets the whole set when `is_affinity` is not
+    ///    set
+    #[test]
+    #[cfg(not(target_os = "macos"))]
+    fn ut_async_pool_worker_cpus() {
+        assert_eq!(worker_cpus(None, false, 3), None);
+        assert_eq!(worker_cpus(Some(&[2, 3, 5]), true, 0), Some(vec![2]));
+        assert_eq!(worker_cpus(Some(&[2, 3, 5]), true, 4), Some(vec![3]));
+        assert_eq!(worker_cpus(Some(&[2, 3, 5]), false, 4), Some(vec![2, 3, 5]));
+    }
+
+    /// UT test cases for NUMA-aware stealing.
+    ///
+    /// # Brief
+    /// 1. Create a scheduler of 3 workers, where worker 0 and 2 are on node 0
+    ///    while worker 1 is on node 1
+    /// 2. Push a task into the local queues of worker 1 and worker 2
+    /// 3. Steal tasks for worker 0, check that worker 2 is stolen first
+    #[test]
+    fn ut_async_pool_numa_aware_steal() {
+        let (arc_handle, _) = Driver::initialize();
+        let mut executor_mng_info = MultiThreadScheduler::new(3, arc_handle.clone());
+        executor_mng_info.numa_nodes = vec![Some(0), Some(1), Some(0)];
+
+        let builder = TaskBuilder::new();
+        let exe_scheduler = Arc::downgrade(&Arc::new(MultiThreadScheduler::new(1, arc_handle)));
+        for index in [1, 2] {
+            let (task, _) = create_task(
+                &builder,
+                exe_scheduler.clone(),
+                test_future(),
+                VirtualTableType::Ylong,
+            );
+            executor_mng_info.locals[index].push_back(task, &executor_mng_info.global);
+        }
+
+        let local = executor_mng_info.create_local_queue(0);
+        assert!(executor_mng_info.steal(&local).is_some());
+        assert!(executor_mng_info.locals[2].is_empty());
+        assert!(!executor_mng_info.locals[1].is_empty());
+        assert!(executor_mng_info.steal(&local).is_some());
+        assert!(executor_mng_info.locals[1].is_empty());
+    }
+
     /// UT test cases for `UnboundedSender`.
     ///
     /// # Brief
@@ -997,4 +1550,225 @@ pub(crate) mod test {
         let thread_pool_builder = RuntimeBuilder::new_multi_thread();
         let _ = AsyncPoolSpawner::new(&thread_pool_builder.is_affinity(true)).unwrap();
     }
+
+    /// UT test cases for qos inheritance in `AsyncPoolSpawner::spawn`.
+    ///
+    /// # Brief
+    /// 1. Spawn a task with `UserInteractive` qos onto the async pool
+    /// 2. Inside the task, spawn a child task with `Inherent` qos
+    /// 3. Check that the child task gets the parent's qos level
+    /// 4. Check that a task spawned with `Inherent` outside of any task gets
+    ///    the default qos level
+    #[test]
+    fn ut_async_pool_spawner_qos_inherent() {
+        let thread_pool_builder = RuntimeBuilder::new_multi_thread().worker_num(2);
+        let spawner = Arc::new(AsyncPoolSpawner::new(&thread_pool_builder).unwrap());
+
+        let spawner_clone = spawner.clone();
+        let parent = TaskBuilder::new().qos(Qos::UserInteractive);
+        let handle = spawner.spawn(&parent, async move {
+            let child = TaskBuilder::new().qos(Qos::Inherent);
+            let handle = spawner_clone.spawn(&child, async {});
+            handle.raw.header().qos
+        });
+        let (tx, rx) = channel();
+        spawner.spawn(&TaskBuilder::new(), async move {
+            tx.send(handle.await.unwrap()).unwrap();
+        });
+        assert_eq!(rx.recv().unwrap(), Qos::UserInteractive);
+
+        let child = TaskBuilder::new().qos(Qos::Inherent);
+        let handle = spawner.spawn(&child, async {});
+        assert_eq!(handle.raw.header().qos, Qos::Default);
+    }
+
+    /// UT test cases for qos ordering in `AsyncPoolSpawner::spawn`.
+    ///
+    /// # Brief
+    /// 1. Create an async pool with one worker and occupy the worker with a
+    ///    busy task
+    /// 2. Spawn tasks with `Background` qos first and then tasks with
+    ///    `UserInteractive` qos
+    /// 3. Release the worker and check that all the `UserInteractive` tasks are
+    ///    executed before the `Background` tasks
+    #[test]
+    fn ut_async_pool_spawner_qos_order() {
+        let thread_pool_builder = RuntimeBuilder::new_multi_thread().worker_num(1);
+        let spawner = AsyncPoolSpawner::new(&thread_pool_builder).unwrap();
+
+        let (tx, rx) = channel();
+        let release = Arc::new(AtomicBool::new(false));
+        let release_clone = release.clone();
+        spawner.spawn(&TaskBuilder::new(), async move {
+            tx.send(()).unwrap();
+            while !release_clone.load(Acquire) {
+                std::hint::spin_loop();
+            }
+        });
+        rx.recv().unwrap();
+
+        let (tx, rx) = channel();
+        for qos in [Qos::Background, Qos::UserInteractive] {
+            for _ in 0..5 {
+                let tx = tx.clone();
+                spawner.spawn(&TaskBuilder::new().qos(qos), async move {
+                    tx.send(qos).unwrap();
+                });
+            }
+        }
+        release.store(true, Release);
+
+        let order: Vec<Qos> = (0..10).map(|_| rx.recv().unwrap()).collect();
+        assert!(order[..5].iter().all(|qos| *qos == Qos::UserInteractive));
+        assert!(order[5..].iter().all(|qos| *qos == Qos::Background));
+    }
+
+    /// UT test cases for `ScheduleAlgo::LifoSlot`.
+    ///
+    /// # Brief
+    /// 1. Create a runtime with one worker under `LifoSlot` policy
+    /// 2. Inside a task, spawn three child tasks in a row
+    /// 3. Check that the last spawned task runs first through the lifo slot,
+    ///    and the other tasks run in FIFO order
+    #[test]
+    #[cfg(feature = "multi_instance_runtime")]
+    fn ut_async_pool_schedule_lifo_slot() {
+        let runtime = RuntimeBuilder::new_multi_thread()
+            .worker_num(1)
+            .schedule_algo(ScheduleAlgo::LifoSlot)
+            .build()
+            .unwrap();
+
+        let (tx, rx) = channel();
+        runtime.spawn(async move {
+            let handle = Handle::current();
+            for i in 1..=3 {
+                let tx = tx.clone();
+                handle.spawn(async move { tx.send(i).unwrap() });
+            }
+        });
+        let order: Vec<usize> = (0..3).map(|_| rx.recv().unwrap()).collect();
+        assert_eq!(order, vec![3, 1, 2]);
+    }
+
+    /// UT test cases for the bound of the lifo slot.
+    ///
+    /// # Brief
+    /// 1. Create a runtime with one worker under `LifoSlot` policy
+    /// 2. Inside a task, spawn a task and then a chain of tasks, each of
+    ///    which spawns the next one
+    /// 3. Check that the first task does not wait for the whole chain
+    #[test]
+    #[cfg(feature = "multi_instance_runtime")]
+    fn ut_async_pool_schedule_lifo_slot_bounded() {
+        fn spawn_chain(tx: Sender<usize>, n: usize) {
+            Handle::current().spawn(async move {
+                tx.send(n).unwrap();
+                if n > 0 {
+                    spawn_chain(tx, n - 1);
+                }
+            });
+        }
+
+        let runtime = RuntimeBuilder::new_multi_thread()
+            .worker_num(1)
+            .schedule_algo(ScheduleAlgo::LifoSlot)
+            .build()
+            .unwrap();
+
+        let (tx, rx) = channel();
+        runtime.spawn(async move {
+            let other = tx.clone();
+            Handle::current().spawn(async move { other.send(usize::MAX).unwrap() });
+            spawn_chain(tx, 9);
+        });
+        let order: Vec<usize> = (0..11).map(|_| rx.recv().unwrap()).collect();
+        let pos = order.iter().position(|n| *n == usize::MAX).unwrap();
+        assert!(pos <= 3);
+    }
+
+    /// UT test cases for `ScheduleAlgo::FifoGlobal`.
+    ///
+    /// # Brief
+    /// 1. Create a runtime with one worker under `FifoGlobal` policy
+    /// 2. Inside a task, spawn a child task, then wait for a task spawned
+    ///    outside of the runtime, and then spawn another child task
+    /// 3. Check that the tasks run in the order they are spawned
+    #[test]
+    #[cfg(feature = "multi_instance_runtime")]
+    fn ut_async_pool_schedule_fifo_global() {
+        let runtime = RuntimeBuilder::new_multi_thread()
+            .worker_num(1)
+            .schedule_algo(ScheduleAlgo::FifoGlobal)
+            .build()
+            .unwrap();
+
+        let (tx, rx) = channel();
+        let (ready_tx, ready_rx) = channel();
+        let release = Arc::new(AtomicBool::new(false));
+        let release_clone = release.clone();
+        let tx_clone = tx.clone();
+        runtime.spawn(async move {
+            let handle = Handle::current();
+            let tx = tx_clone.clone();
+            handle.spawn(async move { tx.send(1).unwrap() });
+            ready_tx.send(()).unwrap();
+            while !release_clone.load(Acquire) {
+                std::hint::spin_loop();
+            }
+            handle.spawn(async move { tx_clone.send(3).unwrap() });
+        });
+        ready_rx.recv().unwrap();
+        runtime.spawn(async move { tx.send(2).unwrap() });
+        release.store(true, Release);
+
+        let order: Vec<usize> = (0..3).map(|_| rx.recv().unwrap()).collect();
+        assert_eq!(order, vec![1, 2, 3]);
+    }
+
+    /// UT test cases for `ScheduleAlgo::RoundRobin`.
+    ///
+    /// # Brief
+    /// 1. Create a runtime with one worker under `RoundRobin` policy
+    /// 2. Fill the local queue of the worker with tasks, and then spawn tasks
+    ///    outside of the runtime into the global queue
+    /// 3. Check that the worker takes turns between the two queues
+    #[test]
+    #[cfg(feature = "multi_instance_runtime")]
+    fn ut_async_pool_schedule_round_robin() {
+        let runtime = RuntimeBuilder::new_multi_thread()
+            .worker_num(1)
+            .schedule_algo(ScheduleAlgo::RoundRobin { budget: 2 })
+            .build()
+            .unwrap();
+
+        let (tx, rx) = channel();
+        let (ready_tx, ready_rx) = channel();
+        let release = Arc::new(AtomicBool::new(false));
+        let release_clone = release.clone();
+        let tx_clone = tx.clone();
+        runtime.spawn(async move {
+            let handle = Handle::current();
+            for _ in 0..20 {
+                let tx = tx_clone.clone();
+                handle.spawn(async move { tx.send(true).unwrap() });
+            }
+            ready_tx.send(()).unwrap();
+            while !release_clone.load(Acquire) {
+                std::hint::spin_loop();
+            }
+        });
+        ready_rx.recv().unwrap();
+        for _ in 0..20 {
+            let tx = tx.clone();
+            runtime.spawn(async move { tx.send(false).unwrap() });
+        }
+        release.store(true, Release);
+
+        // true for the tasks in the local queue, false for the global ones
+        let order: Vec<bool> = (0..40).map(|_| rx.recv().unwrap()).collect();
+        let pos = order.iter().position(|local| !*local).unwrap();
+        assert!(pos < 6);
+        assert_eq!(order.iter().filter(|local| **local).count(), 20);
+    }
 }
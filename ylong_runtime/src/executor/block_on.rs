@@ -16,6 +16,8 @@ use std::mem;
 use std::pin::Pin;
 use std::sync::{Arc, Condvar, Mutex};
 use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+#[cfg(all(feature = "sync", feature = "time"))]
+use std::time::{Duration, Instant};
 
 static BLOCK_ON_RAW_WAKER_VIRTUAL_TABLE: RawWakerVTable =
     RawWakerVTable::new(clone, wake, wake_by_ref, drop);
@@ -62,6 +64,11 @@ impl ThreadParker {
         self.inner.notified();
     }
 
+    #[cfg(all(feature = "sync", feature = "time"))]
+    pub(crate) fn notified_timeout(&self, time: Duration) {
+        self.inner.notified_timeout(time);
+    }
+
     pub(crate) fn waker(&self) -> Waker {
         let data = Arc::into_raw(self.inner.clone()).cast::<()>();
         unsafe { Waker::from_raw(RawWaker::new(data, &BLOCK_ON_RAW_WAKER_VIRTUAL_TABLE)) }
@@ -83,6 +90,16 @@ impl Parker {
         *guard = false;
     }
 
+    #[cfg(all(feature = "sync", feature = "time"))]
+    fn notified_timeout(&self, time: Duration) {
+        let guard = self.mutex.lock().unwrap();
+        let (mut guard, _) = self
+            .condvar
+            .wait_timeout_while(guard, time, |notified| !*notified)
+            .unwrap();
+        *guard = false;
+    }
+
     fn notify_one(&self) {
         let mut guard = self.mutex.lock().unwrap();
         *guard = true;
@@ -109,3 +126,60 @@ where
         parker.notified();
     }
 }
+
+/// Panics if the current thread is driving asynchronous tasks, because
+/// blocking it would stall every task scheduled on it.
+#[cfg(feature = "sync")]
+pub(crate) fn check_blocking_context(api: &str) {
+    #[cfg(not(feature = "ffrt"))]
+    if crate::executor::worker::is_driving_tasks() {
+        panic!(
+            "`{api}` cannot be called in a runtime context. This happens because \
+            it tries to block the current thread which is being used to drive \
+            asynchronous tasks, use the async version of it instead."
+        );
+    }
+    #[cfg(feature = "ffrt")]
+    let _ = api;
+}
+
+/// Blocks the current thread on a future woken by other threads, such as a
+/// channel operation. `api` is the name of the calling method.
+///
+/// # Panics
+/// Panics if the current thread is driving asynchronous tasks.
+#[cfg(feature = "sync")]
+pub(crate) fn blocking_wait<T>(api: &str, task: T) -> T::Output
+where
+    T: Future,
+{
+    check_blocking_context(api);
+    block_on(task)
+}
+
+/// Same as [`blocking_wait`], but gives up and returns `None` once `time` has
+/// elapsed.
+#[cfg(all(feature = "sync", feature = "time"))]
+pub(crate) fn blocking_wait_timeout<T>(api: &str, mut task: T, time: Duration) -> Option<T::Output>
+where
+    T: Future,
+{
+    check_blocking_context(api);
+    let deadline = Instant::now() + time;
+    let parker = ThreadParker::new();
+    let waker = parker.waker();
+    let mut cx = Context::from_waker(&waker);
+
+    let mut future = unsafe { Pin::new_unchecked(&mut task) };
+    loop {
+        if let Poll::Ready(res) = future.as_mut().poll(&mut cx) {
+            return Some(res);
+        }
+
+        let now = Instant::now();
+        if now >= deadline {
+            return None;
+        }
+        parker.notified_timeout(deadline - now);
+    }
+}
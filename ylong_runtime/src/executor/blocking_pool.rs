@@ -20,11 +20,18 @@ use std::task::{Context, Poll};
 use std::thread;
 use std::time::Duration;
 
-use crate::builder::{CallbackHook, CommonBuilder};
+use crate::builder::{BlockingRejectPolicy, CommonBuilder, WorkerHook, WorkerInfo, WorkerKind};
 use crate::error::{ErrorKind, ScheduleError};
-use crate::executor::PlaceholderScheduler;
+use crate::executor::{RuntimeInner, Schedule};
+#[cfg(feature = "metrics")]
+use crate::metrics::TaskRegistry;
 use crate::task;
-use crate::task::{JoinHandle, TaskBuilder, VirtualTableType};
+use crate::task::{
+    JoinHandle, PanicHandler, PanicPayload, TaskBuilder, TaskInfo, VirtualTableType,
+};
+#[cfg(not(target_os = "macos"))]
+use crate::util::core_affinity::set_current_affinity_set;
+use crate::util::thread_name::set_current_name;
 
 pub(crate) const BLOCKING_THREAD_QUIT_WAIT_TIME: Duration = Duration::from_secs(1);
 pub(crate) const DEFAULT_MAX_BLOCKING_POOL_SIZE: u8 = 16;
@@ -54,50 +61,87 @@ impl BlockPoolSpawner {
                     queue: VecDeque::new(),
                     total_thread_num: 0,
                     idle_thread_num: 0,
+                    active_thread_num: 0,
                     notify_num: 0,
+                    assigned_num: 0,
                     current_permanent_thread_num: 0,
                     shutdown: false,
                     worker_id: 0,
                     worker_threads: VecDeque::new(),
                 }),
                 condvar: Condvar::new(),
+                not_full_condvar: Condvar::new(),
                 shutdown_shared: Mutex::new(false),
                 shutdown_condvar: Condvar::new(),
                 stack_size: builder.stack_size,
+                cpu_set: builder.blocking_cpu_set.clone(),
                 after_start: builder.after_start.clone(),
                 before_stop: builder.before_stop.clone(),
                 max_thread_num,
                 keep_alive_time,
                 max_permanent_thread_num: builder.blocking_permanent_thread_num,
+                queue_size: builder.blocking_queue_size,
+                reject_policy: builder.blocking_reject_policy,
+                scheduler: Arc::new(BlockingScheduler {
+                    panic_handler: PanicHandler::new(builder),
+                    #[cfg(feature = "metrics")]
+                    registry: TaskRegistry::default(),
+                }),
             }),
         }
     }
 
-    pub fn shutdown(&mut self, timeout: Duration) -> bool {
-        let mut shared = self.inner.shared.lock().unwrap();
+    pub(crate) fn set_runtime(&self, runtime: Weak<RuntimeInner>) {
+        self.inner.scheduler.panic_handler.set_runtime(runtime);
+    }
 
-        if shared.shutdown {
+    pub fn shutdown(&self, timeout: Duration) -> bool {
+        if !self.cancel() {
             return false;
         }
-        self.inner.condvar.notify_all();
+        let mut shared = self.inner.shared.lock().unwrap();
         let workers = std::mem::take(&mut shared.worker_threads);
+        let thread_num = shared.total_thread_num;
         drop(shared);
+        if thread_num == 0 {
+            return true;
+        }
 
         let shutdown_shared = self.inner.shutdown_shared.lock().unwrap();
-
-        if *self
+        let (_finished, res) = self
             .inner
             .shutdown_condvar
-            .wait_timeout(shutdown_shared, timeout)
-            .unwrap()
-            .0
-        {
-            for handle in workers {
-                let _ = handle.1.join();
-            }
-            return true;
+            .wait_timeout_while(shutdown_shared, timeout, |finished| !*finished)
+            .unwrap();
+        if res.timed_out() {
+            return false;
+        }
+        for handle in workers {
+            let _ = handle.1.join();
         }
-        false
+        true
+    }
+
+    /// Stops accepting new tasks and cancels the tasks that have not been
+    /// executed yet, without waiting for the threads to exit. Returns false if
+    /// the pool has been shut down already.
+    pub(crate) fn cancel(&self) -> bool {
+        let mut shared = self.inner.shared.lock().unwrap();
+
+        if shared.shutdown {
+            return false;
+        }
+        shared.shutdown = true;
+        self.inner.condvar.notify_all();
+        self.inner.not_full_condvar.notify_all();
+        let tasks = std::mem::take(&mut shared.queue);
+        shared.assigned_num = 0;
+        drop(shared);
+
+        for task in tasks {
+            task.cancel_queued();
+        }
+        true
     }
 }
 
@@ -111,6 +155,9 @@ struct Inner {
     /// Used for thread synchronization
     condvar: Condvar,
 
+    /// Used for waking up the callers waiting for the queue to have room
+    not_full_condvar: Condvar,
+
     /// Stores the notification for shutting down
     shutdown_shared: Mutex<bool>,
 
@@ -120,11 +167,15 @@ struct Inner {
     /// Stack size of each thread in the blocking pool
     stack_size: Option<usize>,
 
+    /// Cpus that the threads are restricted to
+    #[cfg_attr(target_os = "macos", allow(unused))]
+    cpu_set: Option<Vec<usize>>,
+
     /// A callback func to be called after thread starts
-    after_start: Option<CallbackHook>,
+    after_start: Option<WorkerHook>,
 
     /// A callback func to be called before thread stops
-    before_stop: Option<CallbackHook>,
+    before_stop: Option<WorkerHook>,
 
     /// Maximum thread number for the blocking pool
     max_thread_num: u8,
@@ -134,6 +185,39 @@ struct Inner {
 
     /// Max number of permanent threads
     max_permanent_thread_num: u8,
+
+    /// Max number of tasks waiting inside the queue, `None` means unbounded
+    queue_size: Option<usize>,
+
+    /// Policy applied when the queue is full
+    reject_policy: BlockingRejectPolicy,
+
+    /// Scheduler of the tasks inside the pool
+    scheduler: Arc<BlockingScheduler>,
+}
+
+/// Blocking tasks never get rescheduled, the scheduler only handles their
+/// panics.
+struct BlockingScheduler {
+    panic_handler: PanicHandler,
+    /// Live tasks of the pool
+    #[cfg(feature = "metrics")]
+    registry: TaskRegistry,
+}
+
+impl Schedule for BlockingScheduler {
+    fn schedule(&self, _task: Task, _lifo: bool) {
+        panic!("blocking tasks should not be rescheduled");
+    }
+
+    fn on_task_panic(&self, info: &TaskInfo, payload: &PanicPayload) {
+        self.panic_handler.handle(info, payload);
+    }
+
+    #[cfg(feature = "metrics")]
+    fn registry(&self) -> Option<&TaskRegistry> {
+        Some(&self.registry)
+    }
 }
 
 /// Shared info among the blocking pool
@@ -147,9 +231,19 @@ struct Shared {
     /// Number of current idle threads
     idle_thread_num: u8,
 
+    /// Number of threads running a task
+    active_thread_num: u8,
+
     /// Number of calls to `notify_one`, prevents spurious wakeup of condvar.
     notify_num: u8,
 
+    /// Number of tasks inside the queue which already have a thread woken up
+    /// or spawned for them. The rest of the queue are the waiting tasks.
+    ///
+    /// A queued task may be taken by any running thread rather than the one
+    /// woken up for it, so the number is decreased whenever a task is popped.
+    assigned_num: usize,
+
     /// number of permanent threads in the pool
     current_permanent_thread_num: u8,
 
@@ -191,33 +285,70 @@ impl BlockPoolSpawner {
         Ok(())
     }
 
+    /// Spawns a task onto the blocking pool.
+    ///
+    /// If the blocking queue is full, the task is handled by the reject policy
+    /// of the pool. The caller gets blocked under `BlockingRejectPolicy::Wait`,
+    /// and runs the task itself under `BlockingRejectPolicy::CallerRuns`.
     pub(crate) fn spawn_blocking<T, R>(&self, builder: &TaskBuilder, task: T) -> JoinHandle<R>
     where
         T: FnOnce() -> R,
         T: Send + 'static,
         R: Send + 'static,
     {
-        let task = BlockingTask(Some(task));
-        let scheduler: Weak<PlaceholderScheduler> = Weak::new();
+        let scheduler = Arc::downgrade(&self.inner.scheduler);
+        let mut shared = self.inner.shared.lock().unwrap();
+        loop {
+            if shared.shutdown {
+                drop(shared);
+                let task = BlockingTask::new(None, task);
+                return Task::create_rejected_task(
+                    builder,
+                    task,
+                    ErrorKind::RuntimeShutdown.into(),
+                );
+            }
+            if !self.inner.is_full(&shared) {
+                break;
+            }
+            match self.inner.reject_policy {
+                BlockingRejectPolicy::Error => {
+                    drop(shared);
+                    let task = BlockingTask::new(None, task);
+                    return Task::create_rejected_task(
+                        builder,
+                        task,
+                        ErrorKind::BlockQueueFull.into(),
+                    );
+                }
+                BlockingRejectPolicy::CallerRuns => {
+                    drop(shared);
+                    // the caller thread keeps its own name
+                    let task = BlockingTask::new(None, task);
+                    let (task, handle) =
+                        Task::create_task(builder, scheduler, task, VirtualTableType::Ylong);
+                    task.run();
+                    return handle;
+                }
+                BlockingRejectPolicy::Wait => {
+                    shared = self.inner.not_full_condvar.wait(shared).unwrap();
+                }
+            }
+        }
+
+        let task = BlockingTask::new(builder.name.clone(), task);
         let (task, handle) = Task::create_task(builder, scheduler, task, VirtualTableType::Ylong);
-        self.spawn(task);
+        self.spawn(shared, task);
         handle
     }
 
-    fn spawn(&self, task: Task) {
-        let mut shared = self.inner.shared.lock().unwrap();
-
-        // if the shutdown flag is on, cancel the task
-        assert!(
-            !shared.shutdown,
-            "The blocking runtime has already been shutdown, cannot spawn tasks"
-        );
-
+    fn spawn(&self, mut shared: MutexGuard<'_, Shared>, task: Task) {
         shared.queue.push_back(task);
         // there are idle threads, wake up one
         if shared.idle_thread_num != 0 {
             shared.idle_thread_num -= 1;
             shared.notify_num += 1;
+            shared.assigned_num += 1;
             self.inner.condvar.notify_one();
             return;
         }
@@ -229,6 +360,7 @@ impl BlockPoolSpawner {
         // there is no idle thread and the maximum thread number has not been reached,
         // therefore create a new thread
         shared.total_thread_num += 1;
+        shared.assigned_num += 1;
         // sets all required attributes for the thread
         let worker_id = shared.worker_id;
         let mut builder = thread::Builder::new().name(format!("block-{worker_id}"));
@@ -250,6 +382,16 @@ impl BlockPoolSpawner {
     }
 }
 
+impl Shared {
+    // Pops a task from the queue, which uses up one assignment no matter
+    // whether this thread is the one woken up for it.
+    fn pop_task(&mut self) -> Option<Task> {
+        let task = self.queue.pop_front()?;
+        self.assigned_num = self.assigned_num.saturating_sub(1);
+        Some(task)
+    }
+}
+
 enum WaitState {
     Continue,
     ExitWait,
@@ -257,6 +399,19 @@ enum WaitState {
 }
 
 impl<'a> Inner {
+    // returns true if the spawned task could neither get a thread nor wait
+    // inside the queue
+    fn is_full(&self, shared: &Shared) -> bool {
+        match self.queue_size {
+            Some(queue_size) => {
+                shared.idle_thread_num == 0
+                    && shared.total_thread_num >= self.max_thread_num
+                    && shared.queue.len().saturating_sub(shared.assigned_num) >= queue_size
+            }
+            None => false,
+        }
+    }
+
     // return true if it is not a spurious wakeup
     fn wait_permanent(&'a self, mut shared: MutexGuard<'a, Shared>) -> (bool, MutexGuard<Shared>) {
         shared.current_permanent_thread_num += 1;
@@ -311,6 +466,8 @@ impl<'a> Inner {
         worker_id: usize,
     ) -> (bool, MutexGuard<Shared>) {
         shared.idle_thread_num += 1;
+        // an idle thread makes room for the callers waiting on a full queue
+        self.not_full_condvar.notify_one();
         while !shared.shutdown {
             // permanent waits, the thread keep alive until shutdown.
             if shared.current_permanent_thread_num < self.max_permanent_thread_num {
@@ -334,17 +491,25 @@ impl<'a> Inner {
     }
 
     fn run(&self, worker_id: usize) {
+        #[cfg(not(target_os = "macos"))]
+        if let Some(cpus) = &self.cpu_set {
+            let _ = set_current_affinity_set(cpus);
+        }
+        let info = WorkerInfo::new(worker_id, WorkerKind::Blocking);
         if let Some(f) = &self.after_start {
-            f()
+            f(&info)
         }
 
         let mut shared = self.shared.lock().unwrap();
         loop {
             // get a task from the global queue
-            while let Some(task) = shared.queue.pop_front() {
+            while let Some(task) = shared.pop_task() {
+                shared.active_thread_num += 1;
+                self.not_full_condvar.notify_one();
                 drop(shared);
                 task.run();
                 shared = self.shared.lock().unwrap();
+                shared.active_thread_num -= 1;
             }
 
             let (is_released, guard) = self.wait(shared, worker_id);
@@ -354,9 +519,10 @@ impl<'a> Inner {
                 break;
             }
             if shared.shutdown {
-                // empty the tasks in the global queue
-                while let Some(_task) = shared.queue.pop_front() {
+                // cancel the tasks left in the global queue
+                while let Some(task) = shared.pop_task() {
                     drop(shared);
+                    task.cancel_queued();
                     shared = self.shared.lock().unwrap();
                 }
                 break;
@@ -373,8 +539,10 @@ impl<'a> Inner {
             .checked_sub(1)
             .expect("idle thread num underflowed");
 
-        let shutdown = shared.shutdown;
+        // the last thread notifies the shutdown waiter
+        let shutdown = shared.shutdown && shared.total_thread_num == 0;
         drop(shared);
+        self.not_full_condvar.notify_one();
 
         if shutdown {
             *self.shutdown_shared.lock().unwrap() = true;
@@ -382,27 +550,78 @@ impl<'a> Inner {
         }
 
         if let Some(f) = &self.before_stop {
-            f()
+            f(&info)
         }
     }
 }
 
-struct BlockingTask<T>(Option<T>);
+cfg_metrics! {
+    impl BlockPoolSpawner {
+        pub(crate) fn queue_len(&self) -> usize {
+            self.inner.shared.lock().unwrap().queue.len()
+        }
+
+        pub(crate) fn active_thread_num(&self) -> usize {
+            self.inner.shared.lock().unwrap().active_thread_num as usize
+        }
+
+        pub(crate) fn registry(&self) -> &TaskRegistry {
+            &self.inner.scheduler.registry
+        }
+    }
+}
+
+struct BlockingTask<T> {
+    /// Name of the task, shown as the thread name while the task is running
+    name: Option<String>,
+    func: Option<T>,
+}
+
+impl<T> BlockingTask<T> {
+    fn new(name: Option<String>, func: T) -> Self {
+        BlockingTask {
+            name,
+            func: Some(func),
+        }
+    }
+}
 
 impl<T> Unpin for BlockingTask<T> {}
 
+/// Renames the current thread to the task name, and restores the thread name
+/// when dropped.
+struct NameGuard {
+    thread_name: String,
+}
+
+impl NameGuard {
+    fn new(name: &str) -> Option<NameGuard> {
+        let thread_name = thread::current().name()?.to_string();
+        set_current_name(name);
+        Some(NameGuard { thread_name })
+    }
+}
+
+impl Drop for NameGuard {
+    fn drop(&mut self) {
+        set_current_name(&self.thread_name);
+    }
+}
+
 impl<T, R> Future for BlockingTask<T>
 where
     T: FnOnce() -> R,
 {
     type Output = R;
 
-    fn poll(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Self::Output> {
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
         // Task won't be polled again after finished
-        let func = self
-            .0
+        let func = this
+            .func
             .take()
             .expect("blocking tasks cannot be polled after finished");
+        let _guard = this.name.as_deref().and_then(NameGuard::new);
         Poll::Ready(func())
     }
 }
@@ -411,8 +630,9 @@ where
 mod test {
     use std::time::Duration;
 
-    use crate::builder::RuntimeBuilder;
+    use crate::builder::{BlockingRejectPolicy, RuntimeBuilder};
     use crate::executor::blocking_pool::{BlockPoolSpawner, DEFAULT_MAX_BLOCKING_POOL_SIZE};
+    use crate::task::TaskBuilder;
 
     /// UT test cases for BlockPoolSpawner::new()
     ///
@@ -441,11 +661,40 @@ mod test {
         );
     }
 
+    /// UT test cases for the names of blocking tasks
+    ///
+    /// # Brief
+    /// 1. Poll a named blocking task on a named thread
+    /// 2. Check that the thread is renamed while the task is running
+    /// 3. Check that the thread name is restored after the task finishes
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn ut_blocking_task_name() {
+        use crate::executor::blocking_pool::BlockingTask;
+
+        fn current_name() -> String {
+            let name = std::fs::read_to_string("/proc/thread-self/comm").unwrap();
+            name.trim_end().to_string()
+        }
+
+        let handle = std::thread::Builder::new()
+            .name(String::from("block-test"))
+            .spawn(|| {
+                let task = BlockingTask::new(Some(String::from("task-name")), current_name);
+                let name = crate::block_on(task);
+                (name, current_name())
+            })
+            .unwrap();
+        let (name, restored) = handle.join().unwrap();
+        assert_eq!(name, "task-name");
+        assert_eq!(restored, "block-test");
+    }
+
     /// UT test cases for BlockPoolSpawner::shutdown()
     ///
     /// # Brief
-    /// 1. When shared.shutdown is false, the thread is safely exited without a
-    ///    timeout
+    /// 1. When shared.shutdown is false and there is a thread in the pool, the
+    ///    thread is safely exited without a timeout
     /// 2. When shared.shutdown is false, the thread is not safely exited in
     ///    case of timeout
     /// 3. When shared.shutdown is true, BlockPoolSpawner::shutdown returns
@@ -454,12 +703,14 @@ mod test {
     #[test]
     fn ut_blocking_pool_shutdown() {
         let thread_pool_builder = RuntimeBuilder::new_multi_thread();
-        let mut blocking_pool = BlockPoolSpawner::new(&thread_pool_builder.common);
+        let blocking_pool = BlockPoolSpawner::new(&thread_pool_builder.common);
         blocking_pool.inner.shared.lock().unwrap().shutdown = true;
         assert!(!blocking_pool.shutdown(Duration::from_secs(3)));
 
         let thread_pool_builder = RuntimeBuilder::new_multi_thread();
-        let mut blocking_pool = BlockPoolSpawner::new(&thread_pool_builder.common);
+        let blocking_pool = BlockPoolSpawner::new(&thread_pool_builder.common);
+        // pretends that a thread is running, so that shutdown waits on the condvar
+        blocking_pool.inner.shared.lock().unwrap().total_thread_num = 1;
         let spawner_inner_clone = blocking_pool.inner.clone();
         let _thread = std::thread::spawn(move || {
             *spawner_inner_clone.shutdown_shared.lock().unwrap() = true;
@@ -468,7 +719,7 @@ mod test {
         assert!(blocking_pool.shutdown(Duration::from_secs(3)));
 
         let thread_pool_builder = RuntimeBuilder::new_multi_thread();
-        let mut blocking_pool = BlockPoolSpawner::new(&thread_pool_builder.common);
+        let blocking_pool = BlockPoolSpawner::new(&thread_pool_builder.common);
         let spawner_inner_clone = blocking_pool.inner.clone();
         let _thread = std::thread::spawn(move || {
             spawner_inner_clone.shutdown_condvar.notify_one();
@@ -547,4 +798,50 @@ mod test {
             "block-r-0"
         );
     }
+
+    /// UT test cases for the blocking queue being stolen from
+    ///
+    /// # Brief
+    /// 1. Create a pool with bounded queue, and pretend all threads have been
+    ///    created
+    /// 2. Spawn a task which wakes up the only idle thread
+    /// 3. Pop the task from another running thread before the idle thread wakes
+    ///    up
+    /// 4. Check the assigned number goes back to 0 and the next spawn is not
+    ///    rejected
+    #[test]
+    fn ut_blocking_pool_steal_then_spawn() {
+        let builder = RuntimeBuilder::new_multi_thread()
+            .max_blocking_pool_size(2)
+            .blocking_queue_size(1)
+            .blocking_reject_policy(BlockingRejectPolicy::Error);
+        let blocking_pool = BlockPoolSpawner::new(&builder.common);
+        {
+            let mut shared = blocking_pool.inner.shared.lock().unwrap();
+            shared.total_thread_num = 2;
+            shared.idle_thread_num = 1;
+        }
+
+        let _handle = blocking_pool.spawn_blocking(&TaskBuilder::new(), || 1);
+        let mut shared = blocking_pool.inner.shared.lock().unwrap();
+        assert_eq!(shared.assigned_num, 1);
+        assert_eq!(shared.idle_thread_num, 0);
+        assert!(shared.pop_task().is_some());
+        assert_eq!(shared.assigned_num, 0);
+        assert!(!blocking_pool.inner.is_full(&shared));
+        shared.assigned_num = 1;
+        assert!(!blocking_pool.inner.is_full(&shared));
+        shared.assigned_num = 0;
+        drop(shared);
+
+        let handle = blocking_pool.spawn_blocking(&TaskBuilder::new(), || 2);
+        let mut shared = blocking_pool.inner.shared.lock().unwrap();
+        assert_eq!(shared.queue.len(), 1);
+        assert!(blocking_pool.inner.is_full(&shared));
+        // no thread has been created actually, let the shutdown return at once
+        shared.total_thread_num = 0;
+        drop(shared);
+        drop(blocking_pool);
+        assert!(crate::block_on(handle).is_err());
+    }
 }
@@ -11,20 +11,30 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{self, Display, Formatter};
 use std::future::Future;
 use std::mem;
+use std::num::ParseIntError;
 use std::pin::Pin;
+use std::str::FromStr;
 #[cfg(feature = "metrics")]
 use std::sync::atomic::AtomicU64;
-use std::sync::atomic::AtomicUsize;
-use std::sync::atomic::Ordering::{AcqRel, Acquire};
-use std::sync::{Arc, Condvar, Mutex};
+use std::sync::atomic::Ordering::{AcqRel, Acquire, Release};
+use std::sync::atomic::{AtomicBool, AtomicUsize};
+use std::sync::{Arc, Condvar, Mutex, Weak};
 use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
 
+use crate::error::ErrorKind;
 use crate::executor::driver::{Driver, Handle, ParkFlag};
-use crate::executor::Schedule;
-use crate::task::{JoinHandle, Task, TaskBuilder, VirtualTableType};
+use crate::executor::{RuntimeInner, Schedule};
+use crate::fastrand;
+#[cfg(feature = "metrics")]
+use crate::metrics::TaskRegistry;
+use crate::task::coop::{self, DEFAULT_BUDGET};
+use crate::task::{
+    JoinHandle, PanicHandler, PanicPayload, Task, TaskBuilder, TaskId, TaskInfo, VirtualTableType,
+};
 
 // Idle state
 const IDLE: usize = 0;
@@ -41,15 +51,174 @@ pub(crate) struct CurrentThreadSpawner {
     pub(crate) scheduler: Arc<CurrentThreadScheduler>,
     pub(crate) driver: Arc<Mutex<Driver>>,
     pub(crate) handle: Arc<Handle>,
+    /// Budget of each poll of a task
+    pub(crate) task_budget: Option<u32>,
+    /// Seed of the random numbers of the thread running `block_on`
+    pub(crate) rng_seed: Option<u64>,
 }
 
 #[derive(Default)]
 pub(crate) struct CurrentThreadScheduler {
     pub(crate) inner: Mutex<VecDeque<Task>>,
     pub(crate) parker_list: Mutex<Vec<Arc<Parker>>>,
+    /// Shutdown state of the scheduler
+    is_cancel: AtomicBool,
+    /// Handles the panics of the tasks
+    panic_handler: PanicHandler,
     /// Total task count
     #[cfg(feature = "metrics")]
     pub(crate) count: AtomicU64,
+    /// Live tasks of the scheduler
+    #[cfg(feature = "metrics")]
+    pub(crate) registry: TaskRegistry,
+    /// Records or replays the poll order of the tasks, `None` if disabled
+    pub(crate) tracer: Option<Tracer>,
+}
+
+/// The order in which a current-thread runtime polls its tasks.
+///
+/// Each task is identified by its spawn order in the runtime starting from 0,
+/// so a trace recorded in one run still makes sense in another run of the same
+/// program. A trace could be converted to and parsed from a string of
+/// comma-separated numbers in order to be kept in a log.
+///
+/// # Examples
+///
+/// ```
+/// use ylong_runtime::executor::ScheduleTrace;
+///
+/// let trace: ScheduleTrace = "0,1,0,2".parse().unwrap();
+/// assert_eq!(trace.polls(), &[0, 1, 0, 2]);
+/// assert_eq!(trace.to_string(), "0,1,0,2");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ScheduleTrace {
+    polls: Vec<u64>,
+}
+
+impl ScheduleTrace {
+    /// Gets the spawn orders of the polled tasks, in the order they get polled.
+    pub fn polls(&self) -> &[u64] {
+        &self.polls
+    }
+}
+
+impl Display for ScheduleTrace {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, ordinal) in self.polls.iter().enumerate() {
+            if i != 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{ordinal}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ScheduleTrace {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Ok(ScheduleTrace::default());
+        }
+        let polls = s
+            .split(',')
+            .map(|ordinal| ordinal.trim().parse())
+            .collect::<Result<Vec<u64>, _>>()?;
+        Ok(ScheduleTrace { polls })
+    }
+}
+
+// Records the poll order of the tasks, and forces the order to follow a
+// recorded one when replaying.
+pub(crate) struct Tracer {
+    inner: Mutex<TracerInner>,
+}
+
+struct TracerInner {
+    // Spawn order of the live tasks
+    ordinals: HashMap<TaskId, u64>,
+    // Live tasks by their spawn order
+    ids: HashMap<u64, TaskId>,
+    next_ordinal: u64,
+    recorded: Vec<u64>,
+    replay: Vec<u64>,
+    cursor: usize,
+}
+
+impl Tracer {
+    pub(crate) fn new(replay: Option<ScheduleTrace>) -> Self {
+        Tracer {
+            inner: Mutex::new(TracerInner {
+                ordinals: HashMap::new(),
+                ids: HashMap::new(),
+                next_ordinal: 0,
+                recorded: Vec::new(),
+                replay: replay.map(|trace| trace.polls).unwrap_or_default(),
+                cursor: 0,
+            }),
+        }
+    }
+
+    fn register(&self, id: TaskId) {
+        let mut inner = self.inner.lock().unwrap();
+        let ordinal = inner.next_ordinal;
+        inner.next_ordinal += 1;
+        inner.ordinals.insert(id, ordinal);
+        inner.ids.insert(ordinal, id);
+    }
+
+    fn release(&self, id: TaskId) {
+        let mut inner = self.inner.lock().unwrap();
+        if let Some(ordinal) = inner.ordinals.remove(&id) {
+            inner.ids.remove(&ordinal);
+        }
+    }
+
+    fn trace(&self) -> ScheduleTrace {
+        ScheduleTrace {
+            polls: self.inner.lock().unwrap().recorded.clone(),
+        }
+    }
+
+    // Pops the next task to poll. While replaying, it's the next recorded task,
+    // and nothing is popped until that task gets woken. Once the replay is
+    // over, the tasks are popped in FIFO order.
+    fn pop(&self, queue: &mut VecDeque<Task>) -> Option<Task> {
+        let mut inner = self.inner.lock().unwrap();
+        let index = loop {
+            let ordinal = match inner.replay.get(inner.cursor) {
+                Some(ordinal) => *ordinal,
+                None => break 0,
+            };
+            if let Some(id) = inner.ids.get(&ordinal) {
+                match queue.iter().position(|task| task.0.header().id == *id) {
+                    Some(index) => {
+                        inner.cursor += 1;
+                        break index;
+                    }
+                    // The task is still alive, it might get woken later
+                    None => return None,
+                }
+            }
+            if ordinal >= inner.next_ordinal {
+                // The task has not been spawned yet
+                return None;
+            }
+            // The task has already finished, so the replay has diverged from the
+            // record here. Skips it to keep going.
+            inner.cursor += 1;
+        };
+
+        let task = queue.remove(index)?;
+        if let Some(ordinal) = inner.ordinals.get(&task.0.header().id) {
+            let ordinal = *ordinal;
+            inner.recorded.push(ordinal);
+        }
+        Some(task)
+    }
 }
 
 unsafe impl Sync for CurrentThreadScheduler {}
@@ -57,6 +226,11 @@ unsafe impl Sync for CurrentThreadScheduler {}
 impl Schedule for CurrentThreadScheduler {
     #[inline]
     fn schedule(&self, task: Task, _lifo: bool) {
+        // The scheduler has been shut down, so the task would never be executed
+        if self.is_cancel.load(Acquire) {
+            task.cancel_queued();
+            return;
+        }
         let mut queue = self.inner.lock().unwrap();
         #[cfg(feature = "metrics")]
         self.count.fetch_add(1, AcqRel);
@@ -67,12 +241,56 @@ impl Schedule for CurrentThreadScheduler {
             parker.unpark(false);
         }
     }
+
+    fn on_task_panic(&self, info: &TaskInfo, payload: &PanicPayload) {
+        self.panic_handler.handle(info, payload);
+    }
+
+    #[cfg(feature = "metrics")]
+    fn registry(&self) -> Option<&TaskRegistry> {
+        Some(&self.registry)
+    }
+
+    fn on_task_release(&self, id: TaskId) {
+        if let Some(tracer) = &self.tracer {
+            tracer.release(id);
+        }
+    }
 }
 
 impl CurrentThreadScheduler {
+    pub(crate) fn with_panic_handler(panic_handler: PanicHandler) -> Self {
+        CurrentThreadScheduler {
+            panic_handler,
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn set_runtime(&self, runtime: Weak<RuntimeInner>) {
+        self.panic_handler.set_runtime(runtime);
+    }
+
     fn pop(&self) -> Option<Task> {
         let mut queue = self.inner.lock().unwrap();
-        queue.pop_front()
+        match &self.tracer {
+            Some(tracer) => tracer.pop(&mut queue),
+            None => queue.pop_front(),
+        }
+    }
+
+    /// Gets the poll order of the tasks recorded so far, `None` if neither
+    /// recording nor replaying is enabled.
+    pub(crate) fn trace(&self) -> Option<ScheduleTrace> {
+        self.tracer.as_ref().map(Tracer::trace)
+    }
+
+    // Stops accepting new tasks and cancels the tasks left in the queue
+    fn shutdown(&self) {
+        self.is_cancel.store(true, Release);
+        let tasks = mem::take(&mut *self.inner.lock().unwrap());
+        for task in tasks {
+            task.cancel_queued();
+        }
     }
 }
 
@@ -240,6 +458,8 @@ impl CurrentThreadSpawner {
             scheduler: Default::default(),
             driver,
             handle,
+            task_budget: Some(DEFAULT_BUDGET),
+            rng_seed: None,
         }
     }
 
@@ -252,8 +472,14 @@ impl CurrentThreadSpawner {
         T: Future + Send + 'static,
         T::Output: Send + 'static,
     {
+        if self.scheduler.is_cancel.load(Acquire) {
+            return Task::create_rejected_task(builder, task, ErrorKind::RuntimeShutdown.into());
+        }
         let scheduler = Arc::downgrade(&self.scheduler);
         let (task, handle) = Task::create_task(builder, scheduler, task, VirtualTableType::Ylong);
+        if let Some(tracer) = &self.scheduler.tracer {
+            tracer.register(task.0.header().id);
+        }
 
         let mut queue = self.scheduler.inner.lock().unwrap();
         queue.push_back(task);
@@ -267,10 +493,19 @@ impl CurrentThreadSpawner {
         handle
     }
 
+    /// Shuts down the runtime. Tasks left in the queue get canceled, and new
+    /// tasks are rejected.
+    pub(crate) fn shutdown(&self) {
+        self.scheduler.shutdown();
+    }
+
     pub(crate) fn block_on<T>(&self, future: T) -> T::Output
     where
         T: Future,
     {
+        if let Some(seed) = self.rng_seed {
+            fastrand::set_thread_seed(seed);
+        }
         let parker = Arc::new(self.get_parker());
         let mut parker_list = self.scheduler.parker_list.lock().unwrap();
         parker_list.push(parker.clone());
@@ -292,7 +527,7 @@ impl CurrentThreadSpawner {
             }
 
             while let Some(task) = self.scheduler.pop() {
-                task.run();
+                coop::with_task_budget(self.task_budget, || task.run());
             }
 
             wake = parker.park();
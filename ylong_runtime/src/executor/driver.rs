@@ -73,7 +73,7 @@ impl Driver {
     pub(crate) fn run(&mut self) -> ParkFlag {
         let _duration: Option<Duration> = None;
         #[cfg(feature = "time")]
-        let _duration = self.time.run();
+        let _duration = self.time.run_idle();
         #[cfg(feature = "net")]
         self.io
             .drive(_duration)
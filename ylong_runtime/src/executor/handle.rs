@@ -0,0 +1,345 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Handle of a runtime, which could be cloned and sent to other threads.
+
+use std::cell::RefCell;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use crate::executor::{block_on, global_default_async, AsyncHandle, RuntimeInner};
+use crate::task::{JoinHandle, TaskBuilder};
+
+cfg_ffrt! {
+    use crate::ffrt::spawner::spawn;
+}
+
+cfg_not_ffrt! {
+    use crate::executor::worker;
+    use crate::executor::worker::{get_current_ctx, WorkerHandle};
+}
+
+thread_local! {
+    static CURRENT_RUNTIME: RefCell<Option<Handle>> = const { RefCell::new(None) };
+}
+
+/// Handle to a runtime.
+///
+/// The handle is cheap to clone and could be sent to other threads, so that
+/// tasks could be spawned onto the runtime without holding a reference to
+/// the [`Runtime`]. After the runtime is dropped or shut down, tasks spawned
+/// by the handle get a `RuntimeShutdown` error.
+///
+/// [`Runtime`]: crate::executor::Runtime
+///
+/// # Examples
+///
+/// ```
+/// use ylong_runtime::builder::RuntimeBuilder;
+///
+/// let runtime = RuntimeBuilder::new_multi_thread().build().unwrap();
+/// let handle = runtime.handle().clone();
+///
+/// let join = std::thread::spawn(move || {
+///     let task = handle.spawn(async { 1 });
+///     handle.block_on(task).unwrap()
+/// });
+/// assert_eq!(join.join().unwrap(), 1);
+/// ```
+#[derive(Clone)]
+pub struct Handle {
+    pub(crate) inner: Arc<RuntimeInner>,
+}
+
+/// Guard returned by [`Handle::enter`]. The previous runtime context of the
+/// thread gets restored when the guard is dropped.
+pub struct EnterGuard<'a> {
+    prev: Option<Handle>,
+    #[cfg(not(feature = "ffrt"))]
+    prev_handle: *const (),
+    // the driver handle registered to the thread, boxed to keep its address
+    #[cfg(not(feature = "ffrt"))]
+    _cur_handle: Box<WorkerHandle>,
+    // the guard modifies a thread local, so it should not be sent to other threads
+    _marker: PhantomData<(&'a Handle, *const ())>,
+}
+
+impl Drop for EnterGuard<'_> {
+    fn drop(&mut self) {
+        let prev = self.prev.take();
+        CURRENT_RUNTIME.with(|cur| *cur.borrow_mut() = prev);
+        #[cfg(not(feature = "ffrt"))]
+        worker::CURRENT_HANDLE.with(|ctx| ctx.set(self.prev_handle));
+    }
+}
+
+impl Handle {
+    /// Gets the handle of the runtime in the current context.
+    ///
+    /// Inside a worker thread of a runtime, or inside [`Handle::enter`] and
+    /// `block_on`, the handle of that runtime is returned. Otherwise, the
+    /// handle of the global runtime is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::builder::RuntimeBuilder;
+    /// use ylong_runtime::executor::Handle;
+    ///
+    /// let runtime = RuntimeBuilder::new_multi_thread().build().unwrap();
+    /// let task = runtime.spawn(async {
+    ///     // spawns onto the runtime that is running the current task
+    ///     let handle = Handle::current();
+    ///     handle.spawn(async { 1 }).await.unwrap()
+    /// });
+    /// assert_eq!(runtime.block_on(task).unwrap(), 1);
+    /// ```
+    pub fn current() -> Handle {
+        if let Some(handle) = CURRENT_RUNTIME.with(|cur| cur.borrow().clone()) {
+            return handle;
+        }
+        #[cfg(not(feature = "ffrt"))]
+        if let Some(ctx) = get_current_ctx() {
+            if let Some(inner) = ctx.worker.scheduler.get_runtime() {
+                return Handle { inner };
+            }
+        }
+        global_default_async().handle.clone()
+    }
+
+    /// Enters the runtime context of this handle. Until the returned guard is
+    /// dropped, [`Handle::current`] returns this handle on the current thread,
+    /// and timers or io sources created on the thread get registered to this
+    /// runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::builder::RuntimeBuilder;
+    /// use ylong_runtime::executor::Handle;
+    ///
+    /// let runtime = RuntimeBuilder::new_multi_thread().build().unwrap();
+    /// let _guard = runtime.handle().enter();
+    /// let task = Handle::current().spawn(async { 1 });
+    /// assert_eq!(runtime.block_on(task).unwrap(), 1);
+    /// ```
+    pub fn enter(&self) -> EnterGuard<'_> {
+        let prev = CURRENT_RUNTIME.with(|cur| cur.borrow_mut().replace(self.clone()));
+        #[cfg(not(feature = "ffrt"))]
+        let cur_handle = Box::new(WorkerHandle {
+            _handle: self.inner.get_handle(),
+            entered: !worker::is_driving_tasks(),
+        });
+        #[cfg(not(feature = "ffrt"))]
+        let prev_handle = worker::CURRENT_HANDLE
+            .with(|ctx| ctx.replace((&*cur_handle as *const WorkerHandle).cast::<()>()));
+        EnterGuard {
+            prev,
+            #[cfg(not(feature = "ffrt"))]
+            prev_handle,
+            #[cfg(not(feature = "ffrt"))]
+            _cur_handle: cur_handle,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Spawns a future onto the runtime, returning a [`JoinHandle`] for it.
+    ///
+    /// See [`Runtime::spawn`] for more details.
+    ///
+    /// [`Runtime::spawn`]: crate::executor::Runtime::spawn
+    pub fn spawn<T, R>(&self, task: T) -> JoinHandle<R>
+    where
+        T: Future<Output = R> + Send + 'static,
+        R: Send + 'static,
+    {
+        self.spawn_with_attr(task, &TaskBuilder::default())
+    }
+
+    #[inline]
+    pub(crate) fn spawn_with_attr<T, R>(&self, task: T, builder: &TaskBuilder) -> JoinHandle<R>
+    where
+        T: Future<Output = R> + Send + 'static,
+        R: Send + 'static,
+    {
+        match &self.inner.async_spawner {
+            #[cfg(feature = "current_thread_runtime")]
+            AsyncHandle::CurrentThread(current_thread) => current_thread.spawn(builder, task),
+            #[cfg(not(feature = "ffrt"))]
+            AsyncHandle::MultiThread(async_spawner) => async_spawner.spawn(builder, task),
+            #[cfg(feature = "ffrt")]
+            AsyncHandle::FfrtMultiThread => spawn(task, builder),
+        }
+    }
+
+    /// Spawns the provided function or closure onto the blocking pool of the
+    /// runtime. The closure runs inside the runtime context of this handle.
+    ///
+    /// See [`Runtime::spawn_blocking`] for more details.
+    ///
+    /// [`Runtime::spawn_blocking`]: crate::executor::Runtime::spawn_blocking
+    pub fn spawn_blocking<T, R>(&self, task: T) -> JoinHandle<R>
+    where
+        T: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let handle = self.clone();
+        let task = move || {
+            let _guard = handle.enter();
+            task()
+        };
+        #[cfg(not(feature = "ffrt"))]
+        if let Some(blocking_spawner) = &self.inner.blocking_spawner {
+            return blocking_spawner.spawn_blocking(&TaskBuilder::new(), task);
+        }
+        crate::spawn::spawn_blocking(&TaskBuilder::new(), task)
+    }
+
+    /// Blocks the current thread and runs the given future to completion
+    /// inside the runtime context of this handle.
+    ///
+    /// See [`Runtime::block_on`] for more details.
+    ///
+    /// [`Runtime::block_on`]: crate::executor::Runtime::block_on
+    pub fn block_on<T, R>(&self, task: T) -> R
+    where
+        T: Future<Output = R>,
+    {
+        let _guard = self.enter();
+        self.block_on_inner(task)
+    }
+
+    #[cfg(not(feature = "ffrt"))]
+    fn block_on_inner<T, R>(&self, task: T) -> R
+    where
+        T: Future<Output = R>,
+    {
+        if worker::is_driving_tasks() {
+            panic!(
+                "Cannot block_on an asynchronous function in a runtime context. \
+                This happens because a block_on call tries to block the current \
+                thread which is being used to drive asynchronous tasks."
+            );
+        }
+
+        // Registers handle to the current thread when block_on().
+        // so that async_source can get the handle and register it.
+        let cur_context = WorkerHandle {
+            _handle: self.inner.get_handle(),
+            entered: false,
+        };
+
+        let prev = worker::CURRENT_HANDLE
+            .with(|ctx| ctx.replace((&cur_context as *const WorkerHandle).cast::<()>()));
+
+        let ret = match &self.inner.async_spawner {
+            #[cfg(feature = "current_thread_runtime")]
+            AsyncHandle::CurrentThread(current_thread) => current_thread.block_on(task),
+            AsyncHandle::MultiThread(_) => block_on::block_on(task),
+        };
+
+        // Restores the current thread variable, which is null outside of
+        // `Handle::enter`, otherwise the worker's CURRENT_WORKER can not be set
+        // under MultiThread.
+        worker::CURRENT_HANDLE.with(|ctx| {
+            ctx.set(prev);
+        });
+
+        ret
+    }
+
+    #[cfg(feature = "ffrt")]
+    fn block_on_inner<T, R>(&self, task: T) -> R
+    where
+        T: Future<Output = R>,
+    {
+        match &self.inner.async_spawner {
+            #[cfg(feature = "current_thread_runtime")]
+            AsyncHandle::CurrentThread(current_thread) => current_thread.block_on(task),
+            AsyncHandle::FfrtMultiThread => block_on::block_on(task),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[cfg(feature = "multi_instance_runtime")]
+    use crate::builder::RuntimeBuilder;
+    #[cfg(feature = "multi_instance_runtime")]
+    use crate::executor::Handle;
+
+    /// UT test cases for `Handle::current`
+    ///
+    /// # Brief
+    /// 1. Get the current handle inside a worker of a multi-thread runtime
+    /// 2. Check that the handle points to the runtime
+    /// 3. Get the current handle inside `Handle::enter`
+    /// 4. Check that the handle points to the entered runtime
+    #[test]
+    #[cfg(feature = "multi_instance_runtime")]
+    fn ut_handle_current() {
+        let runtime = RuntimeBuilder::new_multi_thread()
+            .worker_num(1)
+            .build()
+            .unwrap();
+        let handle = runtime.handle().clone();
+        let task = runtime.spawn(async move {
+            let current = Handle::current();
+            std::sync::Arc::ptr_eq(&current.inner, &handle.inner)
+        });
+        assert!(runtime.block_on(task).unwrap());
+
+        let other = RuntimeBuilder::new_multi_thread()
+            .worker_num(1)
+            .build()
+            .unwrap();
+        let guard = other.handle().enter();
+        assert!(std::sync::Arc::ptr_eq(
+            &Handle::current().inner,
+            &other.handle().inner
+        ));
+        drop(guard);
+        assert!(!std::sync::Arc::ptr_eq(
+            &Handle::current().inner,
+            &other.handle().inner
+        ));
+    }
+
+    /// UT test cases for creating timers inside `Handle::enter`
+    ///
+    /// # Brief
+    /// 1. Create a `sleep` outside of any task inside `Handle::enter`
+    /// 2. Block on the sleep while the guard is still alive
+    /// 3. Check that the sleep finishes after its duration
+    /// 4. Drop the guard, check that the runtime could still block on tasks
+    #[test]
+    #[cfg(all(feature = "multi_instance_runtime", feature = "time"))]
+    fn ut_handle_enter_sleep() {
+        use std::time::{Duration, Instant};
+
+        let runtime = RuntimeBuilder::new_multi_thread()
+            .worker_num(1)
+            .build()
+            .unwrap();
+        let guard = runtime.handle().enter();
+        let start = Instant::now();
+        let sleep = crate::time::sleep(Duration::from_millis(20));
+        runtime.block_on(sleep);
+        assert!(start.elapsed() >= Duration::from_millis(20));
+        drop(guard);
+
+        let task = runtime.spawn(async { 1 });
+        assert_eq!(runtime.block_on(task).unwrap(), 1);
+    }
+}
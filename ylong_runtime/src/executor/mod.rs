@@ -17,23 +17,27 @@
 pub(crate) mod block_on;
 #[cfg(feature = "current_thread_runtime")]
 pub(crate) mod current_thread;
+#[cfg(feature = "current_thread_runtime")]
+pub use current_thread::ScheduleTrace;
+pub(crate) mod driver_handle;
+mod handle;
 
 use std::future::Future;
 use std::mem::MaybeUninit;
-use std::sync::Once;
+use std::sync::{Arc, Once};
+#[cfg(not(feature = "ffrt"))]
+use std::time::{Duration, Instant};
+
+pub use handle::{EnterGuard, Handle};
 
 use crate::builder::multi_thread_builder::GLOBAL_BUILDER;
 use crate::builder::RuntimeBuilder;
 #[cfg(feature = "current_thread_runtime")]
 use crate::executor::current_thread::CurrentThreadSpawner;
-use crate::task::{JoinHandle, Task, TaskBuilder};
-mod driver_handle;
-pub(crate) use driver_handle::Handle;
+#[cfg(feature = "current_thread_runtime")]
+use crate::task::TaskId;
+use crate::task::{JoinHandle, PanicPayload, Task, TaskBuilder, TaskInfo};
 
-use crate::executor::worker::WorkerHandle;
-cfg_ffrt! {
-    use crate::ffrt::spawner::spawn;
-}
 cfg_not_ffrt! {
     mod parker;
     pub(crate) mod async_pool;
@@ -42,13 +46,33 @@ cfg_not_ffrt! {
     mod sleeper;
     pub(crate) mod worker;
     pub(crate) mod driver;
+    pub(crate) mod watchdog;
+    pub use watchdog::BlockingInfo;
     use crate::builder::{initialize_blocking_spawner, initialize_async_spawner};
     use crate::executor::async_pool::AsyncPoolSpawner;
-    use crate::executor::blocking_pool::BlockPoolSpawner;
+    use crate::executor::blocking_pool::{BlockPoolSpawner, BLOCKING_THREAD_QUIT_WAIT_TIME};
+}
+
+cfg_metrics! {
+    use crate::metrics::TaskRegistry;
 }
 
 pub(crate) trait Schedule {
     fn schedule(&self, task: Task, lifo: bool);
+
+    /// Gets called when a task of the scheduler panics.
+    fn on_task_panic(&self, _info: &TaskInfo, _payload: &PanicPayload) {}
+
+    /// Gets the registry of the live tasks of the scheduler.
+    #[cfg(feature = "metrics")]
+    fn registry(&self) -> Option<&TaskRegistry> {
+        None
+    }
+
+    /// Gets called right before the memory of a task of the scheduler gets
+    /// freed.
+    #[cfg(feature = "current_thread_runtime")]
+    fn on_task_release(&self, _id: TaskId) {}
 }
 
 pub(crate) struct PlaceholderScheduler;
@@ -68,6 +92,66 @@ pub(crate) enum AsyncHandle {
     FfrtMultiThread,
 }
 
+pub(crate) struct RuntimeInner {
+    pub(crate) async_spawner: AsyncHandle,
+    /// The blocking pool of the runtime. The global runtime uses the global
+    /// blocking pool instead.
+    #[cfg(not(feature = "ffrt"))]
+    pub(crate) blocking_spawner: Option<BlockPoolSpawner>,
+}
+
+#[cfg(not(feature = "ffrt"))]
+impl RuntimeInner {
+    pub(crate) fn get_handle(&self) -> Arc<driver::Handle> {
+        match &self.async_spawner {
+            #[cfg(feature = "current_thread_runtime")]
+            AsyncHandle::CurrentThread(s) => s.handle.clone(),
+            AsyncHandle::MultiThread(s) => s.exe_mng_info.handle.clone(),
+        }
+    }
+
+    pub(crate) fn shutdown(&self, timeout: Duration) -> bool {
+        let start = Instant::now();
+        let async_finished = match &self.async_spawner {
+            #[cfg(feature = "current_thread_runtime")]
+            AsyncHandle::CurrentThread(s) => {
+                s.shutdown();
+                true
+            }
+            AsyncHandle::MultiThread(s) => !s.exe_mng_info.is_cancel() && s.shutdown(timeout),
+        };
+        let blocking_finished = match &self.blocking_spawner {
+            Some(s) => s.shutdown(timeout.saturating_sub(start.elapsed())),
+            None => true,
+        };
+        async_finished && blocking_finished
+    }
+
+    // Signals the pools to shut down without waiting for their threads, so it
+    // could be called on the threads of the runtime as well.
+    pub(crate) fn shutdown_background(&self) {
+        match &self.async_spawner {
+            #[cfg(feature = "current_thread_runtime")]
+            AsyncHandle::CurrentThread(s) => s.shutdown(),
+            AsyncHandle::MultiThread(s) => s.exe_mng_info.cancel(),
+        }
+        if let Some(s) = &self.blocking_spawner {
+            s.cancel();
+        }
+    }
+
+    fn release(&self) {
+        match &self.async_spawner {
+            #[cfg(feature = "current_thread_runtime")]
+            AsyncHandle::CurrentThread(s) => s.shutdown(),
+            AsyncHandle::MultiThread(s) => s.release(),
+        }
+        if let Some(s) = &self.blocking_spawner {
+            s.shutdown(BLOCKING_THREAD_QUIT_WAIT_TIME);
+        }
+    }
+}
+
 /// Runtime struct.
 ///
 /// # If `multi_instance_runtime` feature is turned on
@@ -79,17 +163,176 @@ pub(crate) enum AsyncHandle {
 /// The async and blocking pools working when calling methods of this struct are
 /// stored in the global static executor instance. Here, keep the empty struct
 /// for compatibility and possibility for function extension in the future.
+///
+/// The runtime gets released when it is dropped, even if there are still
+/// [`Handle`]s of it.
 pub struct Runtime {
-    pub(crate) async_spawner: AsyncHandle,
+    pub(crate) handle: Handle,
+}
+
+impl Runtime {
+    pub(crate) fn from_inner(inner: RuntimeInner) -> Runtime {
+        let inner = Arc::new(inner);
+        // lets the workers and the panic handlers find the runtime they belong to
+        #[cfg(not(feature = "ffrt"))]
+        {
+            match &inner.async_spawner {
+                #[cfg(feature = "current_thread_runtime")]
+                AsyncHandle::CurrentThread(s) => s.scheduler.set_runtime(Arc::downgrade(&inner)),
+                AsyncHandle::MultiThread(s) => s.exe_mng_info.set_runtime(Arc::downgrade(&inner)),
+            }
+            if let Some(s) = &inner.blocking_spawner {
+                s.set_runtime(Arc::downgrade(&inner));
+            }
+        }
+        Runtime {
+            handle: Handle { inner },
+        }
+    }
+
+    /// Gets the handle of the runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::builder::RuntimeBuilder;
+    ///
+    /// let runtime = RuntimeBuilder::new_multi_thread().build().unwrap();
+    /// let handle = runtime.handle().clone();
+    /// let task = handle.spawn(async { 1 });
+    /// assert_eq!(handle.block_on(task).unwrap(), 1);
+    /// ```
+    pub fn handle(&self) -> &Handle {
+        &self.handle
+    }
+}
+
+#[cfg(not(feature = "ffrt"))]
+impl Drop for Runtime {
+    fn drop(&mut self) {
+        // handles of the runtime might still be alive, so release the pools here
+        self.handle.inner.release();
+    }
 }
 
 #[cfg(not(feature = "ffrt"))]
 impl Runtime {
-    pub(crate) fn get_handle(&self) -> std::sync::Arc<Handle> {
-        match &self.async_spawner {
+    /// Shuts down the runtime and waits for its threads to exit.
+    ///
+    /// After calling this method, the runtime stops accepting new tasks. Tasks
+    /// spawned afterwards get a [`ErrorKind::RuntimeShutdown`] error from
+    /// their `JoinHandle`. Tasks that are waiting in the queues get canceled,
+    /// and their `JoinHandle` returns a [`ErrorKind::TaskCanceled`] error.
+    /// Tasks that are being executed will be canceled when they return
+    /// `Pending`, while blocking tasks that are being executed are not
+    /// interrupted.
+    ///
+    /// Returns true if all the worker threads and blocking threads of the
+    /// runtime exit within the timeout. Returns false if the timeout is
+    /// reached, or if the runtime has already been shut down.
+    ///
+    /// The global runtime does not own the global blocking pool, so only its
+    /// worker threads are waited.
+    ///
+    /// [`ErrorKind::RuntimeShutdown`]: crate::error::ErrorKind::RuntimeShutdown
+    /// [`ErrorKind::TaskCanceled`]: crate::error::ErrorKind::TaskCanceled
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use ylong_runtime::builder::RuntimeBuilder;
+    ///
+    /// let runtime = RuntimeBuilder::new_multi_thread().build().unwrap();
+    /// let handle = runtime.spawn(async { 1 });
+    /// assert_eq!(runtime.block_on(handle).unwrap(), 1);
+    ///
+    /// assert!(runtime.shutdown_timeout(Duration::from_secs(1)));
+    /// ```
+    pub fn shutdown_timeout(&self, timeout: Duration) -> bool {
+        self.handle.inner.shutdown(timeout)
+    }
+
+    /// Shuts down the runtime without waiting for its threads to exit.
+    ///
+    /// Same as [`Runtime::shutdown_timeout`], tasks spawned afterwards get
+    /// rejected and tasks in the queues get canceled. The threads of the
+    /// runtime exit in the background once they finish their current tasks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::builder::RuntimeBuilder;
+    ///
+    /// let runtime = RuntimeBuilder::new_multi_thread().build().unwrap();
+    /// runtime.shutdown_background();
+    ///
+    /// let handle = runtime.spawn(async { 1 });
+    /// assert!(runtime.block_on(handle).is_err());
+    /// ```
+    pub fn shutdown_background(&self) {
+        self.handle.inner.shutdown_background();
+    }
+
+    /// Sets the number of worker threads of a multi-thread runtime while it is
+    /// running.
+    ///
+    /// The number is bounded between 1 and the max number set by
+    /// [`MultiThreadBuilder::max_worker_num`] or
+    /// [`MultiThreadBuilder::auto_scale`]. When the runtime shrinks, the
+    /// retired workers hand their tasks over to the others and get parked.
+    /// When it grows, the parked workers get resumed first, and then new
+    /// threads get spawned. If auto-scaling is enabled, the number may be
+    /// adjusted again according to the load afterwards.
+    ///
+    /// Returns the number of workers after being bounded. A current-thread
+    /// runtime always has one worker, so 1 is returned.
+    ///
+    /// # Error
+    /// Returns an error if the runtime has been shut down, or if spawning a
+    /// new thread fails.
+    ///
+    /// [`MultiThreadBuilder::max_worker_num`]: crate::builder::MultiThreadBuilder::max_worker_num
+    /// [`MultiThreadBuilder::auto_scale`]: crate::builder::MultiThreadBuilder::auto_scale
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::builder::RuntimeBuilder;
+    ///
+    /// let runtime = RuntimeBuilder::new_multi_thread()
+    ///     .worker_num(4)
+    ///     .max_worker_num(8)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // shrinks to one worker when idle
+    /// assert_eq!(runtime.set_worker_num(1).unwrap(), 1);
+    /// // grows under burst load, bounded by the max number
+    /// assert_eq!(runtime.set_worker_num(16).unwrap(), 8);
+    ///
+    /// let handle = runtime.spawn(async { 1 });
+    /// assert_eq!(runtime.block_on(handle).unwrap(), 1);
+    /// ```
+    pub fn set_worker_num(&self, num: usize) -> std::io::Result<usize> {
+        match &self.handle.inner.async_spawner {
             #[cfg(feature = "current_thread_runtime")]
-            AsyncHandle::CurrentThread(s) => s.handle.clone(),
-            AsyncHandle::MultiThread(s) => s.exe_mng_info.handle.clone(),
+            AsyncHandle::CurrentThread(_) => Ok(1),
+            AsyncHandle::MultiThread(spawner) => spawner.set_worker_num(num),
+        }
+    }
+
+    /// Gets the order in which the runtime has polled its tasks so far.
+    ///
+    /// Returns `None` unless the runtime is a current-thread runtime that
+    /// records or replays its schedule, see
+    /// `CurrentThreadBuilder::record_schedule`.
+    #[cfg(feature = "current_thread_runtime")]
+    pub fn schedule_trace(&self) -> Option<ScheduleTrace> {
+        match &self.handle.inner.async_spawner {
+            AsyncHandle::CurrentThread(spawner) => spawner.scheduler.trace(),
+            AsyncHandle::MultiThread(_) => None,
         }
     }
 }
@@ -110,15 +353,16 @@ pub(crate) fn global_default_async() -> &'static Runtime {
             // we have just made sure the global builder is a some, so this unwrap_unchecked is safe
             let runtime = match initialize_async_spawner(global_builder.as_ref().unwrap_unchecked())
             {
-                Ok(s) => Runtime {
+                Ok(s) => Runtime::from_inner(RuntimeInner {
                     async_spawner: AsyncHandle::MultiThread(s),
-                },
+                    blocking_spawner: None,
+                }),
                 Err(e) => panic!("initialize runtime failed: {e:?}"),
             };
             #[cfg(feature = "ffrt")]
-            let runtime = Runtime {
+            let runtime = Runtime::from_inner(RuntimeInner {
                 async_spawner: AsyncHandle::FfrtMultiThread,
-            };
+            });
             GLOBAL_DEFAULT_ASYNC = MaybeUninit::new(runtime);
         });
         &*GLOBAL_DEFAULT_ASYNC.as_ptr()
@@ -193,7 +437,7 @@ impl Runtime {
         T: Future<Output = R> + Send + 'static,
         R: Send + 'static,
     {
-        self.spawn_with_attr(task, &TaskBuilder::default())
+        self.handle.spawn(task)
     }
 
     #[inline]
@@ -202,14 +446,7 @@ impl Runtime {
         T: Future<Output = R> + Send + 'static,
         R: Send + 'static,
     {
-        match &self.async_spawner {
-            #[cfg(feature = "current_thread_runtime")]
-            AsyncHandle::CurrentThread(current_thread) => current_thread.spawn(builder, task),
-            #[cfg(not(feature = "ffrt"))]
-            AsyncHandle::MultiThread(async_spawner) => async_spawner.spawn(builder, task),
-            #[cfg(feature = "ffrt")]
-            AsyncHandle::FfrtMultiThread => spawn(task, builder),
-        }
+        self.handle.spawn_with_attr(task, builder)
     }
 
     /// Spawns the provided function or closure onto the runtime.
@@ -239,7 +476,7 @@ impl Runtime {
         T: FnOnce() -> R + Send + 'static,
         R: Send + 'static,
     {
-        crate::spawn::spawn_blocking(&TaskBuilder::new(), task)
+        self.handle.spawn_blocking(task)
     }
 
     /// Blocks the current thread and runs the given future (usually a
@@ -278,64 +515,12 @@ impl Runtime {
     where
         T: Future<Output = R>,
     {
-        self.block_on_inner(task)
-    }
-
-    #[cfg(not(feature = "ffrt"))]
-    fn block_on_inner<T, R>(&self, task: T) -> R
-    where
-        T: Future<Output = R>,
-    {
-        worker::CURRENT_HANDLE.with(|ctx| {
-            if !ctx.get().is_null() {
-                panic!(
-                    "Cannot block_on an asynchronous function in a runtime context. \
-                    This happens because a block_on call tries to block the current \
-                    thread which is being used to drive asynchronous tasks."
-                );
-            }
-        });
-
-        // Registers handle to the current thread when block_on().
-        // so that async_source can get the handle and register it.
-        let cur_context = worker::WorkerHandle {
-            _handle: self.get_handle(),
-        };
-
-        worker::CURRENT_HANDLE.with(|ctx| {
-            ctx.set((&cur_context as *const WorkerHandle).cast::<()>());
-        });
-
-        let ret = match &self.async_spawner {
-            #[cfg(feature = "current_thread_runtime")]
-            AsyncHandle::CurrentThread(current_thread) => current_thread.block_on(task),
-            AsyncHandle::MultiThread(_) => block_on::block_on(task),
-        };
-
-        // Sets the current thread variable to null,
-        // otherwise the worker's CURRENT_WORKER can not be set under MultiThread.
-        worker::CURRENT_HANDLE.with(|ctx| {
-            ctx.set(std::ptr::null());
-        });
-
-        ret
-    }
-
-    #[cfg(feature = "ffrt")]
-    fn block_on_inner<T, R>(&self, task: T) -> R
-    where
-        T: Future<Output = R>,
-    {
-        match &self.async_spawner {
-            #[cfg(feature = "current_thread_runtime")]
-            AsyncHandle::CurrentThread(current_thread) => current_thread.block_on(task),
-            AsyncHandle::FfrtMultiThread => block_on::block_on(task),
-        }
+        self.handle.block_on(task)
     }
 }
 
 cfg_metrics!(
-    use crate::metrics::Metrics;
+    use crate::metrics::{Metrics, RuntimeDump};
     impl Runtime {
         /// User can get some message from Runtime during running.
         ///
@@ -347,6 +532,26 @@ cfg_metrics!(
         pub fn metrics(&self) -> Metrics {
             Metrics::new(self)
         }
+
+        /// Takes a snapshot of all the live tasks of the runtime, including
+        /// the blocking ones.
+        ///
+        /// To make the dump possible, every task is registered in a sharded
+        /// table of its scheduler while the `metrics` feature is enabled, which
+        /// costs a lock on each spawn and release of a task. The dump itself
+        /// locks every shard in turn.
+        ///
+        /// # Example
+        /// ```
+        /// let runtime = ylong_runtime::builder::RuntimeBuilder::new_multi_thread().build().unwrap();
+        /// let _handle = runtime.spawn(std::future::pending::<()>());
+        /// for task in runtime.dump().tasks() {
+        ///     println!("{}: {:?}", task.id(), task.status());
+        /// }
+        /// ```
+        pub fn dump(&self) -> RuntimeDump {
+            RuntimeDump::new(self)
+        }
     }
 
     /// Gets metrics of the global Runtime.
@@ -359,6 +564,17 @@ cfg_metrics!(
     pub fn get_global_runtime_metrics() -> Metrics<'static> {
         Metrics::new(global_default_async())
     }
+
+    /// Takes a snapshot of all the live tasks of the global Runtime.
+    /// # Example
+    /// ```
+    /// use ylong_runtime::executor::get_global_runtime_dump;
+    ///
+    /// let dump = get_global_runtime_dump();
+    /// ```
+    pub fn get_global_runtime_dump() -> RuntimeDump {
+        RuntimeDump::new(global_default_async())
+    }
 );
 
 #[cfg(test)]
@@ -17,6 +17,7 @@ use std::sync::{Arc, Condvar, Mutex};
 use std::thread;
 use std::time::Duration;
 
+use crate::builder::{WorkerHook, WorkerInfo};
 use crate::executor::driver::{Driver, Handle, ParkFlag};
 
 #[derive(Clone)]
@@ -29,6 +30,41 @@ struct Inner {
     mutex: Mutex<()>,
     condvar: Condvar,
     driver: Arc<Mutex<Driver>>,
+    hooks: Option<ParkHooks>,
+}
+
+/// Callbacks called around the actual parking of a worker, which are set by
+/// `on_thread_park` and `on_thread_unpark` of the builder.
+pub(crate) struct ParkHooks {
+    info: WorkerInfo,
+    on_park: Option<WorkerHook>,
+    on_unpark: Option<WorkerHook>,
+}
+
+impl ParkHooks {
+    pub(crate) fn new(
+        info: WorkerInfo,
+        on_park: Option<WorkerHook>,
+        on_unpark: Option<WorkerHook>,
+    ) -> Self {
+        ParkHooks {
+            info,
+            on_park,
+            on_unpark,
+        }
+    }
+
+    fn park(&self) {
+        if let Some(f) = &self.on_park {
+            f(&self.info);
+        }
+    }
+
+    fn unpark(&self) {
+        if let Some(f) = &self.on_unpark {
+            f(&self.info);
+        }
+    }
 }
 
 const IDLE: usize = 0;
@@ -38,12 +74,21 @@ const NOTIFIED: usize = 3;
 
 impl Parker {
     pub(crate) fn new(driver: Arc<Mutex<Driver>>) -> Parker {
+        Parker::build(driver, None)
+    }
+
+    pub(crate) fn with_hooks(driver: Arc<Mutex<Driver>>, hooks: ParkHooks) -> Parker {
+        Parker::build(driver, Some(hooks))
+    }
+
+    fn build(driver: Arc<Mutex<Driver>>, hooks: Option<ParkHooks>) -> Parker {
         Parker {
             inner: Arc::new(Inner {
                 state: AtomicUsize::new(IDLE),
                 mutex: Mutex::new(()),
                 condvar: Condvar::new(),
                 driver,
+                hooks,
             }),
         }
     }
@@ -84,6 +129,10 @@ impl Inner {
             thread::yield_now();
         }
 
+        if let Some(hooks) = &self.hooks {
+            hooks.park();
+        }
+
         let mut park_flag = ParkFlag::Park;
         if let Ok(mut driver) = self.driver.try_lock() {
             park_flag = self.park_on_driver(&mut driver);
@@ -94,6 +143,10 @@ impl Inner {
             ParkFlag::Park => self.park_on_condvar_timeout(None),
             ParkFlag::ParkTimeout(duration) => self.park_on_condvar_timeout(Some(duration)),
         }
+
+        if let Some(hooks) = &self.hooks {
+            hooks.unpark();
+        }
     }
 
     fn park_on_driver(&self, driver: &mut Driver) -> ParkFlag {
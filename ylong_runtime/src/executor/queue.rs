@@ -23,7 +23,7 @@ use std::{cmp, ptr};
 /// Schedule strategy implementation, includes FIFO LIFO priority and
 /// work-stealing work-stealing strategy include stealing half of every worker
 /// or the largest amount of worker
-use crate::task::{Header, Task};
+use crate::task::{Header, Qos, Task};
 use crate::util::linked_list::LinkedList;
 
 unsafe fn non_atomic_load(data: &AtomicU16) -> u16 {
@@ -422,13 +422,28 @@ impl Drop for InnerBuffer {
     }
 }
 
+/// Number of qos levels, each of them owns a list inside the global queue
+pub(crate) const QOS_LEVELS: usize = Qos::UserInteractive as usize + 1;
+
+#[inline]
+fn qos_level(header: &Header) -> usize {
+    match header.qos {
+        // inherent qos is resolved during task creation, this arm is just a fallback
+        Qos::Inherent => Qos::Default as usize,
+        qos => qos as usize,
+    }
+}
+
 pub(crate) struct GlobalQueue {
     /// Current number of tasks
     len: AtomicUsize,
+    /// Current number of tasks whose qos is higher than the default level
+    urgent_len: AtomicUsize,
     /// The total number of tasks which has entered global queue.
     #[cfg(feature = "metrics")]
     count: AtomicU64,
-    globals: Mutex<LinkedList<Header>>,
+    /// One list for each qos level, indexed by the level
+    globals: Mutex<Vec<LinkedList<Header>>>,
 }
 
 impl Drop for GlobalQueue {
@@ -443,25 +458,58 @@ impl Drop for GlobalQueue {
 
 impl GlobalQueue {
     pub(crate) fn new() -> Self {
+        let mut globals = Vec::with_capacity(QOS_LEVELS);
+        for _ in 0..QOS_LEVELS {
+            globals.push(LinkedList::new());
+        }
         GlobalQueue {
             len: AtomicUsize::new(0_usize),
+            urgent_len: AtomicUsize::new(0_usize),
             #[cfg(feature = "metrics")]
             count: AtomicU64::new(0),
-            globals: Mutex::new(LinkedList::new()),
+            globals: Mutex::new(globals),
         }
     }
     pub(super) fn is_empty(&self) -> bool {
         self.len.load(Acquire) == 0
     }
 
+    /// Checks whether there are tasks with a qos higher than the default level.
+    /// These tasks should be polled before the ones inside the local queues.
+    pub(super) fn has_urgent(&self) -> bool {
+        self.urgent_len.load(Acquire) != 0
+    }
+
+    fn push_by_qos(&self, lists: &mut [LinkedList<Header>], task: Task) {
+        let header = task.into_header();
+        let level = qos_level(unsafe { header.as_ref() });
+        if level > Qos::Default as usize {
+            self.urgent_len.fetch_add(1, AcqRel);
+        }
+        lists[level].push_front(header);
+    }
+
+    // Pops a task from the list with the highest qos level
+    fn pop_by_qos(&self, lists: &mut [LinkedList<Header>]) -> Option<Task> {
+        for (level, list) in lists.iter_mut().enumerate().rev() {
+            if let Some(header) = list.pop_back() {
+                if level > Qos::Default as usize {
+                    self.urgent_len.fetch_sub(1, AcqRel);
+                }
+                return Some(unsafe { Task::from_raw(header) });
+            }
+        }
+        None
+    }
+
     pub(super) fn push_batch(&self, tasks: Vec<UnsafeCell<MaybeUninit<Task>>>, task: Task) {
-        let mut list = self.globals.lock().unwrap();
+        let mut lists = self.globals.lock().unwrap();
         let len = tasks.len() + 1;
         for task_ptr in tasks {
             let task = unsafe { ptr::read(task_ptr.get()).assume_init() };
-            list.push_front(task.into_header());
+            self.push_by_qos(&mut lists, task);
         }
-        list.push_front(task.into_header());
+        self.push_by_qos(&mut lists, task);
         self.len.fetch_add(len, AcqRel);
         #[cfg(feature = "metrics")]
         self.count.fetch_add(len as u64, AcqRel);
@@ -481,17 +529,17 @@ impl GlobalQueue {
         let rear = unsafe { non_atomic_load(&inner_buf.rear) };
         let mut curr = rear;
 
-        let mut list = self.globals.lock().unwrap();
-        let first_task = unsafe { Task::from_raw(list.pop_back()?) };
+        let mut lists = self.globals.lock().unwrap();
+        let first_task = self.pop_by_qos(&mut lists)?;
 
         let mut count = 1;
 
         for _ in 1..num {
-            if let Some(task) = list.pop_back() {
+            if let Some(task) = self.pop_by_qos(&mut lists) {
                 let idx = (curr & MASK) as usize;
                 let ptr = inner_buf.buffer[idx].get();
                 unsafe {
-                    ptr::write((*ptr).as_mut_ptr(), Task::from_raw(task));
+                    ptr::write((*ptr).as_mut_ptr(), task);
                 }
                 curr = curr.wrapping_add(1);
                 count += 1;
@@ -499,7 +547,7 @@ impl GlobalQueue {
                 break;
             }
         }
-        drop(list);
+        drop(lists);
         self.len.fetch_sub(count, AcqRel);
         inner_buf.rear.store(curr, Release);
 
@@ -516,23 +564,20 @@ impl GlobalQueue {
         if self.is_empty() {
             return None;
         }
-        let mut list = self.globals.lock().unwrap();
-        let task = list
-            .pop_back()
-            .map(|header| unsafe { Task::from_raw(header) });
+        let mut lists = self.globals.lock().unwrap();
+        let task = self.pop_by_qos(&mut lists);
         if task.is_some() {
             self.len.fetch_sub(1, AcqRel);
         }
-        drop(list);
+        drop(lists);
         task
     }
 
     pub(super) fn push_back(&self, task: Task) {
-        let mut list = self.globals.lock().unwrap();
-        let header = task.into_header();
-        list.push_front(header);
+        let mut lists = self.globals.lock().unwrap();
+        self.push_by_qos(&mut lists, task);
         self.len.fetch_add(1, AcqRel);
-        drop(list);
+        drop(lists);
         #[cfg(feature = "metrics")]
         self.count.fetch_add(1, AcqRel);
     }
@@ -561,7 +606,7 @@ pub(crate) mod test {
     use crate::executor::async_pool::MultiThreadScheduler;
     use crate::executor::driver::Driver;
     use crate::executor::queue::{GlobalQueue, InnerBuffer, LocalQueue, LOCAL_QUEUE_CAP};
-    use crate::task::{TaskBuilder, VirtualTableType};
+    use crate::task::{Qos, TaskBuilder, VirtualTableType};
 
     #[cfg(any(not(feature = "metrics"), feature = "ffrt"))]
     impl InnerBuffer {
@@ -1100,4 +1145,52 @@ pub(crate) mod test {
 
         assert!(other_local_queue.steal_into(&local_queue).is_none());
     }
+
+    /// UT test cases for GlobalQueue qos ordering
+    ///
+    /// # Brief
+    /// 1. Push tasks with different qos levels into the global queue
+    /// 2. Check that tasks are popped from the highest qos level to the lowest
+    /// 3. Check that the urgent flag is cleared after all urgent tasks are
+    ///    popped
+    #[test]
+    fn ut_global_queue_qos_order() {
+        let global_queue = GlobalQueue::new();
+        let (arc_handle, _) = Driver::initialize();
+        let multi_scheduler = Arc::new(MultiThreadScheduler::new(1, arc_handle));
+
+        let levels = [
+            Qos::Background,
+            Qos::UserInteractive,
+            Qos::Default,
+            Qos::Utility,
+            Qos::DeadlineRequest,
+        ];
+        for qos in levels {
+            let builder = TaskBuilder::new().qos(qos);
+            let (task, _) = create_task(
+                &builder,
+                Arc::downgrade(&multi_scheduler),
+                test_future(),
+                VirtualTableType::Ylong,
+            );
+            global_queue.push_back(task);
+        }
+        assert!(global_queue.has_urgent());
+
+        let expected = [
+            Qos::UserInteractive,
+            Qos::DeadlineRequest,
+            Qos::Default,
+            Qos::Utility,
+            Qos::Background,
+        ];
+        for qos in expected {
+            let task = global_queue.pop_front().unwrap();
+            assert_eq!(task.0.header().qos, qos);
+            task.shutdown();
+        }
+        assert!(!global_queue.has_urgent());
+        assert!(global_queue.is_empty());
+    }
 }
@@ -22,15 +22,33 @@ pub(crate) struct Sleeper {
 }
 
 impl Sleeper {
-    pub fn new(num_workers: usize) -> Self {
+    /// Creates a sleeper for `active_num` running workers, with room for up to
+    /// `num_workers` workers.
+    pub fn new(active_num: usize, num_workers: usize) -> Self {
         Sleeper {
-            record: Record::new(num_workers),
+            record: Record::new(active_num),
             idle_list: Mutex::new(Vec::with_capacity(num_workers)),
             num_workers,
             wake_by_search: Mutex::new(vec![false; num_workers]),
         }
     }
 
+    // Returns the number of workers inside the idle list
+    pub fn idle_num(&self) -> usize {
+        self.idle_list.lock().unwrap().len()
+    }
+
+    // A retired worker is neither active nor inside the idle list.
+    // Returns true if it's the last active thread.
+    pub fn retire_worker(&self) -> bool {
+        self.record.dec_active_num()
+    }
+
+    #[inline]
+    pub fn resume_worker(&self) {
+        self.record.inc_active_num();
+    }
+
     pub fn is_parked(&self, worker_index: &usize) -> bool {
         let idle_list = self.idle_list.lock().unwrap();
         idle_list.contains(worker_index)
@@ -0,0 +1,200 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Watchdog that detects the workers stuck inside a single poll, which usually
+//! means that the task runs blocking code.
+
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Mutex, Weak};
+use std::time::{Duration, Instant};
+use std::{cmp, thread};
+
+use crate::builder::BlockingDetectionHook;
+use crate::executor::async_pool::MultiThreadScheduler;
+use crate::task::{RawTask, Task, TaskId};
+
+const MIN_CHECK_INTERVAL: Duration = Duration::from_millis(1);
+
+/// Information of a worker stuck inside a single poll, which is passed to the
+/// hook set by `blocking_detection_hook`.
+#[derive(Clone, Debug)]
+pub struct BlockingInfo {
+    worker_index: usize,
+    task_id: TaskId,
+    task_name: Option<String>,
+    elapsed: Duration,
+}
+
+impl BlockingInfo {
+    /// Gets the index of the blocked worker.
+    pub fn worker_index(&self) -> usize {
+        self.worker_index
+    }
+
+    /// Gets the ID of the task being polled by the worker.
+    pub fn task_id(&self) -> TaskId {
+        self.task_id
+    }
+
+    /// Gets the name of the task set by `TaskBuilder::name`.
+    pub fn task_name(&self) -> Option<&str> {
+        self.task_name.as_deref()
+    }
+
+    /// Gets how long the worker has been inside the poll when it's detected.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+}
+
+/// Settings of the blocking detection, which is disabled by default.
+#[derive(Clone, Default)]
+pub(crate) struct BlockingDetection {
+    /// Polls taking longer than this are reported, `None` means disabled
+    pub(crate) threshold: Option<Duration>,
+    /// Called when a blocked worker is detected
+    pub(crate) hook: Option<BlockingDetectionHook>,
+    /// Whether to hand the local queue of the blocked worker over to the others
+    pub(crate) offload: bool,
+}
+
+struct Polling {
+    task: RawTask,
+    start: Instant,
+    reported: bool,
+}
+
+/// The poll being run by a worker, shared with the watchdog.
+#[derive(Default)]
+pub(crate) struct PollWatch {
+    polling: Mutex<Option<Polling>>,
+}
+
+// The task inside is only accessed while holding the lock, and it's kept alive
+// by the reference taken in `start`.
+unsafe impl Send for PollWatch {}
+unsafe impl Sync for PollWatch {}
+
+impl PollWatch {
+    /// Gets called right before the worker polls the task.
+    pub(crate) fn start(&self, task: &Task) {
+        task.0.header().state.inc_ref();
+        *self.polling.lock().unwrap() = Some(Polling {
+            task: task.0,
+            start: Instant::now(),
+            reported: false,
+        });
+    }
+
+    /// Gets called right after the worker polls the task.
+    pub(crate) fn end(&self) {
+        let polling = self.polling.lock().unwrap().take();
+        if let Some(polling) = polling {
+            polling.task.drop_ref();
+        }
+    }
+
+    // Returns the information of the poll if it takes longer than the threshold
+    // and has not been reported yet.
+    fn check(&self, index: usize, threshold: Duration) -> Option<BlockingInfo> {
+        let mut polling = self.polling.lock().unwrap();
+        let polling = polling.as_mut()?;
+        let elapsed = polling.start.elapsed();
+        if polling.reported || elapsed < threshold {
+            return None;
+        }
+        polling.reported = true;
+        let header = polling.task.header();
+        Some(BlockingInfo {
+            worker_index: index,
+            task_id: header.id,
+            task_name: header.name.clone(),
+            elapsed,
+        })
+    }
+}
+
+/// Runs the watchdog until the executor gets shut down.
+pub(crate) fn watchdog_proc(scheduler: Weak<MultiThreadScheduler>, detection: BlockingDetection) {
+    let threshold = match detection.threshold {
+        Some(threshold) => threshold,
+        None => return,
+    };
+    let interval = cmp::max(threshold / 4, MIN_CHECK_INTERVAL);
+    loop {
+        thread::sleep(interval);
+        let scheduler = match scheduler.upgrade() {
+            Some(scheduler) => scheduler,
+            None => return,
+        };
+        if scheduler.is_cancel() {
+            return;
+        }
+
+        for (index, watch) in scheduler.poll_watches.iter().enumerate() {
+            let info = match watch.check(index, threshold) {
+                Some(info) => info,
+                None => continue,
+            };
+            if let Some(hook) = &detection.hook {
+                // a panicking hook should not take down the watchdog
+                let _ = panic::catch_unwind(AssertUnwindSafe(|| hook(&info)));
+            }
+            if detection.offload {
+                scheduler.offload_local_queue(index);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Weak;
+    use std::time::Duration;
+
+    use crate::executor::watchdog::PollWatch;
+    use crate::executor::PlaceholderScheduler;
+    use crate::task::{Task, TaskBuilder, VirtualTableType};
+
+    /// UT test cases for `PollWatch`
+    ///
+    /// # Brief
+    /// 1. Start a poll of a named task, check that it's not reported before
+    ///    reaching the threshold
+    /// 2. Check that the poll gets reported once after reaching the threshold
+    /// 3. End the poll, check that nothing gets reported anymore
+    #[test]
+    fn ut_poll_watch_check() {
+        let builder = TaskBuilder::new().name("blocked".to_string());
+        let scheduler: Weak<PlaceholderScheduler> = Weak::new();
+        let (task, handle) =
+            Task::create_task(&builder, scheduler, async {}, VirtualTableType::Ylong);
+
+        let watch = PollWatch::default();
+        assert!(watch.check(0, Duration::ZERO).is_none());
+        watch.start(&task);
+        assert!(watch.check(0, Duration::from_secs(100)).is_none());
+        std::thread::sleep(Duration::from_millis(5));
+        let info = watch.check(3, Duration::from_millis(1)).unwrap();
+        assert_eq!(info.worker_index(), 3);
+        assert_eq!(info.task_id(), handle.id());
+        assert_eq!(info.task_name(), Some("blocked"));
+        assert!(info.elapsed() >= Duration::from_millis(1));
+        assert!(watch.check(3, Duration::from_millis(1)).is_none());
+        watch.end();
+        assert!(watch.check(0, Duration::ZERO).is_none());
+
+        task.cancel_queued();
+        drop(handle);
+    }
+}
@@ -17,11 +17,16 @@ use std::sync::Arc;
 use std::task::Waker;
 
 /// worker struct info and method
+use crate::builder::ScheduleAlgo;
 use crate::executor::async_pool::MultiThreadScheduler;
 use crate::executor::driver::Handle;
 use crate::executor::parker::Parker;
 use crate::executor::queue::LocalQueue;
-use crate::task::Task;
+use crate::task::{coop, Task};
+
+cfg_metrics! {
+    use crate::metrics::PollStats;
+}
 
 thread_local! {
     pub(crate) static CURRENT_WORKER: Cell<* const ()> = Cell::new(ptr::null());
@@ -52,12 +57,27 @@ impl WorkerContext {
 
     #[inline]
     fn release(&mut self) {
+        // the yielded tasks get canceled once woken up, otherwise they would
+        // never be scheduled again
+        self.wake_yield();
         self.worker.release();
     }
 }
 
 pub(crate) struct WorkerHandle {
     pub(crate) _handle: Arc<Handle>,
+    // Whether the handle is set by `Handle::enter` on a thread that is not
+    // driving any task, so that the thread could still be blocked
+    pub(crate) entered: bool,
+}
+
+/// Checks whether the current thread is driving asynchronous tasks
+#[inline]
+pub(crate) fn is_driving_tasks() -> bool {
+    CURRENT_HANDLE.with(|ctx| {
+        let val = ctx.get();
+        !val.is_null() && !unsafe { &*(val.cast::<WorkerHandle>()) }.entered
+    })
 }
 
 /// Gets the handle of the current thread
@@ -91,7 +111,10 @@ pub(crate) fn get_current_ctx() -> Option<&'static WorkerContext> {
 pub(crate) fn run_worker(worker: Arc<Worker>, handle: Arc<Handle>) {
     let mut cur_context = WorkerContext { worker };
 
-    let cur_handle = WorkerHandle { _handle: handle };
+    let cur_handle = WorkerHandle {
+        _handle: handle,
+        entered: false,
+    };
 
     struct Reset(*const (), *const ());
 
@@ -126,6 +149,9 @@ pub(crate) struct Worker {
     pub(crate) inner: RefCell<Box<Inner>>,
     pub(crate) lifo: RefCell<Option<Task>>,
     pub(crate) yielded: RefCell<Vec<Waker>>,
+    /// Poll statistics of the worker
+    #[cfg(feature = "metrics")]
+    pub(crate) poll_stats: PollStats,
 }
 
 unsafe impl Send for Worker {}
@@ -137,6 +163,11 @@ impl Worker {
         let inner = inner.as_mut();
 
         while !inner.is_cancel() {
+            if self.scheduler.is_retired(self.index) {
+                self.retire(inner, worker_ctx);
+                continue;
+            }
+
             inner.increment_count();
             inner.periodic_check(self);
 
@@ -145,7 +176,20 @@ impl Worker {
                     inner.is_searching = false;
                     self.scheduler.wake_up_rand_one_if_last_search();
                 }
-                task.run();
+                #[cfg(feature = "metrics")]
+                let start = self.poll_stats.start_poll(&task);
+                let watch = self.scheduler.poll_watch(self.index);
+                if let Some(watch) = watch {
+                    watch.start(&task);
+                }
+                coop::with_task_budget(self.scheduler.task_budget, || task.run());
+                if let Some(watch) = watch {
+                    watch.end();
+                }
+                #[cfg(feature = "metrics")]
+                self.poll_stats.end_poll(start);
+                // stop running the queued tasks once the pool gets shut down
+                self.check_cancel(inner);
                 continue;
             }
 
@@ -161,10 +205,19 @@ impl Worker {
 
     fn get_task(&self, inner: &mut Inner, worker_ctx: &WorkerContext) -> Option<Task> {
         // schedule lifo task first
-        let mut lifo_slot = worker_ctx.worker.lifo.borrow_mut();
-        if let Some(task) = lifo_slot.take() {
-            return Some(task);
+        let lifo_task = worker_ctx.worker.lifo.borrow_mut().take();
+        if let Some(task) = lifo_task {
+            // Under LifoSlot policy, tasks could keep waking each other up through the
+            // slot, so the slot is bounded to give the local queue a chance
+            if self.scheduler.schedule_algo != ScheduleAlgo::LifoSlot
+                || inner.lifo_polls < MAX_LIFO_POLLS
+            {
+                inner.lifo_polls = inner.lifo_polls.saturating_add(1);
+                return Some(task);
+            }
+            inner.run_queue.push_back(task, &self.scheduler.global);
         }
+        inner.lifo_polls = 0;
 
         self.scheduler.dequeue(inner, worker_ctx)
     }
@@ -194,6 +247,12 @@ impl Worker {
                 break;
             }
 
+            // the pool has shrunk while the worker is sleeping
+            if self.scheduler.is_retired(self.index) {
+                self.scheduler.turn_from_sleep(&self.index);
+                break;
+            }
+
             if self.scheduler.is_parked(&self.index) {
                 self.check_cancel(inner);
                 continue;
@@ -202,6 +261,27 @@ impl Worker {
         }
     }
 
+    // Hands the tasks of the worker over to the others, and parks until the pool
+    // grows again or gets shut down.
+    fn retire(&self, inner: &mut Inner, worker_ctx: &WorkerContext) {
+        // tasks woken up by a retired worker go to the global queue
+        worker_ctx.wake_yield();
+        let lifo_task = self.lifo.borrow_mut().take();
+        if let Some(task) = lifo_task {
+            self.scheduler.global.push_back(task);
+        }
+        while let Some(task) = inner.run_queue.pop_front() {
+            self.scheduler.global.push_back(task);
+        }
+        self.scheduler.turn_to_retired(inner);
+
+        while !inner.is_cancel && self.scheduler.is_retired(self.index) {
+            inner.parker.park();
+            self.check_cancel(inner);
+        }
+        self.scheduler.turn_from_retired();
+    }
+
     /// Gets Worker's Inner with ptr.
     ///
     /// # Safety
@@ -214,10 +294,19 @@ impl Worker {
         &*ptr
     }
 
-    #[inline]
     fn release(&self) {
-        // wait for tasks in queue to finish
-        while !self.scheduler.has_no_work() {}
+        // cancel the tasks left in the queues, so that their JoinHandles get notified
+        let inner = self.inner.borrow();
+        loop {
+            let task = self.lifo.borrow_mut().take();
+            let task = task
+                .or_else(|| inner.run_queue.pop_front())
+                .or_else(|| self.scheduler.global.pop_front());
+            match task {
+                Some(task) => task.cancel_queued(),
+                None => break,
+            }
+        }
     }
 }
 
@@ -230,6 +319,8 @@ pub(crate) struct Inner {
     pub(crate) run_queue: LocalQueue,
     pub(crate) parker: Parker,
     pub(crate) is_searching: bool,
+    /// Number of tasks polled from the lifo slot in a row
+    lifo_polls: u8,
 }
 
 impl Inner {
@@ -240,11 +331,13 @@ impl Inner {
             run_queue: run_queues,
             parker,
             is_searching: false,
+            lifo_polls: 0,
         }
     }
 }
 
 const GLOBAL_PERIODIC_INTERVAL: u8 = 61;
+const MAX_LIFO_POLLS: u8 = 3;
 
 impl Inner {
     #[inline]
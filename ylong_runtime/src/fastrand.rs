@@ -19,6 +19,10 @@ use std::collections::hash_map::RandomState;
 use std::hash::{BuildHasher, Hasher};
 use std::num::Wrapping;
 
+thread_local! {
+    static RNG: Cell<Wrapping<u64>> = Cell::new(Wrapping(seed()));
+}
+
 /// Generates a fast random ranging from 0 to usize::MAX
 ///
 /// # Examples
@@ -28,10 +32,6 @@ use std::num::Wrapping;
 /// assert!(rand <= u64::MAX);
 /// ```
 pub fn fast_random() -> u64 {
-    thread_local! {
-        static RNG: Cell<Wrapping<u64>> = Cell::new(Wrapping(seed()));
-    }
-
     RNG.with(|rng| {
         let mut s = rng.get();
         s ^= s >> 12;
@@ -42,6 +42,21 @@ pub fn fast_random() -> u64 {
     })
 }
 
+/// Reseeds the generator of the current thread, so that the random numbers it
+/// generates afterwards are reproducible.
+pub(crate) fn set_thread_seed(seed: u64) {
+    // Scrambles the seed with SplitMix64, so that close seeds give unrelated
+    // sequences. The state of xorshift must not be zero.
+    let mut z = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^= z >> 31;
+    if z == 0 {
+        z = 0x9e37_79b9_7f4a_7c15;
+    }
+    RNG.with(|rng| rng.set(Wrapping(z)));
+}
+
 fn seed() -> u64 {
     let seed = RandomState::new();
 
@@ -923,9 +923,13 @@ mod test {
     /// 7. Check if the attempt succeeds
     #[test]
     fn ut_file_try_into_std() {
-        let file_path = "file18.txt";
+        // The file is kept out of the source tree, and the one left by an
+        // aborted run gets removed first.
+        let file_path = std::env::temp_dir().join("ylong_file18.txt");
+        let _ = std::fs::remove_file(&file_path);
+        let path = file_path.clone();
         let handle = crate::spawn(async move {
-            let mut file = File::create(file_path).await.unwrap();
+            let mut file = File::create(path).await.unwrap();
             let res = file.write_all(b"hello").await;
             assert!(res.is_ok());
 
@@ -958,9 +962,11 @@ mod test {
         use std::os::fd::{AsFd, AsRawFd};
         use std::os::unix::io::FromRawFd;
 
-        let file_path = "file19.txt";
+        let file_path = std::env::temp_dir().join("ylong_file19.txt");
+        let _ = std::fs::remove_file(&file_path);
+        let path = file_path.clone();
         let handle = crate::spawn(async move {
-            let file = File::create(file_path).await.unwrap();
+            let file = File::create(path).await.unwrap();
             let std = file.into_std().await;
             let fd = std.as_raw_fd();
             let file = File::from(std);
@@ -35,13 +35,13 @@ where
     let task_builder = TaskBuilder::new();
     let runtime = global_default_async();
 
-    match runtime.async_spawner {
+    match runtime.handle.inner.async_spawner {
         #[cfg(feature = "current_thread_runtime")]
         AsyncHandle::CurrentThread(_) => Ok(consumer.consume(par_iter)),
         #[cfg(not(feature = "ffrt"))]
         AsyncHandle::MultiThread(ref runtime) => {
             const MIN_SPLIT_LEN: usize = 1;
-            let split_time = runtime.exe_mng_info.num_workers;
+            let split_time = runtime.exe_mng_info.num_workers();
             recur(
                 runtime,
                 &task_builder,
@@ -202,7 +202,7 @@ where
         recur_ffrt(task_builder, par_iter, consumer, min_split_len, split_time),
         VirtualTableType::Ffrt,
     );
-    let join_handle = JoinHandle::new(raw_task);
+    let join_handle = JoinHandle::new(raw_task, task_builder);
     let task = Task(raw_task);
     ffrt_submit(task, task_builder);
     join_handle
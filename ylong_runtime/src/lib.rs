@@ -71,7 +71,7 @@ cfg_sync! {
 
 cfg_metrics! {
     mod metrics;
-    pub use metrics::Metrics;
+    pub use metrics::{Histogram, Metrics, RuntimeDump, TaskDump, TaskStatus};
 }
 
 cfg_fs! {
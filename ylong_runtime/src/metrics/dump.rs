@@ -0,0 +1,333 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering::Relaxed;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::executor::{global_default_blocking, AsyncHandle, Runtime};
+use crate::task::{state, Header, Qos, RawTask, TaskId};
+
+/// Poll statistics of a task.
+pub(crate) struct TaskStats {
+    spawned_at: Instant,
+    poll_count: AtomicU64,
+    // Nanoseconds between the spawn and the start of the last poll plus one,
+    // zero means the task has never been polled
+    last_poll: AtomicU64,
+    // Nanoseconds between the spawn and the last time the task got scheduled
+    scheduled: AtomicU64,
+}
+
+impl TaskStats {
+    pub(crate) fn new() -> Self {
+        TaskStats {
+            spawned_at: Instant::now(),
+            poll_count: AtomicU64::new(0),
+            last_poll: AtomicU64::new(0),
+            scheduled: AtomicU64::new(0),
+        }
+    }
+
+    /// Gets called each time before the task gets polled.
+    pub(crate) fn record_poll(&self) {
+        self.poll_count.fetch_add(1, Relaxed);
+        let nanos = self.elapsed_nanos();
+        self.last_poll.store(nanos.saturating_add(1), Relaxed);
+    }
+
+    /// Gets called each time the task gets woken up and pushed into a queue.
+    pub(crate) fn record_schedule(&self) {
+        self.scheduled.store(self.elapsed_nanos(), Relaxed);
+    }
+
+    /// Returns when the task got scheduled for the last time, which is the
+    /// spawn time if the task has never been woken up.
+    pub(crate) fn scheduled_at(&self) -> Instant {
+        self.spawned_at + Duration::from_nanos(self.scheduled.load(Relaxed))
+    }
+
+    fn elapsed_nanos(&self) -> u64 {
+        u64::try_from(self.spawned_at.elapsed().as_nanos()).unwrap_or(u64::MAX)
+    }
+
+    fn last_poll(&self) -> Option<Instant> {
+        match self.last_poll.load(Relaxed) {
+            0 => None,
+            nanos => Some(self.spawned_at + Duration::from_nanos(nanos - 1)),
+        }
+    }
+}
+
+// Number of shards of a task registry, must be a power of two
+const SHARD_NUM: usize = 16;
+
+/// All the live tasks of a scheduler. A task gets registered when it's
+/// created and removed right before its memory gets released.
+///
+/// The tasks are spread over several shards by their addresses, so that
+/// spawning and releasing tasks on different workers rarely contend on the
+/// same lock.
+pub(crate) struct TaskRegistry {
+    // Keyed by the address of the task header
+    shards: [Mutex<HashMap<usize, RawTask>>; SHARD_NUM],
+}
+
+// SAFETY: The registry holds the tasks without a ref count. This is sound since
+// `TaskHandle::release` removes a task from the registry before its memory gets
+// freed, and the registered tasks are only dereferenced while holding the lock
+// of their shard, so a task could not be freed while it's being read.
+unsafe impl Send for TaskRegistry {}
+// SAFETY: See the `Send` impl above, all the accesses go through the locks.
+unsafe impl Sync for TaskRegistry {}
+
+impl Default for TaskRegistry {
+    fn default() -> Self {
+        TaskRegistry {
+            shards: std::array::from_fn(|_| Mutex::new(HashMap::new())),
+        }
+    }
+}
+
+impl TaskRegistry {
+    fn shard(&self, addr: usize) -> &Mutex<HashMap<usize, RawTask>> {
+        // Tasks are allocated separately, the low bits are mostly the same
+        &self.shards[(addr >> 6) & (SHARD_NUM - 1)]
+    }
+
+    pub(crate) fn insert(&self, task: RawTask) {
+        let addr = task.ptr.as_ptr() as usize;
+        let mut tasks = self.shard(addr).lock().unwrap();
+        tasks.insert(addr, task);
+    }
+
+    pub(crate) fn remove(&self, header: &Header) {
+        let addr = header as *const Header as usize;
+        let mut tasks = self.shard(addr).lock().unwrap();
+        tasks.remove(&addr);
+    }
+
+    fn dump_into(&self, dumps: &mut Vec<TaskDump>) {
+        for shard in &self.shards {
+            let tasks = shard.lock().unwrap();
+            dumps.extend(tasks.values().map(|task| TaskDump::new(task.header())));
+        }
+    }
+}
+
+/// Status of a task at the moment of the dump.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TaskStatus {
+    /// The task is waiting inside a queue to be polled
+    Scheduled,
+    /// The task is being polled
+    Running,
+    /// The task is pending and waits to be woken up
+    Idle,
+    /// The task has finished, but its output has not been taken yet
+    Completed,
+    /// The task has been canceled
+    Canceled,
+}
+
+impl TaskStatus {
+    fn from_state(cur: usize) -> TaskStatus {
+        if state::is_canceled(cur) {
+            TaskStatus::Canceled
+        } else if state::is_finished(cur) {
+            TaskStatus::Completed
+        } else if state::is_running(cur) {
+            TaskStatus::Running
+        } else if state::is_scheduling(cur) {
+            TaskStatus::Scheduled
+        } else {
+            TaskStatus::Idle
+        }
+    }
+}
+
+/// Snapshot of a live task.
+#[derive(Clone, Debug)]
+pub struct TaskDump {
+    id: TaskId,
+    name: Option<String>,
+    qos: Qos,
+    status: TaskStatus,
+    poll_count: u64,
+    spawned_at: Instant,
+    last_poll: Option<Instant>,
+}
+
+impl TaskDump {
+    fn new(header: &Header) -> TaskDump {
+        TaskDump {
+            id: header.id,
+            name: header.name.clone(),
+            qos: header.qos,
+            status: TaskStatus::from_state(header.state.get_current_state()),
+            poll_count: header.stats.poll_count.load(Relaxed),
+            spawned_at: header.stats.spawned_at,
+            last_poll: header.stats.last_poll(),
+        }
+    }
+
+    /// Returns the ID of the task.
+    pub fn id(&self) -> TaskId {
+        self.id
+    }
+
+    /// Returns the name of the task set by the `TaskBuilder`.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the qos level of the task.
+    pub fn qos(&self) -> Qos {
+        self.qos
+    }
+
+    /// Returns the status of the task.
+    pub fn status(&self) -> TaskStatus {
+        self.status
+    }
+
+    /// Returns how many times the task has been polled.
+    pub fn poll_count(&self) -> u64 {
+        self.poll_count
+    }
+
+    /// Returns when the task was spawned.
+    pub fn spawned_at(&self) -> Instant {
+        self.spawned_at
+    }
+
+    /// Returns when the task was polled for the last time, or `None` if it has
+    /// never been polled.
+    pub fn last_poll(&self) -> Option<Instant> {
+        self.last_poll
+    }
+}
+
+/// Snapshot of all the live tasks of a runtime.
+///
+/// # Example
+/// ```
+/// let runtime = ylong_runtime::builder::RuntimeBuilder::new_multi_thread()
+///     .build()
+///     .unwrap();
+/// let dump = runtime.dump();
+/// for task in dump.tasks() {
+///     println!("{:?}", task);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct RuntimeDump {
+    tasks: Vec<TaskDump>,
+}
+
+impl RuntimeDump {
+    pub(crate) fn new(runtime: &Runtime) -> RuntimeDump {
+        let inner = &runtime.handle.inner;
+        let mut tasks = Vec::new();
+        match &inner.async_spawner {
+            #[cfg(feature = "current_thread_runtime")]
+            AsyncHandle::CurrentThread(spawner) => spawner.scheduler.registry.dump_into(&mut tasks),
+            AsyncHandle::MultiThread(spawner) => {
+                spawner.exe_mng_info.registry.dump_into(&mut tasks)
+            }
+        }
+        // The global runtime uses the global blocking pool
+        let blocking = match &inner.blocking_spawner {
+            Some(spawner) => spawner,
+            None => global_default_blocking(),
+        };
+        blocking.registry().dump_into(&mut tasks);
+        tasks.sort_by_key(TaskDump::id);
+        RuntimeDump { tasks }
+    }
+
+    /// Returns the snapshots of the tasks, sorted by their IDs.
+    pub fn tasks(&self) -> &[TaskDump] {
+        &self.tasks
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::metrics::dump::{TaskStats, TaskStatus};
+    use crate::task::Header;
+
+    /// UT test cases for `TaskStatus::from_state`
+    ///
+    /// # Brief
+    /// 1. Create a task header, check that the new task is scheduled
+    /// 2. Turn the task into running, idle and finished, check the status
+    /// 3. Cancel a task, check the status
+    #[test]
+    fn ut_task_status_from_state() {
+        let header = Header::default();
+        let status = || TaskStatus::from_state(header.state.get_current_state());
+        assert_eq!(status(), TaskStatus::Scheduled);
+        header.state.turning_to_running();
+        assert_eq!(status(), TaskStatus::Running);
+        header.state.turning_to_idle();
+        assert_eq!(status(), TaskStatus::Idle);
+        header.state.turn_to_scheduling();
+        header.state.turning_to_running();
+        header.state.turning_to_finish().unwrap();
+        assert_eq!(status(), TaskStatus::Completed);
+
+        let header = Header::default();
+        header.state.turn_to_canceled_and_scheduled();
+        assert_eq!(
+            TaskStatus::from_state(header.state.get_current_state()),
+            TaskStatus::Canceled
+        );
+    }
+
+    /// UT test cases for `TaskStats`
+    ///
+    /// # Brief
+    /// 1. Create the stats, check that the task has never been polled
+    /// 2. Record two polls, check the poll count and the last-poll time
+    #[test]
+    fn ut_task_stats_record_poll() {
+        let stats = TaskStats::new();
+        assert!(stats.last_poll().is_none());
+        stats.record_poll();
+        let first = stats.last_poll().unwrap();
+        assert!(first >= stats.spawned_at);
+        stats.record_poll();
+        assert!(stats.last_poll().unwrap() >= first);
+        assert_eq!(
+            stats.poll_count.load(std::sync::atomic::Ordering::Relaxed),
+            2
+        );
+    }
+
+    /// UT test cases for `TaskStats::record_schedule`
+    ///
+    /// # Brief
+    /// 1. Create the stats, check that the task is scheduled at the spawn time
+    /// 2. Record a schedule, check that the schedule time moves forward
+    #[test]
+    fn ut_task_stats_record_schedule() {
+        let stats = TaskStats::new();
+        assert_eq!(stats.scheduled_at(), stats.spawned_at);
+        std::thread::sleep(std::time::Duration::from_millis(1));
+        stats.record_schedule();
+        assert!(stats.scheduled_at() > stats.spawned_at);
+    }
+}
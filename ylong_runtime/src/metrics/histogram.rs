@@ -0,0 +1,256 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Range;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering::Relaxed;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::task::Task;
+
+/// Snapshot of a histogram of durations.
+///
+/// The durations are counted into buckets. Each bucket covers the durations
+/// from its lower bound (inclusive) to its upper bound (exclusive), and the
+/// last bucket covers all the durations beyond the largest bound set by the
+/// builder.
+#[derive(Clone, Debug)]
+pub struct Histogram {
+    bounds: Arc<[Duration]>,
+    counts: Vec<u64>,
+}
+
+impl Histogram {
+    /// Returns the number of buckets.
+    pub fn num_buckets(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Returns the range of durations covered by the bucket. The upper bound
+    /// of the last bucket is `Duration::MAX`.
+    ///
+    /// # Panics
+    /// This method panics if the index is out of range.
+    pub fn bucket_range(&self, index: usize) -> Range<Duration> {
+        assert!(index < self.counts.len(), "out of index");
+        let start = match index {
+            0 => Duration::ZERO,
+            i => self.bounds[i - 1],
+        };
+        let end = self.bounds.get(index).copied().unwrap_or(Duration::MAX);
+        start..end
+    }
+
+    /// Returns the number of durations counted into the bucket.
+    ///
+    /// # Panics
+    /// This method panics if the index is out of range.
+    pub fn bucket_count(&self, index: usize) -> u64 {
+        self.counts[index]
+    }
+
+    /// Returns the number of durations counted into all the buckets.
+    pub fn total_count(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+}
+
+/// A histogram that could be updated concurrently.
+struct AtomicHistogram {
+    bounds: Arc<[Duration]>,
+    counts: Box<[AtomicU64]>,
+}
+
+impl AtomicHistogram {
+    fn new(bounds: Arc<[Duration]>) -> Self {
+        let counts = (0..=bounds.len()).map(|_| AtomicU64::new(0)).collect();
+        AtomicHistogram { bounds, counts }
+    }
+
+    fn record(&self, duration: Duration) {
+        // bounds are sorted, so the first bucket whose bound is larger is the one
+        let index = self.bounds.partition_point(|bound| *bound <= duration);
+        self.counts[index].fetch_add(1, Relaxed);
+    }
+
+    fn snapshot(&self) -> Histogram {
+        Histogram {
+            bounds: self.bounds.clone(),
+            counts: self
+                .counts
+                .iter()
+                .map(|count| count.load(Relaxed))
+                .collect(),
+        }
+    }
+}
+
+/// Poll statistics settings of the workers, all the statistics are disabled
+/// by default.
+#[derive(Clone, Default)]
+pub(crate) struct PollStatsConfig {
+    /// Upper bounds of the histogram buckets, sorted and deduplicated
+    pub(crate) histogram_bounds: Option<Arc<[Duration]>>,
+    /// Polls taking longer than this get counted as long polls
+    pub(crate) long_poll_threshold: Option<Duration>,
+}
+
+impl PollStatsConfig {
+    pub(crate) fn set_histogram_bounds(&mut self, mut bounds: Vec<Duration>) {
+        bounds.sort_unstable();
+        bounds.dedup();
+        self.histogram_bounds = Some(bounds.into());
+    }
+}
+
+/// Poll statistics of a worker. The worker updates them, and `Metrics` reads
+/// them from other threads.
+pub(crate) struct PollStats {
+    poll_time: Option<AtomicHistogram>,
+    schedule_latency: Option<AtomicHistogram>,
+    long_poll_threshold: Option<Duration>,
+    long_polls: AtomicU64,
+}
+
+impl PollStats {
+    pub(crate) fn new(config: &PollStatsConfig) -> Self {
+        PollStats {
+            poll_time: config.histogram_bounds.clone().map(AtomicHistogram::new),
+            schedule_latency: config.histogram_bounds.clone().map(AtomicHistogram::new),
+            long_poll_threshold: config.long_poll_threshold,
+            long_polls: AtomicU64::new(0),
+        }
+    }
+
+    /// Gets called right before the worker polls the task. Returns the start
+    /// time of the poll if the poll needs to be timed.
+    #[inline]
+    pub(crate) fn start_poll(&self, task: &Task) -> Option<Instant> {
+        if self.poll_time.is_none() && self.long_poll_threshold.is_none() {
+            return None;
+        }
+        let now = Instant::now();
+        if let Some(histogram) = &self.schedule_latency {
+            let scheduled_at = task.0.header().stats.scheduled_at();
+            histogram.record(now.saturating_duration_since(scheduled_at));
+        }
+        Some(now)
+    }
+
+    /// Gets called right after the worker polls the task.
+    #[inline]
+    pub(crate) fn end_poll(&self, start: Option<Instant>) {
+        let elapsed = match start {
+            Some(start) => start.elapsed(),
+            None => return,
+        };
+        if let Some(histogram) = &self.poll_time {
+            histogram.record(elapsed);
+        }
+        if let Some(threshold) = self.long_poll_threshold {
+            if elapsed > threshold {
+                self.long_polls.fetch_add(1, Relaxed);
+            }
+        }
+    }
+
+    pub(crate) fn poll_time_histogram(&self) -> Option<Histogram> {
+        self.poll_time.as_ref().map(AtomicHistogram::snapshot)
+    }
+
+    pub(crate) fn schedule_latency_histogram(&self) -> Option<Histogram> {
+        self.schedule_latency
+            .as_ref()
+            .map(AtomicHistogram::snapshot)
+    }
+
+    pub(crate) fn long_poll_count(&self) -> Option<u64> {
+        self.long_poll_threshold
+            .map(|_| self.long_polls.load(Relaxed))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use crate::metrics::histogram::{AtomicHistogram, PollStats, PollStatsConfig};
+
+    /// UT test cases for `AtomicHistogram`
+    ///
+    /// # Brief
+    /// 1. Create a histogram with bounds of 1ms and 10ms
+    /// 2. Record durations below, between, on and beyond the bounds
+    /// 3. Check the ranges and the counts of the buckets
+    #[test]
+    fn ut_atomic_histogram_record() {
+        let bounds = vec![Duration::from_millis(1), Duration::from_millis(10)];
+        let histogram = AtomicHistogram::new(bounds.into());
+        histogram.record(Duration::ZERO);
+        histogram.record(Duration::from_millis(1));
+        histogram.record(Duration::from_millis(5));
+        histogram.record(Duration::from_millis(10));
+        histogram.record(Duration::from_secs(1));
+
+        let snapshot = histogram.snapshot();
+        assert_eq!(snapshot.num_buckets(), 3);
+        assert_eq!(
+            snapshot.bucket_range(0),
+            Duration::ZERO..Duration::from_millis(1)
+        );
+        assert_eq!(
+            snapshot.bucket_range(2),
+            Duration::from_millis(10)..Duration::MAX
+        );
+        assert_eq!(snapshot.bucket_count(0), 1);
+        assert_eq!(snapshot.bucket_count(1), 2);
+        assert_eq!(snapshot.bucket_count(2), 2);
+        assert_eq!(snapshot.total_count(), 5);
+    }
+
+    /// UT test cases for `PollStats::end_poll`
+    ///
+    /// # Brief
+    /// 1. Create poll stats with the histograms disabled, check that polls are
+    ///    not timed
+    /// 2. Create poll stats with unsorted bounds and a long-poll threshold
+    /// 3. Record a short poll and a long poll, check the histogram and the
+    ///    long-poll count
+    #[test]
+    fn ut_poll_stats_end_poll() {
+        let stats = PollStats::new(&PollStatsConfig::default());
+        assert!(stats.poll_time_histogram().is_none());
+        assert!(stats.schedule_latency_histogram().is_none());
+        assert!(stats.long_poll_count().is_none());
+
+        let mut config = PollStatsConfig {
+            long_poll_threshold: Some(Duration::from_millis(10)),
+            ..Default::default()
+        };
+        config.set_histogram_bounds(vec![
+            Duration::from_millis(10),
+            Duration::from_millis(1),
+            Duration::from_millis(10),
+        ]);
+        let stats = PollStats::new(&config);
+        stats.end_poll(Some(std::time::Instant::now()));
+        stats.end_poll(Some(std::time::Instant::now() - Duration::from_millis(20)));
+
+        let histogram = stats.poll_time_histogram().unwrap();
+        assert_eq!(histogram.num_buckets(), 3);
+        assert_eq!(histogram.bucket_count(2), 1);
+        assert_eq!(histogram.total_count(), 2);
+        assert_eq!(stats.long_poll_count(), Some(1));
+    }
+}
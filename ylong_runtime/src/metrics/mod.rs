@@ -11,5 +11,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod dump;
+mod histogram;
 mod runtime;
+pub(crate) use dump::{TaskRegistry, TaskStats};
+pub use dump::{RuntimeDump, TaskDump, TaskStatus};
+pub(crate) use histogram::{PollStats, PollStatsConfig};
+pub use histogram::Histogram;
 pub use runtime::Metrics;
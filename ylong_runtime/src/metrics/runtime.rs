@@ -12,7 +12,9 @@
 // limitations under the License.
 
 use crate::executor::async_pool::AsyncPoolSpawner;
-use crate::executor::{AsyncHandle, Runtime};
+use crate::executor::blocking_pool::BlockPoolSpawner;
+use crate::executor::{global_default_blocking, AsyncHandle, Runtime, RuntimeInner};
+use crate::metrics::Histogram;
 
 /// User can get some message from Runtime during running.
 ///
@@ -24,7 +26,7 @@ use crate::executor::{AsyncHandle, Runtime};
 /// let metrics = runtime.metrics();
 /// ```
 pub struct Metrics<'a> {
-    runtime: &'a Runtime,
+    runtime: &'a RuntimeInner,
 }
 
 /// List of workers state.
@@ -40,11 +42,23 @@ impl Metrics<'_> {
     const ACTIVE_STATE: usize = 3;
 
     pub(crate) fn new(runtime: &Runtime) -> Metrics {
-        Metrics { runtime }
+        Metrics {
+            runtime: &runtime.handle.inner,
+        }
+    }
+
+    // The global runtime uses the global blocking pool
+    fn blocking_spawner(&self) -> &BlockPoolSpawner {
+        match &self.runtime.blocking_spawner {
+            Some(spawner) => spawner,
+            None => global_default_blocking(),
+        }
     }
 
     /// Returns workers num
     ///
+    /// Workers retired by shrinking the runtime are not counted.
+    ///
     /// # Example
     /// ```
     /// let runtime = ylong_runtime::builder::RuntimeBuilder::new_multi_thread()
@@ -57,7 +71,7 @@ impl Metrics<'_> {
         match &self.runtime.async_spawner {
             #[cfg(feature = "current_thread_runtime")]
             AsyncHandle::CurrentThread(_) => 1,
-            AsyncHandle::MultiThread(spawner) => spawner.exe_mng_info.num_workers,
+            AsyncHandle::MultiThread(spawner) => spawner.exe_mng_info.num_workers(),
         }
     }
 
@@ -218,6 +232,42 @@ impl Metrics<'_> {
         }
     }
 
+    /// Returns the number of blocking tasks waiting inside the blocking queue
+    ///
+    /// Tasks that are already running on a blocking thread are not counted.
+    ///
+    /// # Example
+    /// ```
+    /// let runtime = ylong_runtime::builder::RuntimeBuilder::new_multi_thread()
+    ///     .build()
+    ///     .unwrap();
+    /// let metrics = runtime.metrics();
+    /// println!(
+    ///     "Runtime's blocking_queue_length:{}",
+    ///     metrics.blocking_queue_length()
+    /// );
+    /// ```
+    pub fn blocking_queue_length(&self) -> usize {
+        self.blocking_spawner().queue_len()
+    }
+
+    /// Returns the number of blocking threads which are running a task
+    ///
+    /// # Example
+    /// ```
+    /// let runtime = ylong_runtime::builder::RuntimeBuilder::new_multi_thread()
+    ///     .build()
+    ///     .unwrap();
+    /// let metrics = runtime.metrics();
+    /// println!(
+    ///     "Runtime's active_blocking_threads_num:{}",
+    ///     metrics.active_blocking_threads_num()
+    /// );
+    /// ```
+    pub fn active_blocking_threads_num(&self) -> usize {
+        self.blocking_spawner().active_thread_num()
+    }
+
     /// Returns the total number of task which has entered global queue
     ///
     /// This value will only increment, not decrease.
@@ -415,6 +465,101 @@ impl Metrics<'_> {
         }
     }
 
+    /// Returns the histogram of the poll time of the tasks polled by the given
+    /// worker thread.
+    ///
+    /// Runtime build by `new_current_thread()` or without calling
+    /// `metrics_poll_histogram()` on the builder will return None.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let runtime = ylong_runtime::builder::RuntimeBuilder::new_multi_thread()
+    ///     .metrics_poll_histogram(vec![Duration::from_millis(1)])
+    ///     .build()
+    ///     .unwrap();
+    /// let metrics = runtime.metrics();
+    /// println!(
+    ///     "Runtime's worker_poll_time_histogram:{:?}",
+    ///     metrics.worker_poll_time_histogram(0)
+    /// );
+    /// ```
+    pub fn worker_poll_time_histogram(&self, index: usize) -> Option<Histogram> {
+        match &self.runtime.async_spawner {
+            #[cfg(feature = "current_thread_runtime")]
+            AsyncHandle::CurrentThread(_) => None,
+            AsyncHandle::MultiThread(spawner) => match spawner.get_worker(index) {
+                Ok(worker) => worker.poll_stats.poll_time_histogram(),
+                Err(_) => panic!("out of index"),
+            },
+        }
+    }
+
+    /// Returns the histogram of the time between a task getting scheduled and
+    /// it getting polled by the given worker thread. A newly spawned task is
+    /// considered scheduled at its spawn time.
+    ///
+    /// Runtime build by `new_current_thread()` or without calling
+    /// `metrics_poll_histogram()` on the builder will return None.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let runtime = ylong_runtime::builder::RuntimeBuilder::new_multi_thread()
+    ///     .metrics_poll_histogram(vec![Duration::from_millis(1)])
+    ///     .build()
+    ///     .unwrap();
+    /// let metrics = runtime.metrics();
+    /// println!(
+    ///     "Runtime's worker_schedule_latency_histogram:{:?}",
+    ///     metrics.worker_schedule_latency_histogram(0)
+    /// );
+    /// ```
+    pub fn worker_schedule_latency_histogram(&self, index: usize) -> Option<Histogram> {
+        match &self.runtime.async_spawner {
+            #[cfg(feature = "current_thread_runtime")]
+            AsyncHandle::CurrentThread(_) => None,
+            AsyncHandle::MultiThread(spawner) => match spawner.get_worker(index) {
+                Ok(worker) => worker.poll_stats.schedule_latency_histogram(),
+                Err(_) => panic!("out of index"),
+            },
+        }
+    }
+
+    /// Returns the number of polls on the given worker thread that take longer
+    /// than the threshold set by `metrics_long_poll_threshold()`.
+    ///
+    /// This value will only increment, not decrease.
+    /// Runtime build by `new_current_thread()` or without calling
+    /// `metrics_long_poll_threshold()` on the builder will return None.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let runtime = ylong_runtime::builder::RuntimeBuilder::new_multi_thread()
+    ///     .metrics_long_poll_threshold(Duration::from_millis(10))
+    ///     .build()
+    ///     .unwrap();
+    /// let metrics = runtime.metrics();
+    /// println!(
+    ///     "Runtime's worker_long_poll_count:{:?}",
+    ///     metrics.worker_long_poll_count(0)
+    /// );
+    /// ```
+    pub fn worker_long_poll_count(&self, index: usize) -> Option<u64> {
+        match &self.runtime.async_spawner {
+            #[cfg(feature = "current_thread_runtime")]
+            AsyncHandle::CurrentThread(_) => None,
+            AsyncHandle::MultiThread(spawner) => match spawner.get_worker(index) {
+                Ok(worker) => worker.poll_stats.long_poll_count(),
+                Err(_) => panic!("out of index"),
+            },
+        }
+    }
+
     /// Returns the number of IO events which has been registered in Driver.
     ///
     /// This value will only increment, not decrease.
@@ -23,7 +23,7 @@ use std::task::{Context, Poll};
 use libc::{gid_t, uid_t};
 use ylong_io::{Interest, Source};
 
-use crate::executor::Handle;
+use crate::executor::driver_handle::Handle;
 use crate::io::{poll_ready, ReadBuf};
 use crate::net::{ReadyEvent, ScheduleIO};
 use crate::util::slab::Ref;
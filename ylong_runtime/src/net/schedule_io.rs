@@ -26,6 +26,7 @@ use ylong_io::Interest;
 
 use crate::futures::poll_fn;
 use crate::net::{Ready, ReadyEvent};
+use crate::task::coop;
 use crate::util::bit::{Bit, Mask};
 use crate::util::linked_list::{Link, LinkedList, Node};
 use crate::util::slab::Entry;
@@ -131,11 +132,16 @@ impl ScheduleIO {
         cx: &mut Context<'_>,
         interest: Interest,
     ) -> Poll<ReadyEvent> {
+        let coop = match coop::poll_budget(cx) {
+            Poll::Ready(coop) => coop,
+            Poll::Pending => return Poll::Pending,
+        };
+
         // Get current status and check if it contains our interest
         let curr_bit = Bit::from_usize(self.status.load(Acquire));
         let ready = Ready::from_usize(curr_bit.get_by_mask(READINESS)).intersection(interest);
 
-        if ready.is_empty() {
+        let res = if ready.is_empty() {
             let mut waiters = self.waiters.lock().unwrap();
             // Put the waker associated with the context into the waiters
             match interest {
@@ -154,7 +160,11 @@ impl ScheduleIO {
         } else {
             let tick = curr_bit.get_by_mask(DRIVER_TICK) as u8;
             Poll::Ready(ReadyEvent::new(tick, ready))
+        };
+        if res.is_ready() {
+            coop.made_progress();
         }
+        res
     }
 
     #[inline]
@@ -434,8 +444,15 @@ impl Future for Readiness<'_> {
         };
         // Safety: `waiter.interest` never changes after initialization.
         let interest = unsafe { (*waiter.get()).interest };
+        let coop = match coop::poll_budget(cx) {
+            Poll::Ready(coop) => coop,
+            Poll::Pending => return Poll::Pending,
+        };
         loop {
             if let Some(poll_res) = poll_state(schedule_io, state, waiter, interest, cx) {
+                if poll_res.is_ready() {
+                    coop.made_progress();
+                }
                 return poll_res;
             }
         }
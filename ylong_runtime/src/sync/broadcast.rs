@@ -0,0 +1,847 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Broadcast channel
+
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering::Release;
+use std::sync::{Arc, Mutex};
+use std::task::Poll::{Pending, Ready};
+use std::task::{Context, Poll, Waker};
+
+use crate::futures::poll_fn;
+use crate::sync::error::SendError;
+use crate::sync::wake_list::{ListItem, WakerList};
+use crate::task::coop;
+cfg_time!(
+    use crate::time::timeout;
+    use std::time::Duration;
+);
+
+/// Creates a new broadcast channel with a `Sender` and `Receiver` handle pair.
+///
+/// Every value sent by the senders is delivered to all the receivers alive at
+/// the moment of sending. The channel keeps the latest `capacity` values, so a
+/// receiver falling behind by more than `capacity` values misses the oldest
+/// ones, which is reported by [`RecvError::Lagged`].
+///
+/// # Panics
+///
+/// Panics if the capacity is zero.
+///
+/// # Examples
+///
+/// ```
+/// use ylong_runtime::sync::broadcast;
+/// async fn io_func() {
+///     let (tx, mut rx) = broadcast::channel(16);
+///     let mut rx2 = tx.subscribe();
+///     ylong_runtime::spawn(async move {
+///         assert_eq!(rx.recv().await, Ok(1));
+///     });
+///     ylong_runtime::spawn(async move {
+///         assert_eq!(rx2.recv().await, Ok(1));
+///     });
+///
+///     assert_eq!(tx.send(1), Ok(2));
+/// }
+/// ```
+pub fn channel<T>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    assert!(
+        capacity > 0,
+        "broadcast channel capacity must be greater than 0"
+    );
+    let channel = Arc::new(Channel::new(capacity));
+    let tx = Sender {
+        channel: channel.clone(),
+    };
+    let rx = Receiver::new(channel, 0);
+    (tx, rx)
+}
+
+/// Error returned by `recv` of a broadcast [`Receiver`].
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum RecvError {
+    /// All senders were dropped and no values are left.
+    Closed,
+    /// The receiver fell behind and missed the given number of the oldest
+    /// values. The next receiving returns the oldest value still inside the
+    /// channel.
+    Lagged(u64),
+}
+
+impl Display for RecvError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecvError::Closed => write!(f, "channel is closed"),
+            RecvError::Lagged(n) => write!(f, "channel lagged by {n}"),
+        }
+    }
+}
+
+impl Error for RecvError {}
+
+/// Error returned by `try_recv` of a broadcast [`Receiver`].
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum TryRecvError {
+    /// No value is left for the receiver now.
+    Empty,
+    /// All senders were dropped and no values are left.
+    Closed,
+    /// The receiver fell behind and missed the given number of the oldest
+    /// values.
+    Lagged(u64),
+}
+
+impl Display for TryRecvError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TryRecvError::Empty => write!(f, "channel is empty"),
+            TryRecvError::Closed => write!(f, "channel is closed"),
+            TryRecvError::Lagged(n) => write!(f, "channel lagged by {n}"),
+        }
+    }
+}
+
+impl Error for TryRecvError {}
+
+cfg_time! {
+    /// Error returned by `recv_timeout` of a broadcast [`Receiver`].
+    #[derive(Debug, Eq, PartialEq, Clone)]
+    pub enum RecvTimeoutError {
+        /// All senders were dropped and no values are left.
+        Closed,
+        /// The receiver fell behind and missed the given number of the oldest
+        /// values.
+        Lagged(u64),
+        /// Receiving timeout.
+        Timeout,
+    }
+
+    impl Display for RecvTimeoutError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            match self {
+                RecvTimeoutError::Closed => write!(f, "channel is closed"),
+                RecvTimeoutError::Lagged(n) => write!(f, "channel lagged by {n}"),
+                RecvTimeoutError::Timeout => write!(f, "channel receiving timeout"),
+            }
+        }
+    }
+
+    impl Error for RecvTimeoutError {}
+}
+
+/// The sender of broadcast channel.
+/// A [`Sender`] and [`Receiver`] handle pair is created by the [`channel`]
+/// function.
+///
+/// # Examples
+///
+/// ```
+/// use ylong_runtime::sync::broadcast;
+/// async fn io_func() {
+///     let (tx, mut rx) = broadcast::channel(16);
+///     let tx2 = tx.clone();
+///     assert_eq!(tx.send(1), Ok(1));
+///     assert_eq!(tx2.send(2), Ok(1));
+///     assert_eq!(rx.recv().await, Ok(1));
+///     assert_eq!(rx.recv().await, Ok(2));
+/// }
+/// ```
+pub struct Sender<T> {
+    channel: Arc<Channel<T>>,
+}
+
+impl<T> Sender<T> {
+    /// Sends a value to all the associated [`Receiver`]s, and returns the
+    /// number of the receivers.
+    ///
+    /// The value is kept inside the channel until all these receivers have
+    /// received it, or it's overwritten by a newer value once the channel is
+    /// full. Sending never waits for the receivers.
+    ///
+    /// An error containing the sent value would be returned if all receivers
+    /// are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::broadcast;
+    /// async fn io_func() {
+    ///     let (tx, rx) = broadcast::channel(16);
+    ///     let _rx2 = tx.subscribe();
+    ///     assert_eq!(tx.send(1), Ok(2));
+    ///     drop(rx);
+    ///     drop(_rx2);
+    ///     assert!(tx.send(2).is_err());
+    /// }
+    /// ```
+    pub fn send(&self, value: T) -> Result<usize, SendError<T>> {
+        let mut state = self.channel.state.lock().unwrap();
+        if state.rx_cnt == 0 {
+            return Err(SendError(value));
+        }
+        let rx_cnt = state.rx_cnt;
+        let index = (state.tail % self.channel.capacity as u64) as usize;
+        state.buffer[index] = Slot {
+            value: Some(value),
+            remaining: rx_cnt,
+        };
+        state.tail += 1;
+        drop(state);
+        self.channel.waker_list.notify_all();
+        Ok(rx_cnt)
+    }
+
+    /// Creates a new [`Receiver`] associated with oneself, which receives the
+    /// values sent after this call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::broadcast;
+    /// async fn io_func() {
+    ///     let (tx, mut rx) = broadcast::channel(16);
+    ///     assert_eq!(tx.send(1), Ok(1));
+    ///     let mut rx2 = tx.subscribe();
+    ///     assert_eq!(tx.send(2), Ok(2));
+    ///     assert_eq!(rx.recv().await, Ok(1));
+    ///     assert_eq!(rx2.recv().await, Ok(2));
+    /// }
+    /// ```
+    pub fn subscribe(&self) -> Receiver<T> {
+        let mut state = self.channel.state.lock().unwrap();
+        state.rx_cnt += 1;
+        Receiver::new(self.channel.clone(), state.tail)
+    }
+
+    /// Gets the number of receivers associated with oneself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::broadcast;
+    /// async fn io_func() {
+    ///     let (tx, rx) = broadcast::channel::<i32>(16);
+    ///     assert_eq!(tx.receiver_count(), 1);
+    ///     let rx2 = tx.subscribe();
+    ///     assert_eq!(tx.receiver_count(), 2);
+    /// }
+    /// ```
+    pub fn receiver_count(&self) -> usize {
+        self.channel.state.lock().unwrap().rx_cnt
+    }
+
+    /// Checks whether the sender and another sender belong to the same
+    /// channel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::broadcast;
+    /// async fn io_func() {
+    ///     let (tx, rx) = broadcast::channel::<i32>(16);
+    ///     let tx2 = tx.clone();
+    ///     assert!(tx.is_same(&tx2));
+    /// }
+    /// ```
+    pub fn is_same(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.channel, &other.channel)
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.channel.state.lock().unwrap().tx_cnt += 1;
+        Sender {
+            channel: self.channel.clone(),
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut state = self.channel.state.lock().unwrap();
+        state.tx_cnt -= 1;
+        if state.tx_cnt == 0 {
+            drop(state);
+            self.channel.waker_list.notify_all();
+        }
+    }
+}
+
+impl<T> Debug for Sender<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Sender")
+            .field("channel", &self.channel)
+            .finish()
+    }
+}
+
+/// The receiver of broadcast channel.
+/// A [`Sender`] and [`Receiver`] handle pair is created by the [`channel`]
+/// function.
+///
+/// # Examples
+///
+/// ```
+/// use ylong_runtime::sync::broadcast;
+/// async fn io_func() {
+///     let (tx, mut rx) = broadcast::channel(16);
+///     ylong_runtime::spawn(async move {
+///         assert_eq!(rx.recv().await, Ok(1));
+///     });
+///
+///     let _ = tx.send(1);
+/// }
+/// ```
+pub struct Receiver<T> {
+    channel: Arc<Channel<T>>,
+    /// Position of the next value to receive
+    next: u64,
+    /// Index of the waker registered by the last pending poll
+    waker_index: Option<usize>,
+    /// Shared with the registered waker, which gets set to 0 once woken up
+    wait_permit: Arc<AtomicUsize>,
+}
+
+impl<T: Clone> Receiver<T> {
+    /// Attempts to receive a value from the associated [`Sender`]s without
+    /// waiting.
+    ///
+    /// # Return value
+    /// * `Ok(T)` if receiving a value successfully.
+    /// * `Err(TryRecvError::Empty)` if no value is left for the receiver now.
+    /// * `Err(TryRecvError::Closed)` if all senders have been dropped and no
+    ///   value is left.
+    /// * `Err(TryRecvError::Lagged(n))` if the receiver has missed `n` values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::broadcast;
+    /// use ylong_runtime::sync::broadcast::TryRecvError;
+    /// async fn io_func() {
+    ///     let (tx, mut rx) = broadcast::channel(2);
+    ///     assert_eq!(rx.try_recv(), Err(TryRecvError::Empty));
+    ///     for i in 0..3 {
+    ///         let _ = tx.send(i);
+    ///     }
+    ///     assert_eq!(rx.try_recv(), Err(TryRecvError::Lagged(1)));
+    ///     assert_eq!(rx.try_recv(), Ok(1));
+    ///     assert_eq!(rx.try_recv(), Ok(2));
+    ///     drop(tx);
+    ///     assert_eq!(rx.try_recv(), Err(TryRecvError::Closed));
+    /// }
+    /// ```
+    pub fn try_recv(&mut self) -> Result<T, TryRecvError> {
+        let capacity = self.channel.capacity as u64;
+        let mut state = self.channel.state.lock().unwrap();
+        let oldest = state.tail.saturating_sub(capacity);
+        if self.next < oldest {
+            let missed = oldest - self.next;
+            self.next = oldest;
+            return Err(TryRecvError::Lagged(missed));
+        }
+        if self.next == state.tail {
+            return if state.tx_cnt == 0 {
+                Err(TryRecvError::Closed)
+            } else {
+                Err(TryRecvError::Empty)
+            };
+        }
+
+        let slot = &mut state.buffer[(self.next % capacity) as usize];
+        self.next += 1;
+        slot.remaining -= 1;
+        // the last receiver takes the value out instead of cloning it
+        if slot.remaining == 0 {
+            Ok(slot.value.take().expect("broadcast value missing"))
+        } else {
+            Ok(slot.value.clone().expect("broadcast value missing"))
+        }
+    }
+
+    /// Polls to receive a value from the associated [`Sender`]s.
+    ///
+    /// When no value is left for the receiver, calling this method will return
+    /// pending, and the waker from the Context will receive a wakeup when a
+    /// value arrives or when the channel is closed. Multiple calls to this
+    /// method, only the waker from the last call will receive a wakeup.
+    ///
+    /// # Return value
+    /// * `Poll::Pending` if no value is left, but the channel is not closed.
+    /// * `Poll::Ready(Ok(T))` if receiving a value successfully.
+    /// * `Poll::Ready(Err(RecvError::Closed))` if all senders have been dropped
+    ///   and no value is left.
+    /// * `Poll::Ready(Err(RecvError::Lagged(n)))` if the receiver has missed
+    ///   `n` values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::futures::poll_fn;
+    /// use ylong_runtime::sync::broadcast;
+    /// async fn io_func() {
+    ///     let (tx, mut rx) = broadcast::channel(16);
+    ///     let handle = ylong_runtime::spawn(async move {
+    ///         let msg = poll_fn(|cx| rx.poll_recv(cx)).await;
+    ///         assert_eq!(msg, Ok(1));
+    ///     });
+    ///     assert!(tx.send(1).is_ok());
+    ///     let _ = ylong_runtime::block_on(handle);
+    /// }
+    /// ```
+    pub fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Result<T, RecvError>> {
+        let coop = match coop::poll_budget(cx) {
+            Ready(coop) => coop,
+            Pending => return Pending,
+        };
+        let res = match self.try_recv() {
+            Err(TryRecvError::Empty) => {
+                self.register_waker(cx.waker());
+                self.try_recv()
+            }
+            res => res,
+        };
+
+        match res {
+            Err(TryRecvError::Empty) => Pending,
+            res => {
+                coop.made_progress();
+                Ready(res.map_err(RecvError::from))
+            }
+        }
+    }
+
+    /// Receives a value from the associated [`Sender`]s.
+    ///
+    /// The receiver can still receive all the values left in the channel after
+    /// all senders have been dropped.
+    ///
+    /// # Return value
+    /// * `Ok(T)` if receiving a value successfully.
+    /// * `Err(RecvError::Closed)` if all senders have been dropped and no value
+    ///   is left.
+    /// * `Err(RecvError::Lagged(n))` if the receiver has missed `n` values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::broadcast;
+    /// async fn io_func() {
+    ///     let (tx, mut rx) = broadcast::channel(16);
+    ///     let handle = ylong_runtime::spawn(async move {
+    ///         assert_eq!(rx.recv().await, Ok(1));
+    ///     });
+    ///     assert!(tx.send(1).is_ok());
+    ///     let _ = ylong_runtime::block_on(handle);
+    /// }
+    /// ```
+    pub async fn recv(&mut self) -> Result<T, RecvError> {
+        poll_fn(|cx| self.poll_recv(cx)).await
+    }
+
+    /// Attempts to receive a value from the associated [`Sender`]s in a
+    /// limited amount of time.
+    ///
+    /// # Return value
+    /// * `Ok(T)` if receiving a value successfully.
+    /// * `Err(RecvTimeoutError::Closed)` if all senders have been dropped and
+    ///   no value is left.
+    /// * `Err(RecvTimeoutError::Lagged(n))` if the receiver has missed `n`
+    ///   values.
+    /// * `Err(RecvTimeoutError::Timeout)` if time limit has been passed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use ylong_runtime::sync::broadcast;
+    /// async fn io_func() {
+    ///     let (tx, mut rx) = broadcast::channel(16);
+    ///     let handle = ylong_runtime::spawn(async move {
+    ///         tx.send(1).unwrap();
+    ///         assert_eq!(rx.recv_timeout(Duration::from_millis(10)).await, Ok(1));
+    ///     });
+    ///     let _ = ylong_runtime::block_on(handle);
+    /// }
+    /// ```
+    #[cfg(feature = "time")]
+    pub async fn recv_timeout(&mut self, time: Duration) -> Result<T, RecvTimeoutError> {
+        match timeout(time, self.recv()).await {
+            Ok(Ok(value)) => Ok(value),
+            Ok(Err(RecvError::Closed)) => Err(RecvTimeoutError::Closed),
+            Ok(Err(RecvError::Lagged(n))) => Err(RecvTimeoutError::Lagged(n)),
+            Err(_) => Err(RecvTimeoutError::Timeout),
+        }
+    }
+}
+
+impl<T> Receiver<T> {
+    fn new(channel: Arc<Channel<T>>, next: u64) -> Self {
+        Receiver {
+            channel,
+            next,
+            waker_index: None,
+            wait_permit: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    // Registers the waker into the list. The waker registered by the last
+    // pending poll gets updated in place if it has not been woken up, so that
+    // polling repeatedly does not grow the list.
+    fn register_waker(&mut self, waker: &Waker) {
+        let mut list = self.channel.waker_list.lock();
+        if let Some(index) = self.waker_index {
+            if list.update_waker(index, &self.wait_permit, waker) {
+                return;
+            }
+        }
+        self.wait_permit.store(1, Release);
+        self.waker_index = Some(list.insert(ListItem {
+            wake: waker.clone(),
+            wait_permit: self.wait_permit.clone(),
+        }));
+    }
+
+    /// Gets the number of values left for the receiver, including the ones it
+    /// has missed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::broadcast;
+    /// async fn io_func() {
+    ///     let (tx, mut rx) = broadcast::channel(16);
+    ///     assert_eq!(rx.len(), 0);
+    ///     let _ = tx.send(1);
+    ///     assert_eq!(rx.len(), 1);
+    /// }
+    /// ```
+    pub fn len(&self) -> usize {
+        let state = self.channel.state.lock().unwrap();
+        (state.tail - self.next) as usize
+    }
+
+    /// Checks whether no value is left for the receiver.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::broadcast;
+    /// async fn io_func() {
+    ///     let (tx, mut rx) = broadcast::channel(16);
+    ///     assert!(rx.is_empty());
+    ///     let _ = tx.send(1);
+    ///     assert!(!rx.is_empty());
+    /// }
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Clone for Receiver<T> {
+    fn clone(&self) -> Self {
+        let mut state = self.channel.state.lock().unwrap();
+        state.rx_cnt += 1;
+        // the new receiver also waits for the values left for this receiver
+        for pos in self.oldest_unread(&state)..state.tail {
+            state.buffer[(pos % self.channel.capacity as u64) as usize].remaining += 1;
+        }
+        Receiver::new(self.channel.clone(), self.next)
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        if let Some(index) = self.waker_index {
+            let mut list = self.channel.waker_list.lock();
+            list.remove_waiting(index, &self.wait_permit);
+        }
+        let mut state = self.channel.state.lock().unwrap();
+        state.rx_cnt -= 1;
+        // releases the values that no other receiver waits for
+        for pos in self.oldest_unread(&state)..state.tail {
+            let slot = &mut state.buffer[(pos % self.channel.capacity as u64) as usize];
+            slot.remaining -= 1;
+            if slot.remaining == 0 {
+                slot.value = None;
+            }
+        }
+    }
+}
+
+impl<T> Receiver<T> {
+    // Gets the position of the oldest value that the receiver has not received
+    // and is still inside the channel.
+    fn oldest_unread(&self, state: &State<T>) -> u64 {
+        let oldest = state.tail.saturating_sub(self.channel.capacity as u64);
+        self.next.max(oldest)
+    }
+}
+
+impl<T> Debug for Receiver<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Receiver")
+            .field("channel", &self.channel)
+            .field("next", &self.next)
+            .finish()
+    }
+}
+
+impl From<TryRecvError> for RecvError {
+    fn from(e: TryRecvError) -> Self {
+        match e {
+            TryRecvError::Lagged(n) => RecvError::Lagged(n),
+            _ => RecvError::Closed,
+        }
+    }
+}
+
+struct Slot<T> {
+    value: Option<T>,
+    /// Number of receivers that have not received the value
+    remaining: usize,
+}
+
+struct State<T> {
+    buffer: Box<[Slot<T>]>,
+    /// Position of the next value to send
+    tail: u64,
+    tx_cnt: usize,
+    rx_cnt: usize,
+}
+
+struct Channel<T> {
+    capacity: usize,
+    state: Mutex<State<T>>,
+    waker_list: WakerList,
+}
+
+impl<T> Channel<T> {
+    fn new(capacity: usize) -> Channel<T> {
+        let buffer = (0..capacity)
+            .map(|_| Slot {
+                value: None,
+                remaining: 0,
+            })
+            .collect();
+        Channel {
+            capacity,
+            state: Mutex::new(State {
+                buffer,
+                tail: 0,
+                tx_cnt: 1,
+                rx_cnt: 1,
+            }),
+            waker_list: WakerList::new(),
+        }
+    }
+}
+
+impl<T> Debug for Channel<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let state = self.state.lock().unwrap();
+        f.debug_struct("Channel")
+            .field("capacity", &self.capacity)
+            .field("tail", &state.tail)
+            .field("sender_count", &state.tx_cnt)
+            .field("receiver_count", &state.rx_cnt)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::task::Poll::Ready;
+
+    use crate::futures::poll_fn;
+    use crate::sync::broadcast;
+    use crate::sync::broadcast::{RecvError, TryRecvError};
+    use crate::{block_on, spawn};
+
+    /// UT test cases for `send()` and `try_recv()`.
+    ///
+    /// # Brief
+    /// 1. Call channel to create a sender and a receiver handle pair.
+    /// 2. Subscribe another receiver.
+    /// 3. Send values and check that both receivers get all of them in order.
+    /// 4. Drop the sender and check that the receivers get closed.
+    #[test]
+    fn send_try_recv() {
+        let (tx, mut rx) = broadcast::channel(4);
+        let mut rx2 = tx.subscribe();
+        assert_eq!(rx.try_recv(), Err(TryRecvError::Empty));
+        assert_eq!(tx.send(1), Ok(2));
+        assert_eq!(tx.send(2), Ok(2));
+        assert_eq!(rx.try_recv(), Ok(1));
+        assert_eq!(rx.try_recv(), Ok(2));
+        assert_eq!(rx2.try_recv(), Ok(1));
+        assert_eq!(rx2.try_recv(), Ok(2));
+        assert_eq!(rx2.try_recv(), Err(TryRecvError::Empty));
+
+        drop(tx);
+        assert_eq!(rx.try_recv(), Err(TryRecvError::Closed));
+        assert_eq!(rx2.try_recv(), Err(TryRecvError::Closed));
+    }
+
+    /// UT test cases for lagging receivers.
+    ///
+    /// # Brief
+    /// 1. Call channel to create a sender and a receiver handle pair with
+    ///    capacity 2.
+    /// 2. Send 5 values.
+    /// 3. Check that the receiver lags by 3 values, and then gets the last 2
+    ///    values.
+    #[test]
+    fn recv_lagged() {
+        let (tx, mut rx) = broadcast::channel(2);
+        for i in 0..5 {
+            assert_eq!(tx.send(i), Ok(1));
+        }
+        assert_eq!(rx.len(), 5);
+        assert_eq!(rx.try_recv(), Err(TryRecvError::Lagged(3)));
+        assert_eq!(rx.try_recv(), Ok(3));
+        assert_eq!(rx.try_recv(), Ok(4));
+        assert!(rx.is_empty());
+    }
+
+    /// UT test cases for the values held by the channel.
+    ///
+    /// # Brief
+    /// 1. Call channel to create a sender and a receiver handle pair.
+    /// 2. Send a value, clone the receiver and drop the original one.
+    /// 3. Check that the value is kept for the cloned receiver.
+    /// 4. Check that the value is released after the cloned receiver gets it.
+    #[test]
+    fn value_release() {
+        let (tx, rx) = broadcast::channel(2);
+        assert_eq!(tx.send(String::from("hello")), Ok(1));
+        let mut rx2 = rx.clone();
+        assert_eq!(tx.receiver_count(), 2);
+        drop(rx);
+        assert_eq!(rx2.try_recv(), Ok(String::from("hello")));
+        assert!(tx.channel.state.lock().unwrap().buffer[0].value.is_none());
+
+        drop(rx2);
+        assert!(tx.send(String::from("world")).is_err());
+    }
+
+    /// UT test cases for the waker registered by `poll_recv()`.
+    ///
+    /// # Brief
+    /// 1. Call channel to create a sender and a receiver handle pair.
+    /// 2. Poll the receiver repeatedly while the channel is empty, check that
+    ///    only one waker is registered.
+    /// 3. Send a value, check that the waker leaves the list.
+    /// 4. Receive the value and poll again, check that the waker is registered
+    ///    again.
+    /// 5. Drop the receiver, check that the waker is removed.
+    #[test]
+    fn poll_recv_waker() {
+        let (tx, mut rx) = broadcast::channel(4);
+        block_on(poll_fn(|cx| {
+            for _ in 0..10 {
+                assert!(rx.poll_recv(cx).is_pending());
+            }
+            Ready(())
+        }));
+        assert_eq!(tx.channel.waker_list.len(), 1);
+
+        assert_eq!(tx.send(1), Ok(1));
+        assert_eq!(tx.channel.waker_list.len(), 0);
+
+        block_on(poll_fn(|cx| {
+            assert_eq!(rx.poll_recv(cx), Ready(Ok(1)));
+            assert!(rx.poll_recv(cx).is_pending());
+            Ready(())
+        }));
+        assert_eq!(tx.channel.waker_list.len(), 1);
+
+        drop(rx);
+        assert_eq!(tx.channel.waker_list.len(), 0);
+    }
+
+    /// UT test cases for async `recv()`.
+    ///
+    /// # Brief
+    /// 1. Call channel to create a sender and a receiver handle pair.
+    /// 2. Spawn several receivers waiting for values.
+    /// 3. Send values and drop the sender.
+    /// 4. Check that every receiver gets all the values and then gets closed.
+    #[test]
+    fn send_recv_await() {
+        let (tx, rx) = broadcast::channel(16);
+        let handles = (0..4)
+            .map(|_| {
+                let mut rx = tx.subscribe();
+                spawn(async move {
+                    let mut values = Vec::new();
+                    loop {
+                        match rx.recv().await {
+                            Ok(value) => values.push(value),
+                            Err(RecvError::Closed) => return values,
+                            Err(RecvError::Lagged(n)) => panic!("lagged by {n}"),
+                        }
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        drop(rx);
+
+        for i in 0..10 {
+            assert_eq!(tx.send(i), Ok(4));
+        }
+        drop(tx);
+        for handle in handles {
+            assert_eq!(block_on(handle).unwrap(), (0..10).collect::<Vec<_>>());
+        }
+    }
+
+    /// UT test cases for `recv_timeout()`.
+    ///
+    /// # Brief
+    /// 1. Call channel to create a sender and a receiver handle pair.
+    /// 2. Receive with a timeout before any value is sent, check that it times
+    ///    out.
+    /// 3. Send a value and check that it's received within the timeout.
+    /// 4. Drop the sender and check that the receiver gets closed.
+    #[test]
+    #[cfg(feature = "time")]
+    fn recv_timeout() {
+        use std::time::Duration;
+
+        use crate::sync::broadcast::RecvTimeoutError;
+
+        let handle = spawn(async move {
+            let (tx, mut rx) = broadcast::channel(16);
+            assert_eq!(
+                rx.recv_timeout(Duration::from_millis(10)).await,
+                Err(RecvTimeoutError::Timeout)
+            );
+            assert_eq!(tx.send(1), Ok(1));
+            assert_eq!(rx.recv_timeout(Duration::from_millis(10)).await, Ok(1));
+            drop(tx);
+            assert_eq!(
+                rx.recv_timeout(Duration::from_millis(10)).await,
+                Err(RecvTimeoutError::Closed)
+            );
+        });
+        block_on(handle).unwrap();
+    }
+}
@@ -14,7 +14,9 @@
 //! Synchronization primitives for asynchronous context.
 
 pub(crate) mod atomic_waker;
+pub mod broadcast;
 pub mod error;
+pub mod mpmc;
 pub mod mpsc;
 pub mod mutex;
 pub mod oneshot;
@@ -25,8 +27,10 @@ pub mod waiter;
 mod wake_list;
 pub mod watch;
 
-pub use mutex::{LockError, Mutex, MutexGuard};
-pub use rwlock::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+pub use mutex::{LockError, MappedMutexGuard, Mutex, MutexGuard, OwnedMutexGuard};
+pub use rwlock::{
+    OwnedRwLockReadGuard, OwnedRwLockWriteGuard, RwLock, RwLockReadGuard, RwLockWriteGuard,
+};
 pub use semaphore::{AutoRelSemaphore, AutoRelSemaphorePermit, Semaphore, SemaphorePermit};
 pub use semaphore_inner::SemaphoreError;
 pub use waiter::Waiter;
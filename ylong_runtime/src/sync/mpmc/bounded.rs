@@ -0,0 +1,317 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bounded multi-consumer channel
+
+use std::sync::Arc;
+
+use crate::executor::block_on::blocking_wait;
+use crate::sync::error::{RecvError, TryRecvError};
+use crate::sync::mpmc::Shared;
+use crate::sync::mpsc::bounded::array::Array;
+pub use crate::sync::mpsc::bounded::BoundedSender;
+use crate::sync::mpsc::channel;
+cfg_time!(
+    use crate::executor::block_on::blocking_wait_timeout;
+    use crate::sync::error::RecvTimeoutError;
+    use crate::time::timeout;
+    use std::time::Duration;
+);
+
+/// The receiver of bounded multi-consumer channel.
+/// A [`BoundedSender`] and [`BoundedReceiver`] handle pair are created by the
+/// [`bounded_channel`] function.
+///
+/// The receiver can be cloned, each value sent is received by only one of the
+/// receivers. The channel is closed once all receivers have been dropped.
+///
+/// # Examples
+///
+/// ```
+/// use ylong_runtime::sync::mpmc::bounded::bounded_channel;
+/// async fn io_func() {
+///     let (tx, rx) = bounded_channel(2);
+///     let rx2 = rx.clone();
+///     assert!(tx.send(1).await.is_ok());
+///     assert!(tx.send(2).await.is_ok());
+///     let handle = ylong_runtime::spawn(async move { rx.recv().await.unwrap() });
+///     let handle2 = ylong_runtime::spawn(async move { rx2.recv().await.unwrap() });
+///     let a = handle.await.unwrap();
+///     let b = handle2.await.unwrap();
+///     assert_eq!(a + b, 3);
+/// }
+/// ```
+pub struct BoundedReceiver<T> {
+    channel: Arc<Shared<Array<T>>>,
+}
+
+impl<T> Clone for BoundedReceiver<T> {
+    fn clone(&self) -> Self {
+        BoundedReceiver {
+            channel: self.channel.clone(),
+        }
+    }
+}
+
+/// Creates a new multi-consumer channel, and returns the `Sender` and
+/// `Receiver` handle pair.
+///
+/// The channel is bounded with the passed in capacity.
+///
+/// # Panics
+///
+/// Panics if the new capacity is initialized to zero.
+///
+/// # Examples
+///
+/// ```
+/// use ylong_runtime::sync::mpmc::bounded::bounded_channel;
+/// async fn io_func() {
+///     let (tx, rx) = bounded_channel(1);
+///     let handle = ylong_runtime::spawn(async move {
+///         assert_eq!(rx.recv().await, Ok(1));
+///     });
+///     assert!(tx.send(1).await.is_ok());
+///     let _ = handle.await;
+/// }
+/// ```
+pub fn bounded_channel<T>(number: usize) -> (BoundedSender<T>, BoundedReceiver<T>) {
+    let array = Array::new(number);
+    let (tx, rx) = channel(array);
+    (
+        BoundedSender::new(tx),
+        BoundedReceiver {
+            channel: Arc::new(Shared::new(rx)),
+        },
+    )
+}
+
+impl<T> BoundedReceiver<T> {
+    /// Gets the number of values in the channel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::mpmc::bounded::bounded_channel;
+    /// let (tx, rx) = bounded_channel(2);
+    /// tx.try_send(1).unwrap();
+    /// tx.try_send(2).unwrap();
+    /// assert_eq!(rx.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.channel.len()
+    }
+
+    /// Returns `true` if the channel contains no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::mpmc::bounded::bounded_channel;
+    /// let (tx, rx) = bounded_channel(1);
+    /// assert!(rx.is_empty());
+    /// tx.try_send(1).unwrap();
+    /// assert!(!rx.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Attempts to receive a value from the associated [`BoundedSender`]
+    /// without blocking.
+    ///
+    /// # Return value
+    /// * `Ok(T)` if receiving a value successfully.
+    /// * `Err(TryRecvError::Empty)` if no value has been sent yet.
+    /// * `Err(TryRecvError::Closed)` if all senders have been dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::error::TryRecvError;
+    /// use ylong_runtime::sync::mpmc::bounded::bounded_channel;
+    /// let (tx, rx) = bounded_channel(1);
+    /// let rx2 = rx.clone();
+    /// assert_eq!(rx.try_recv(), Err(TryRecvError::Empty));
+    /// tx.try_send(1).unwrap();
+    /// assert_eq!(rx2.try_recv(), Ok(1));
+    /// drop(tx);
+    /// assert_eq!(rx.try_recv(), Err(TryRecvError::Closed));
+    /// ```
+    pub fn try_recv(&self) -> Result<T, TryRecvError> {
+        self.channel.try_recv()
+    }
+
+    /// Receives a value from the associated [`BoundedSender`].
+    ///
+    /// If several receivers are waiting, they are served in the order they
+    /// started waiting.
+    ///
+    /// The `receiver` can still receive all sent messages in the channel after
+    /// the channel is closed.
+    ///
+    /// # Return value
+    /// * `Ok(T)` if receiving a value successfully.
+    /// * `Err(RecvError)` if all senders have been dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::mpmc::bounded::bounded_channel;
+    /// async fn io_func() {
+    ///     let (tx, rx) = bounded_channel(1);
+    ///     let handle = ylong_runtime::spawn(async move {
+    ///         assert_eq!(rx.recv().await, Ok(1));
+    ///     });
+    ///     tx.try_send(1).unwrap();
+    ///     let _ = handle.await;
+    /// }
+    /// ```
+    pub async fn recv(&self) -> Result<T, RecvError> {
+        self.channel.recv().await
+    }
+
+    /// Attempts to receive a value from the associated [`BoundedSender`] in a
+    /// limited amount of time.
+    ///
+    /// The `receiver` can still receive all sent messages in the channel after
+    /// the channel is closed.
+    ///
+    /// # Return value
+    /// * `Ok(T)` if receiving a value successfully.
+    /// * `Err(RecvTimeoutError::Closed)` if all senders have been dropped.
+    /// * `Err(RecvTimeoutError::TimeOut)` if time limit has been passed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use ylong_runtime::sync::mpmc::bounded::bounded_channel;
+    /// async fn io_func() {
+    ///     let (tx, rx) = bounded_channel(1);
+    ///     let handle = ylong_runtime::spawn(async move {
+    ///         tx.try_send(1).unwrap();
+    ///         assert_eq!(rx.recv_timeout(Duration::from_millis(10)).await, Ok(1));
+    ///     });
+    ///     let _ = ylong_runtime::block_on(handle);
+    /// }
+    /// ```
+    #[cfg(feature = "time")]
+    pub async fn recv_timeout(&self, time: Duration) -> Result<T, RecvTimeoutError> {
+        match timeout(time, self.recv()).await {
+            Ok(res) => res.map_err(|_| RecvTimeoutError::Closed),
+            Err(_) => Err(RecvTimeoutError::Timeout),
+        }
+    }
+
+    /// Receives a value from the associated [`BoundedSender`], blocking the
+    /// current thread until a value arrives.
+    ///
+    /// This method is meant to be used by ordinary threads that communicate
+    /// with asynchronous tasks.
+    ///
+    /// # Return value
+    /// * `Ok(T)` if receiving a value successfully.
+    /// * `Err(RecvError)` if all senders have been dropped or the channel is
+    ///   closed, and no messages remaining.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called in a runtime context, use [`recv`] there instead.
+    ///
+    /// [`recv`]: BoundedReceiver::recv
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::mpmc::bounded::bounded_channel;
+    /// let (tx, rx) = bounded_channel(1);
+    /// let handle = ylong_runtime::spawn(async move {
+    ///     assert!(tx.send(1).await.is_ok());
+    /// });
+    /// let thread = std::thread::spawn(move || {
+    ///     assert_eq!(rx.blocking_recv(), Ok(1));
+    /// });
+    /// thread.join().unwrap();
+    /// let _ = ylong_runtime::block_on(handle);
+    /// ```
+    pub fn blocking_recv(&self) -> Result<T, RecvError> {
+        blocking_wait("BoundedReceiver::blocking_recv", self.channel.recv())
+    }
+
+    /// Receives a value from the associated [`BoundedSender`], blocking the
+    /// current thread for at most the given amount of time.
+    ///
+    /// # Return value
+    /// * `Ok(T)` if receiving a value successfully.
+    /// * `Err(RecvTimeoutError::Closed)` if all senders have been dropped.
+    /// * `Err(RecvTimeoutError::TimeOut)` if time limit has been passed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called in a runtime context, use [`recv_timeout`] there
+    /// instead.
+    ///
+    /// [`recv_timeout`]: BoundedReceiver::recv_timeout
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use ylong_runtime::sync::error::RecvTimeoutError;
+    /// use ylong_runtime::sync::mpmc::bounded::bounded_channel;
+    /// let (tx, rx) = bounded_channel::<i32>(1);
+    /// assert_eq!(
+    ///     rx.blocking_recv_timeout(Duration::from_millis(10)),
+    ///     Err(RecvTimeoutError::Timeout)
+    /// );
+    /// tx.try_send(1).unwrap();
+    /// assert_eq!(rx.blocking_recv_timeout(Duration::from_millis(10)), Ok(1));
+    /// ```
+    #[cfg(feature = "time")]
+    pub fn blocking_recv_timeout(&self, time: Duration) -> Result<T, RecvTimeoutError> {
+        match blocking_wait_timeout(
+            "BoundedReceiver::blocking_recv_timeout",
+            self.channel.recv(),
+            time,
+        ) {
+            Some(res) => res.map_err(|_| RecvTimeoutError::Closed),
+            None => Err(RecvTimeoutError::Timeout),
+        }
+    }
+
+    /// Closes the channel, prevents the `Sender` from sending more values.
+    ///
+    /// The channel is closed for all clones of the receiver. Values sent
+    /// **before** calling `close` can still be received.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::mpmc::bounded::bounded_channel;
+    /// let (tx, rx) = bounded_channel(1);
+    /// let rx2 = rx.clone();
+    /// assert!(tx.try_send("Hello").is_ok());
+    ///
+    /// rx.close();
+    ///
+    /// assert!(tx.is_closed());
+    /// assert!(tx.try_send("no receive").is_err());
+    /// assert_eq!(rx2.try_recv().unwrap(), "Hello");
+    /// ```
+    pub fn close(&self) {
+        self.channel.close();
+    }
+}
@@ -0,0 +1,321 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Multi-producer multi-consumer work-queue channel.
+//!
+//! Every value sent through the channel is delivered to exactly one of the
+//! receivers. Receivers can be cloned and shared between tasks; receivers
+//! waiting for a value are woken in the order they started waiting.
+
+pub mod bounded;
+pub mod unbounded;
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::Poll::{Pending, Ready};
+use std::task::{Context, Poll, Wake, Waker};
+
+pub use bounded::{bounded_channel, BoundedReceiver, BoundedSender};
+pub use unbounded::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+
+use crate::sync::error::{RecvError, TryRecvError};
+use crate::sync::mpsc::bounded::array::Array;
+use crate::sync::mpsc::unbounded::queue::Queue;
+use crate::sync::mpsc::{Container, Rx};
+use crate::task::coop;
+
+/// A mpsc container whose receiving side can be shared by several consumers.
+pub(crate) trait Consumer: Container {
+    type Item;
+
+    fn try_recv(&self) -> Result<Self::Item, TryRecvError>;
+
+    fn register_rx_waker(&self, waker: &Waker);
+}
+
+impl<T> Consumer for Array<T> {
+    type Item = T;
+
+    fn try_recv(&self) -> Result<T, TryRecvError> {
+        Array::try_recv(self)
+    }
+
+    fn register_rx_waker(&self, waker: &Waker) {
+        Array::register_rx_waker(self, waker)
+    }
+}
+
+impl<T> Consumer for Queue<T> {
+    type Item = T;
+
+    fn try_recv(&self) -> Result<T, TryRecvError> {
+        Queue::try_recv(self)
+    }
+
+    fn register_rx_waker(&self, waker: &Waker) {
+        Queue::register_rx_waker(self, waker)
+    }
+}
+
+#[derive(Default)]
+struct WaitQueue {
+    list: VecDeque<(u64, Waker)>,
+    next_id: u64,
+}
+
+/// Receivers waiting for a value, woken one at a time in FIFO order.
+///
+/// It is registered into the container as the receiving waker, so a wake from
+/// the senders is forwarded to the longest waiting receiver.
+#[derive(Default)]
+struct Waiters {
+    queue: Mutex<WaitQueue>,
+}
+
+impl Waiters {
+    fn insert(&self, waker: &Waker) -> u64 {
+        let mut queue = self.queue.lock().unwrap();
+        let id = queue.next_id;
+        queue.next_id = queue.next_id.wrapping_add(1);
+        queue.list.push_back((id, waker.clone()));
+        id
+    }
+
+    // Returns false if the waiter has already been notified.
+    fn update(&self, id: u64, waker: &Waker) -> bool {
+        let mut queue = self.queue.lock().unwrap();
+        match queue.list.iter_mut().find(|(item, _)| *item == id) {
+            Some((_, old)) => {
+                if !old.will_wake(waker) {
+                    *old = waker.clone();
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Returns false if the waiter has already been notified.
+    fn remove(&self, id: u64) -> bool {
+        let mut queue = self.queue.lock().unwrap();
+        match queue.list.iter().position(|(item, _)| *item == id) {
+            Some(pos) => {
+                queue.list.remove(pos);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn notify_one(&self) {
+        let waiter = self.queue.lock().unwrap().list.pop_front();
+        if let Some((_, waker)) = waiter {
+            waker.wake();
+        }
+    }
+}
+
+impl Wake for Waiters {
+    fn wake(self: Arc<Self>) {
+        self.notify_one();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.notify_one();
+    }
+}
+
+/// The receiving side shared by all clones of a receiver. The channel is
+/// closed once it is dropped.
+pub(crate) struct Shared<C: Consumer> {
+    rx: Rx<C>,
+    // The container only supports one consumer at a time.
+    consumer: Mutex<()>,
+    waiters: Arc<Waiters>,
+    notifier: Waker,
+}
+
+impl<C: Consumer> Shared<C> {
+    pub(crate) fn new(rx: Rx<C>) -> Shared<C> {
+        let waiters = Arc::new(Waiters::default());
+        let notifier = Waker::from(waiters.clone());
+        Shared {
+            rx,
+            consumer: Mutex::new(()),
+            waiters,
+            notifier,
+        }
+    }
+
+    pub(crate) fn try_recv(&self) -> Result<C::Item, TryRecvError> {
+        let _consumer = self.consumer.lock().unwrap();
+        self.rx.try_recv()
+    }
+
+    pub(crate) fn recv(&self) -> Recv<'_, C> {
+        Recv {
+            shared: self,
+            waiter: None,
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        let _consumer = self.consumer.lock().unwrap();
+        self.rx.len()
+    }
+
+    pub(crate) fn close(&self) {
+        self.rx.close();
+    }
+
+    pub(crate) fn is_close(&self) -> bool {
+        self.rx.is_close()
+    }
+
+    // Passes the notification on if there is something left for the next
+    // waiting receiver to observe.
+    fn pass_on(&self) {
+        if self.is_close() || self.len() > 0 {
+            self.waiters.notify_one();
+        }
+    }
+}
+
+impl<C: Consumer> Drop for Shared<C> {
+    fn drop(&mut self) {
+        self.rx.close();
+    }
+}
+
+pub(crate) struct Recv<'a, C: Consumer> {
+    shared: &'a Shared<C>,
+    waiter: Option<u64>,
+}
+
+impl<C: Consumer> Recv<'_, C> {
+    fn finish(&mut self, res: Result<C::Item, TryRecvError>) -> Poll<Result<C::Item, RecvError>> {
+        if let Some(id) = self.waiter.take() {
+            self.shared.waiters.remove(id);
+        }
+        self.shared.pass_on();
+        Ready(res.map_err(|_| RecvError))
+    }
+}
+
+impl<C: Consumer> Future for Recv<'_, C> {
+    type Output = Result<C::Item, RecvError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let coop = match coop::poll_budget(cx) {
+            Ready(coop) => coop,
+            Pending => return Pending,
+        };
+        let this = self.get_mut();
+        match this.shared.try_recv() {
+            Err(TryRecvError::Empty) => {}
+            res => {
+                coop.made_progress();
+                return this.finish(res);
+            }
+        }
+
+        let registered = match this.waiter {
+            Some(id) => this.shared.waiters.update(id, cx.waker()),
+            None => false,
+        };
+        if !registered {
+            this.waiter = Some(this.shared.waiters.insert(cx.waker()));
+        }
+        this.shared.rx.register_rx_waker(&this.shared.notifier);
+
+        match this.shared.try_recv() {
+            Err(TryRecvError::Empty) => Pending,
+            res => {
+                coop.made_progress();
+                this.finish(res)
+            }
+        }
+    }
+}
+
+impl<C: Consumer> Drop for Recv<'_, C> {
+    fn drop(&mut self) {
+        if let Some(id) = self.waiter.take() {
+            // The notification was meant for this receiver but it will never
+            // take the value, so hands it over to the next one.
+            if !self.shared.waiters.remove(id) {
+                self.shared.waiters.notify_one();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::atomic::AtomicBool;
+    use std::sync::atomic::Ordering::SeqCst;
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+
+    use crate::sync::mpmc::unbounded_channel;
+
+    struct Flag(AtomicBool);
+
+    impl Wake for Flag {
+        fn wake(self: Arc<Self>) {
+            self.0.store(true, SeqCst);
+        }
+    }
+
+    fn flag_waker() -> (Arc<Flag>, Waker) {
+        let flag = Arc::new(Flag(AtomicBool::new(false)));
+        (flag.clone(), Waker::from(flag))
+    }
+
+    /// UT test cases for mpmc waiting receivers.
+    ///
+    /// # Brief
+    /// 1. Poll two receivers of an empty channel in order.
+    /// 2. Send a value and check only the first waiting receiver is woken.
+    /// 3. Drop the woken receiver without polling it again and check the
+    ///    second one is woken and gets the value.
+    #[test]
+    fn ut_mpmc_fair_wakeup() {
+        let (tx, rx) = unbounded_channel::<i32>();
+        let rx2 = rx.clone();
+        let (flag1, waker1) = flag_waker();
+        let (flag2, waker2) = flag_waker();
+
+        let mut recv1 = Box::pin(rx.recv());
+        let mut recv2 = Box::pin(rx2.recv());
+        let mut cx1 = Context::from_waker(&waker1);
+        let mut cx2 = Context::from_waker(&waker2);
+        assert!(Pin::new(&mut recv1).poll(&mut cx1).is_pending());
+        assert!(Pin::new(&mut recv2).poll(&mut cx2).is_pending());
+
+        tx.send(1).unwrap();
+        assert!(flag1.0.load(SeqCst));
+        assert!(!flag2.0.load(SeqCst));
+
+        drop(recv1);
+        assert!(flag2.0.load(SeqCst));
+        match Pin::new(&mut recv2).poll(&mut cx2) {
+            Poll::Ready(res) => assert_eq!(res, Ok(1)),
+            Poll::Pending => panic!("value should be ready"),
+        }
+    }
+}
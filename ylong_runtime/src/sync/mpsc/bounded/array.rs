@@ -15,16 +15,18 @@ use std::cell::RefCell;
 use std::future::Future;
 use std::mem::MaybeUninit;
 use std::pin::Pin;
-use std::sync::atomic::AtomicUsize;
-use std::sync::atomic::Ordering::{AcqRel, Acquire, Release};
+use std::sync::atomic::Ordering::{AcqRel, Acquire, Relaxed, Release};
+use std::sync::atomic::{AtomicBool, AtomicUsize};
 use std::sync::Arc;
 use std::task::Poll::{Pending, Ready};
-use std::task::{Context, Poll};
+use std::task::{Context, Poll, Waker};
 
 use crate::sync::atomic_waker::AtomicWaker;
 use crate::sync::error::{RecvError, SendError, TryRecvError, TrySendError};
 use crate::sync::mpsc::Container;
+use crate::sync::mutex::Mutex;
 use crate::sync::wake_list::{ListItem, WakerList};
+use crate::task::coop;
 
 /// The offset of the index.
 const INDEX_SHIFT: usize = 1;
@@ -34,6 +36,8 @@ const CLOSED: usize = 0b01;
 pub(crate) struct Node<T> {
     index: AtomicUsize,
     value: RefCell<MaybeUninit<T>>,
+    /// Marks that the node was reserved but released without a value.
+    abandoned: AtomicBool,
 }
 
 /// Bounded lockless queue.
@@ -43,6 +47,7 @@ pub(crate) struct Array<T> {
     capacity: usize,
     rx_waker: AtomicWaker,
     waiters: WakerList,
+    reserve_lock: Mutex<()>,
     data: Box<[Node<T>]>,
 }
 
@@ -62,6 +67,7 @@ impl<T> Array<T> {
             .map(|i| Node {
                 index: AtomicUsize::new(i),
                 value: RefCell::new(MaybeUninit::uninit()),
+                abandoned: AtomicBool::new(false),
             })
             .collect();
         Array {
@@ -70,31 +76,30 @@ impl<T> Array<T> {
             capacity,
             rx_waker: AtomicWaker::new(),
             waiters: WakerList::new(),
+            reserve_lock: Mutex::new(()),
             data,
         }
     }
 
-    fn prepare_send(&self) -> SendPosition {
+    pub(crate) fn prepare_send(&self) -> SendPosition {
+        self.prepare_send_many(1)
+    }
+
+    /// Claims `n` consecutive nodes, returns the index of the first one.
+    fn prepare_send_many(&self, n: usize) -> SendPosition {
         let mut tail = self.tail.load(Acquire);
         loop {
             if tail & CLOSED == CLOSED {
                 return SendPosition::Closed;
             }
-            let index = (tail >> INDEX_SHIFT) % self.capacity;
-            // index is bounded by capacity, unwrap is safe
-            let node = self.data.get(index).unwrap();
-            let node_index = node.index.load(Acquire);
-
-            // Compare the index of the node with the tail to avoid senders in different
-            // cycles writing data to the same point at the same time.
-            if (tail >> INDEX_SHIFT) == node_index {
+            if self.is_available(tail, n) {
                 match self.tail.compare_exchange(
                     tail,
-                    tail.wrapping_add(1 << INDEX_SHIFT),
+                    tail.wrapping_add(n << INDEX_SHIFT),
                     AcqRel,
                     Acquire,
                 ) {
-                    Ok(_) => return SendPosition::Pos(index),
+                    Ok(_) => return SendPosition::Pos((tail >> INDEX_SHIFT) % self.capacity),
                     Err(actual) => tail = actual,
                 }
             } else {
@@ -103,6 +108,19 @@ impl<T> Array<T> {
         }
     }
 
+    // Checks whether the `n` nodes starting from the tail can be written in.
+    // The receiver releases nodes in order, so only the last one needs to be
+    // checked.
+    fn is_available(&self, tail: usize, n: usize) -> bool {
+        let last = (tail >> INDEX_SHIFT).wrapping_add(n - 1);
+        // index is bounded by capacity, unwrap is safe
+        let node = self.data.get(last % self.capacity).unwrap();
+
+        // Compare the index of the node with the tail to avoid senders in different
+        // cycles writing data to the same point at the same time.
+        node.index.load(Acquire) == last
+    }
+
     pub(crate) fn write(&self, index: usize, value: T) {
         // index is bounded by capacity, unwrap is safe
         let node = self.data.get(index).unwrap();
@@ -113,8 +131,49 @@ impl<T> Array<T> {
         self.rx_waker.wake();
     }
 
+    /// Releases a reserved node without writing a value.
+    ///
+    /// The node is given back at once if no other node has been claimed after
+    /// it, otherwise the receiver skips it.
+    pub(crate) fn abandon(&self, index: usize) {
+        // index is bounded by capacity, unwrap is safe
+        let node = self.data.get(index).unwrap();
+        // The index of a claimed node stays the same until it is written in.
+        let pos = node.index.load(Acquire);
+        let mut tail = self.tail.load(Acquire);
+        while (tail >> INDEX_SHIFT) == pos.wrapping_add(1) {
+            match self.tail.compare_exchange(
+                tail,
+                tail.wrapping_sub(1 << INDEX_SHIFT),
+                AcqRel,
+                Acquire,
+            ) {
+                Ok(_) => {
+                    self.waiters.notify_one();
+                    return;
+                }
+                Err(actual) => tail = actual,
+            }
+        }
+        node.abandoned.store(true, Relaxed);
+
+        // Mark that the node is ready to be skipped.
+        node.index.fetch_sub(1, Release);
+        self.rx_waker.wake();
+    }
+
     pub(crate) async fn get_position(&self) -> SendPosition {
-        Position { array: self }.await
+        Position { array: self, n: 1 }.await
+    }
+
+    /// Waits until `n` consecutive nodes can be claimed.
+    ///
+    /// Only one task waits for several nodes at a time, otherwise they would
+    /// keep handing the wakeup over to each other while there are not enough
+    /// free nodes.
+    pub(crate) async fn get_positions(&self, n: usize) -> SendPosition {
+        let _lock = self.reserve_lock.lock().await;
+        Position { array: self, n }.await
     }
 
     pub(crate) fn try_send(&self, value: T) -> Result<(), TrySendError<T>> {
@@ -141,11 +200,23 @@ impl<T> Array<T> {
     }
 
     pub(crate) fn try_recv(&self) -> Result<T, TryRecvError> {
-        let head = *self.head.borrow();
-        let index = head % self.capacity;
+        let mut head = *self.head.borrow();
+        let mut index = head % self.capacity;
         // index is bounded by capacity, unwrap is safe
-        let node = self.data.get(index).unwrap();
-        let node_index = node.index.load(Acquire);
+        let mut node = self.data.get(index).unwrap();
+        let mut node_index = node.index.load(Acquire);
+
+        // Skips the nodes released by unused permits.
+        while head == node_index.wrapping_add(1) && node.abandoned.swap(false, Relaxed) {
+            node.index.fetch_add(self.capacity + 1, Release);
+            self.waiters.notify_one();
+            head += 1;
+            self.head.replace(head);
+            index = head % self.capacity;
+            // index is bounded by capacity, unwrap is safe
+            node = self.data.get(index).unwrap();
+            node_index = node.index.load(Acquire);
+        }
 
         // Check whether the node has data.
         if head == node_index.wrapping_add(1) {
@@ -166,20 +237,30 @@ impl<T> Array<T> {
         }
     }
 
-    pub(crate) fn poll_recv(&self, cx: &mut Context<'_>) -> Poll<Result<T, RecvError>> {
-        match self.try_recv() {
-            Ok(val) => return Ready(Ok(val)),
-            Err(TryRecvError::Closed) => return Ready(Err(RecvError)),
-            Err(TryRecvError::Empty) => {}
-        }
+    pub(crate) fn register_rx_waker(&self, waker: &Waker) {
+        self.rx_waker.register_by_ref(waker);
+    }
 
-        self.rx_waker.register_by_ref(cx.waker());
+    pub(crate) fn poll_recv(&self, cx: &mut Context<'_>) -> Poll<Result<T, RecvError>> {
+        let coop = match coop::poll_budget(cx) {
+            Ready(coop) => coop,
+            Pending => return Pending,
+        };
+        let res = match self.try_recv() {
+            Err(TryRecvError::Empty) => {
+                self.rx_waker.register_by_ref(cx.waker());
+                self.try_recv()
+            }
+            res => res,
+        };
 
-        match self.try_recv() {
-            Ok(val) => Ready(Ok(val)),
-            Err(TryRecvError::Closed) => Ready(Err(RecvError)),
-            Err(TryRecvError::Empty) => Pending,
-        }
+        let res = match res {
+            Ok(val) => Ok(val),
+            Err(TryRecvError::Closed) => Err(RecvError),
+            Err(TryRecvError::Empty) => return Pending,
+        };
+        coop.made_progress();
+        Ready(res)
     }
 
     pub(crate) fn capacity(&self) -> usize {
@@ -217,6 +298,9 @@ impl<T> Drop for Array<T> {
             index %= self.capacity;
             // index is bounded by capacity, unwrap is safe
             let node = self.data.get(index).unwrap();
+            if node.abandoned.load(Relaxed) {
+                continue;
+            }
             unsafe {
                 node.value.borrow_mut().as_mut_ptr().drop_in_place();
             }
@@ -226,17 +310,23 @@ impl<T> Drop for Array<T> {
 
 struct Position<'a, T> {
     array: &'a Array<T>,
+    n: usize,
 }
 
 impl<T> Future for Position<'_, T> {
     type Output = SendPosition;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        match self.array.prepare_send() {
+        match self.array.prepare_send_many(self.n) {
             SendPosition::Pos(index) => return Ready(SendPosition::Pos(index)),
             SendPosition::Closed => return Ready(SendPosition::Closed),
             SendPosition::Full => {}
         }
+        // The wakeup may be meant for a node that is not enough for this task,
+        // hands it over to the others waiting for a single node.
+        if self.n > 1 && self.array.is_available(self.array.tail.load(Acquire), 1) {
+            self.array.waiters.notify_one();
+        }
         let wake = cx.waker().clone();
         self.array.waiters.insert(ListItem {
             wake,
@@ -244,11 +334,7 @@ impl<T> Future for Position<'_, T> {
         });
 
         let tail = self.array.tail.load(Acquire);
-        let index = (tail >> INDEX_SHIFT) % self.array.capacity;
-        // index is bounded by capacity, unwrap is safe
-        let node = self.array.data.get(index).unwrap();
-        let node_index = node.index.load(Acquire);
-        if (tail >> INDEX_SHIFT) == node_index || tail & CLOSED == CLOSED {
+        if self.array.is_available(tail, self.n) || tail & CLOSED == CLOSED {
             self.array.waiters.notify_one();
         }
         Pending
@@ -14,16 +14,19 @@
 //! Bounded channel
 
 pub(crate) mod array;
+mod permit;
 
 use std::task::{Context, Poll};
 
+use crate::executor::block_on::blocking_wait;
 use crate::futures::poll_fn;
 use crate::sync::error::{RecvError, SendError, TryRecvError, TrySendError};
-use crate::sync::mpsc::bounded::array::Array;
-use crate::sync::mpsc::{channel, Container, Rx, Tx};
+use crate::sync::mpsc::bounded::array::{Array, SendPosition};
+pub use crate::sync::mpsc::bounded::permit::{OwnedPermit, Permit, PermitIterator};
+use crate::sync::mpsc::{channel, Container, Rx, Tx, WeakTx};
 cfg_time!(
+    use crate::executor::block_on::blocking_wait_timeout;
     use crate::sync::error::{RecvTimeoutError, SendTimeoutError};
-    use crate::sync::mpsc::bounded::array::SendPosition;
     use crate::time::timeout;
     use std::time::Duration;
 );
@@ -121,7 +124,7 @@ pub fn bounded_channel<T>(number: usize) -> (BoundedSender<T>, BoundedReceiver<T
 }
 
 impl<T> BoundedSender<T> {
-    fn new(channel: Tx<Array<T>>) -> BoundedSender<T> {
+    pub(crate) fn new(channel: Tx<Array<T>>) -> BoundedSender<T> {
         BoundedSender { channel }
     }
 
@@ -228,6 +231,42 @@ impl<T> BoundedSender<T> {
         }
     }
 
+    /// Sends a value to the associated receiver, blocking the current thread
+    /// until there is capacity in the channel.
+    ///
+    /// This method is meant to be used by ordinary threads that communicate
+    /// with asynchronous tasks.
+    ///
+    /// # Return value
+    /// * `Ok()` if sending a value successfully.
+    /// * `Err(SendError(T))` if receiver has been dropped or closed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called in a runtime context, use [`send`] there instead.
+    ///
+    /// [`send`]: BoundedSender::send
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::mpsc::bounded::bounded_channel;
+    /// let (tx, mut rx) = bounded_channel(1);
+    /// let handle = ylong_runtime::spawn(async move {
+    ///     assert_eq!(rx.recv().await, Ok(1));
+    ///     assert_eq!(rx.recv().await, Ok(2));
+    /// });
+    /// let thread = std::thread::spawn(move || {
+    ///     assert!(tx.blocking_send(1).is_ok());
+    ///     assert!(tx.blocking_send(2).is_ok());
+    /// });
+    /// thread.join().unwrap();
+    /// let _ = ylong_runtime::block_on(handle);
+    /// ```
+    pub fn blocking_send(&self, value: T) -> Result<(), SendError<T>> {
+        blocking_wait("BoundedSender::blocking_send", self.channel.send(value))
+    }
+
     /// Checks whether the channel is closed. If so, the sender could not
     /// send values anymore. It returns true after the [`BoundedReceiver`] is
     /// dropped or the [`close`] method gets called.
@@ -303,6 +342,184 @@ impl<T> BoundedSender<T> {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Waits for a slot in the channel and reserves it, the returned [`Permit`]
+    /// can send a value without waiting.
+    ///
+    /// This allows to check capacity before producing the value, or to hold a
+    /// slot across other operations.
+    ///
+    /// # Return value
+    /// * `Ok(Permit)` if a slot has been reserved.
+    /// * `Err(SendError(()))` if receiver has been dropped or closed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::mpsc::bounded::bounded_channel;
+    /// async fn io_func() {
+    ///     let (tx, mut rx) = bounded_channel(1);
+    ///     let permit = tx.reserve().await.unwrap();
+    ///     permit.send("Hello");
+    ///     assert_eq!(rx.recv().await, Ok("Hello"));
+    /// }
+    /// ```
+    pub async fn reserve(&self) -> Result<Permit<'_, T>, SendError<()>> {
+        match self.channel.get_position().await {
+            SendPosition::Pos(index) => Ok(Permit::new(&self.channel, index)),
+            SendPosition::Closed => Err(SendError(())),
+            SendPosition::Full => unreachable!(),
+        }
+    }
+
+    /// Attempts to reserve a slot in the channel without waiting.
+    ///
+    /// # Return value
+    /// * `Ok(Permit)` if a slot has been reserved.
+    /// * `Err(TrySendError::Full(()))` if the buffer of channel is full.
+    /// * `Err(TrySendError::Closed(()))` if receiver has been dropped or
+    ///   closed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::error::TrySendError;
+    /// use ylong_runtime::sync::mpsc::bounded::bounded_channel;
+    /// let (tx, mut rx) = bounded_channel(1);
+    /// let permit = tx.try_reserve().unwrap();
+    /// assert_eq!(tx.try_reserve().err(), Some(TrySendError::Full(())));
+    /// permit.send(1);
+    /// assert_eq!(rx.try_recv(), Ok(1));
+    /// ```
+    pub fn try_reserve(&self) -> Result<Permit<'_, T>, TrySendError<()>> {
+        match self.channel.prepare_send() {
+            SendPosition::Pos(index) => Ok(Permit::new(&self.channel, index)),
+            SendPosition::Full => Err(TrySendError::Full(())),
+            SendPosition::Closed => Err(TrySendError::Closed(())),
+        }
+    }
+
+    /// Waits for `n` consecutive slots in the channel and reserves them at
+    /// once, the values sent through the returned permits are received in
+    /// order.
+    ///
+    /// # Return value
+    /// * `Ok(PermitIterator)` if the slots have been reserved.
+    /// * `Err(SendError(()))` if receiver has been dropped or closed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than the capacity of the channel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::mpsc::bounded::bounded_channel;
+    /// async fn io_func() {
+    ///     let (tx, mut rx) = bounded_channel(2);
+    ///     let mut permits = tx.reserve_many(2).await.unwrap();
+    ///     assert!(tx.try_send(0).is_err());
+    ///     permits.next().unwrap().send(1);
+    ///     permits.next().unwrap().send(2);
+    ///     assert_eq!(rx.recv().await, Ok(1));
+    ///     assert_eq!(rx.recv().await, Ok(2));
+    /// }
+    /// ```
+    pub async fn reserve_many(&self, n: usize) -> Result<PermitIterator<'_, T>, SendError<()>> {
+        assert!(
+            n <= self.capacity(),
+            "Cannot reserve more slots than the capacity of the channel."
+        );
+        if n == 0 {
+            return Ok(PermitIterator::new(&self.channel, 0, 0));
+        }
+        match self.channel.get_positions(n).await {
+            SendPosition::Pos(index) => Ok(PermitIterator::new(&self.channel, index, n)),
+            SendPosition::Closed => Err(SendError(())),
+            SendPosition::Full => unreachable!(),
+        }
+    }
+
+    /// Waits for a slot in the channel and reserves it. Unlike [`reserve`],
+    /// the sender is moved into the returned [`OwnedPermit`], so it can be
+    /// used in `'static` futures.
+    ///
+    /// [`reserve`]: BoundedSender::reserve
+    ///
+    /// # Return value
+    /// * `Ok(OwnedPermit)` if a slot has been reserved.
+    /// * `Err(SendError(()))` if receiver has been dropped or closed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::mpsc::bounded::bounded_channel;
+    /// async fn io_func() {
+    ///     let (tx, mut rx) = bounded_channel(1);
+    ///     let permit = tx.reserve_owned().await.unwrap();
+    ///     ylong_runtime::spawn(async move {
+    ///         permit.send(1);
+    ///     });
+    ///     assert_eq!(rx.recv().await, Ok(1));
+    /// }
+    /// ```
+    pub async fn reserve_owned(self) -> Result<OwnedPermit<T>, SendError<()>> {
+        match self.channel.get_position().await {
+            SendPosition::Pos(index) => Ok(OwnedPermit::new(self, index)),
+            SendPosition::Closed => Err(SendError(())),
+            SendPosition::Full => unreachable!(),
+        }
+    }
+
+    /// Attempts to reserve a slot in the channel without waiting, the sender
+    /// is moved into the returned [`OwnedPermit`].
+    ///
+    /// # Return value
+    /// * `Ok(OwnedPermit)` if a slot has been reserved.
+    /// * `Err(TrySendError::Full(BoundedSender))` if the buffer of channel is
+    ///   full.
+    /// * `Err(TrySendError::Closed(BoundedSender))` if receiver has been
+    ///   dropped or closed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::error::TrySendError;
+    /// use ylong_runtime::sync::mpsc::bounded::bounded_channel;
+    /// let (tx, mut rx) = bounded_channel(1);
+    /// let permit = match tx.clone().try_reserve_owned() {
+    ///     Ok(permit) => permit,
+    ///     Err(_) => panic!("This won't happen"),
+    /// };
+    /// assert!(matches!(tx.try_reserve_owned(), Err(TrySendError::Full(_))));
+    /// permit.send(1);
+    /// assert_eq!(rx.try_recv(), Ok(1));
+    /// ```
+    pub fn try_reserve_owned(self) -> Result<OwnedPermit<T>, TrySendError<BoundedSender<T>>> {
+        match self.channel.prepare_send() {
+            SendPosition::Pos(index) => Ok(OwnedPermit::new(self, index)),
+            SendPosition::Full => Err(TrySendError::Full(self)),
+            SendPosition::Closed => Err(TrySendError::Closed(self)),
+        }
+    }
+
+    /// Creates a [`WeakBoundedSender`] which does not keep the channel open.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::mpsc::bounded::bounded_channel;
+    /// let (tx, rx) = bounded_channel::<i32>(1);
+    /// let weak = tx.downgrade();
+    /// assert!(weak.upgrade().is_some());
+    /// drop(tx);
+    /// assert!(weak.upgrade().is_none());
+    /// ```
+    pub fn downgrade(&self) -> WeakBoundedSender<T> {
+        WeakBoundedSender {
+            channel: self.channel.downgrade(),
+        }
+    }
 }
 
 impl<T> Drop for BoundedSender<T> {
@@ -311,6 +528,56 @@ impl<T> Drop for BoundedSender<T> {
     }
 }
 
+/// A sender of bounded channel that does not keep the channel open.
+///
+/// The channel is closed once all [`BoundedSender`]s have been dropped, even
+/// if there are weak senders left. A weak sender can be upgraded to a
+/// [`BoundedSender`] while the channel is still open.
+///
+/// # Examples
+///
+/// ```
+/// use ylong_runtime::sync::mpsc::bounded::bounded_channel;
+/// let (tx, mut rx) = bounded_channel(1);
+/// let weak = tx.downgrade();
+/// let tx2 = weak.upgrade().unwrap();
+/// tx2.try_send(1).unwrap();
+/// drop(tx);
+/// drop(tx2);
+/// assert_eq!(rx.try_recv(), Ok(1));
+/// assert!(rx.try_recv().is_err());
+/// ```
+pub struct WeakBoundedSender<T> {
+    channel: WeakTx<Array<T>>,
+}
+
+impl<T> Clone for WeakBoundedSender<T> {
+    fn clone(&self) -> Self {
+        WeakBoundedSender {
+            channel: self.channel.clone(),
+        }
+    }
+}
+
+impl<T> WeakBoundedSender<T> {
+    /// Tries to convert the weak sender into a [`BoundedSender`]. Returns
+    /// `None` if all senders have been dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::mpsc::bounded::bounded_channel;
+    /// let (tx, rx) = bounded_channel::<i32>(1);
+    /// let weak = tx.downgrade();
+    /// assert!(weak.upgrade().is_some());
+    /// drop(tx);
+    /// assert!(weak.upgrade().is_none());
+    /// ```
+    pub fn upgrade(&self) -> Option<BoundedSender<T>> {
+        self.channel.upgrade().map(BoundedSender::new)
+    }
+}
+
 impl<T> BoundedReceiver<T> {
     fn new(channel: Rx<Array<T>>) -> BoundedReceiver<T> {
         BoundedReceiver { channel }
@@ -440,6 +707,50 @@ impl<T> BoundedReceiver<T> {
         poll_fn(|cx| self.channel.poll_recv(cx)).await
     }
 
+    /// Receives values from the associated [`BoundedSender`] and appends them
+    /// to `buf`, at most `limit` values at a time.
+    ///
+    /// It waits until at least one value is available, then takes the values
+    /// that are already in the channel without waiting for more.
+    ///
+    /// # Return value
+    /// The number of values received, `0` is returned if `limit` is zero or
+    /// all senders have been dropped and no messages remaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::mpsc::bounded::bounded_channel;
+    /// async fn io_func() {
+    ///     let (tx, mut rx) = bounded_channel(3);
+    ///     for i in 0..3 {
+    ///         tx.try_send(i).unwrap();
+    ///     }
+    ///     let mut buf = Vec::new();
+    ///     assert_eq!(rx.recv_many(&mut buf, 2).await, 2);
+    ///     assert_eq!(rx.recv_many(&mut buf, 2).await, 1);
+    ///     assert_eq!(buf, vec![0, 1, 2]);
+    /// }
+    /// ```
+    pub async fn recv_many(&mut self, buf: &mut Vec<T>, limit: usize) -> usize {
+        if limit == 0 {
+            return 0;
+        }
+        match self.recv().await {
+            Ok(value) => buf.push(value),
+            Err(_) => return 0,
+        }
+        let mut count = 1;
+        while count < limit {
+            match self.channel.try_recv() {
+                Ok(value) => buf.push(value),
+                Err(_) => break,
+            }
+            count += 1;
+        }
+        count
+    }
+
     /// Attempts to receive a value from the associated [`BoundedSender`] in a
     /// limited amount of time.
     ///
@@ -474,6 +785,86 @@ impl<T> BoundedReceiver<T> {
         }
     }
 
+    /// Receives a value from the associated [`BoundedSender`], blocking the
+    /// current thread until a value arrives.
+    ///
+    /// This method is meant to be used by ordinary threads that communicate
+    /// with asynchronous tasks.
+    ///
+    /// # Return value
+    /// * `Ok(T)` if receiving a value successfully.
+    /// * `Err(RecvError)` if all senders have been dropped or the channel is
+    ///   closed, and no messages remaining.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called in a runtime context, use [`recv`] there instead.
+    ///
+    /// [`recv`]: BoundedReceiver::recv
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::mpsc::bounded::bounded_channel;
+    /// let (tx, mut rx) = bounded_channel(1);
+    /// let handle = ylong_runtime::spawn(async move {
+    ///     assert!(tx.send(1).await.is_ok());
+    /// });
+    /// let thread = std::thread::spawn(move || {
+    ///     assert_eq!(rx.blocking_recv(), Ok(1));
+    /// });
+    /// thread.join().unwrap();
+    /// let _ = ylong_runtime::block_on(handle);
+    /// ```
+    pub fn blocking_recv(&mut self) -> Result<T, RecvError> {
+        blocking_wait(
+            "BoundedReceiver::blocking_recv",
+            poll_fn(|cx| self.channel.poll_recv(cx)),
+        )
+    }
+
+    /// Receives a value from the associated [`BoundedSender`], blocking the
+    /// current thread for at most the given amount of time.
+    ///
+    /// # Return value
+    /// * `Ok(T)` if receiving a value successfully.
+    /// * `Err(RecvTimeoutError::Closed)` if all senders have been dropped.
+    /// * `Err(RecvTimeoutError::TimeOut)` if time limit has been passed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called in a runtime context, use [`recv_timeout`] there
+    /// instead.
+    ///
+    /// [`recv_timeout`]: BoundedReceiver::recv_timeout
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use ylong_runtime::sync::error::RecvTimeoutError;
+    /// use ylong_runtime::sync::mpsc::bounded::bounded_channel;
+    /// let (tx, mut rx) = bounded_channel::<i32>(1);
+    /// assert_eq!(
+    ///     rx.blocking_recv_timeout(Duration::from_millis(10)),
+    ///     Err(RecvTimeoutError::Timeout)
+    /// );
+    /// tx.try_send(1).unwrap();
+    /// assert_eq!(rx.blocking_recv_timeout(Duration::from_millis(10)), Ok(1));
+    /// ```
+    #[cfg(feature = "time")]
+    pub fn blocking_recv_timeout(&mut self, time: Duration) -> Result<T, RecvTimeoutError> {
+        match blocking_wait_timeout(
+            "BoundedReceiver::blocking_recv_timeout",
+            poll_fn(|cx| self.channel.poll_recv(cx)),
+            time,
+        ) {
+            Some(res) => res.map_err(|_| RecvTimeoutError::Closed),
+            None => Err(RecvTimeoutError::Timeout),
+        }
+    }
+
     /// Closes the channel, prevents the `Sender` from sending more values.
     ///
     /// The `Sender` will fail to call [`send`] or [`try_send`] after the
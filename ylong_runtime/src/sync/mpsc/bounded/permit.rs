@@ -0,0 +1,226 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Permits of the bounded channel
+
+use std::mem;
+
+use crate::sync::mpsc::bounded::array::Array;
+use crate::sync::mpsc::bounded::BoundedSender;
+
+/// A reserved slot in a bounded channel, sending a value through it never
+/// waits.
+///
+/// Created by [`BoundedSender::reserve`] and [`BoundedSender::try_reserve`].
+/// The slot is given back to the channel if the permit is dropped without
+/// sending.
+///
+/// The slot keeps its place in the channel, so the receiver cannot get the
+/// values sent after the permit was created until the permit is used or
+/// dropped. A dropped permit frees its slot at once if no other slot has been
+/// reserved after it, otherwise once the receiver reaches it.
+///
+/// # Examples
+///
+/// ```
+/// use ylong_runtime::sync::mpsc::bounded::bounded_channel;
+/// async fn io_func() {
+///     let (tx, mut rx) = bounded_channel(1);
+///     let permit = tx.reserve().await.unwrap();
+///     permit.send(1);
+///     assert_eq!(rx.recv().await, Ok(1));
+/// }
+/// ```
+pub struct Permit<'a, T> {
+    array: &'a Array<T>,
+    index: usize,
+}
+
+impl<'a, T> Permit<'a, T> {
+    pub(crate) fn new(array: &'a Array<T>, index: usize) -> Permit<'a, T> {
+        Permit { array, index }
+    }
+
+    /// Sends a value into the reserved slot.
+    ///
+    /// The value is dropped together with the channel if the receiver has
+    /// been closed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::mpsc::bounded::bounded_channel;
+    /// let (tx, mut rx) = bounded_channel(1);
+    /// let permit = tx.try_reserve().unwrap();
+    /// assert!(tx.try_send(2).is_err());
+    /// permit.send(1);
+    /// assert_eq!(rx.try_recv(), Ok(1));
+    /// ```
+    pub fn send(self, value: T) {
+        self.array.write(self.index, value);
+        mem::forget(self);
+    }
+}
+
+impl<T> Drop for Permit<'_, T> {
+    fn drop(&mut self) {
+        self.array.abandon(self.index);
+    }
+}
+
+/// An owned version of [`Permit`], which holds the sender and can be moved
+/// into `'static` futures.
+///
+/// Created by [`BoundedSender::reserve_owned`] and
+/// [`BoundedSender::try_reserve_owned`].
+///
+/// # Examples
+///
+/// ```
+/// use ylong_runtime::sync::mpsc::bounded::bounded_channel;
+/// async fn io_func() {
+///     let (tx, mut rx) = bounded_channel(1);
+///     let permit = tx.reserve_owned().await.unwrap();
+///     let handle = ylong_runtime::spawn(async move {
+///         let _tx = permit.send(1);
+///     });
+///     assert_eq!(rx.recv().await, Ok(1));
+///     let _ = handle.await;
+/// }
+/// ```
+pub struct OwnedPermit<T> {
+    sender: Option<BoundedSender<T>>,
+    index: usize,
+}
+
+impl<T> OwnedPermit<T> {
+    pub(crate) fn new(sender: BoundedSender<T>, index: usize) -> OwnedPermit<T> {
+        OwnedPermit {
+            sender: Some(sender),
+            index,
+        }
+    }
+
+    /// Sends a value into the reserved slot, and gives back the sender.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::mpsc::bounded::bounded_channel;
+    /// let (tx, mut rx) = bounded_channel(1);
+    /// let permit = tx.try_reserve_owned().ok().unwrap();
+    /// let tx = permit.send(1);
+    /// assert_eq!(rx.try_recv(), Ok(1));
+    /// assert!(tx.try_send(2).is_ok());
+    /// ```
+    pub fn send(mut self, value: T) -> BoundedSender<T> {
+        // the sender is only taken when the permit is consumed, unwrap is safe
+        let sender = self.sender.take().unwrap();
+        sender.channel.write(self.index, value);
+        sender
+    }
+
+    /// Gives back the reserved slot without sending, and returns the sender.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::mpsc::bounded::bounded_channel;
+    /// let (tx, _rx) = bounded_channel::<i32>(1);
+    /// let permit = tx.try_reserve_owned().ok().unwrap();
+    /// let tx = permit.release();
+    /// assert!(tx.try_send(1).is_ok());
+    /// ```
+    pub fn release(mut self) -> BoundedSender<T> {
+        // the sender is only taken when the permit is consumed, unwrap is safe
+        let sender = self.sender.take().unwrap();
+        sender.channel.abandon(self.index);
+        sender
+    }
+}
+
+impl<T> Drop for OwnedPermit<T> {
+    fn drop(&mut self) {
+        if let Some(sender) = self.sender.take() {
+            sender.channel.abandon(self.index);
+        }
+    }
+}
+
+/// An iterator over the consecutive slots reserved by
+/// [`BoundedSender::reserve_many`], which yields a [`Permit`] for each of them.
+///
+/// The slots not yet taken out are given back to the channel when the iterator
+/// is dropped.
+///
+/// # Examples
+///
+/// ```
+/// use ylong_runtime::sync::mpsc::bounded::bounded_channel;
+/// async fn io_func() {
+///     let (tx, mut rx) = bounded_channel(4);
+///     let permits = tx.reserve_many(2).await.unwrap();
+///     assert_eq!(permits.len(), 2);
+///     for (i, permit) in permits.enumerate() {
+///         permit.send(i);
+///     }
+///     assert_eq!(rx.recv().await, Ok(0));
+///     assert_eq!(rx.recv().await, Ok(1));
+/// }
+/// ```
+pub struct PermitIterator<'a, T> {
+    array: &'a Array<T>,
+    index: usize,
+    remaining: usize,
+}
+
+impl<'a, T> PermitIterator<'a, T> {
+    pub(crate) fn new(array: &'a Array<T>, index: usize, n: usize) -> PermitIterator<'a, T> {
+        PermitIterator {
+            array,
+            index,
+            remaining: n,
+        }
+    }
+}
+
+impl<'a, T> Iterator for PermitIterator<'a, T> {
+    type Item = Permit<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let permit = Permit::new(self.array, self.index);
+        self.index = (self.index + 1) % self.array.capacity();
+        self.remaining -= 1;
+        Some(permit)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for PermitIterator<'_, T> {}
+
+impl<T> Drop for PermitIterator<'_, T> {
+    fn drop(&mut self) {
+        // Gives back the slots from the last one, so that they can be reused at
+        // once if no other slot has been reserved after them.
+        let capacity = self.array.capacity();
+        for i in (0..self.remaining).rev() {
+            self.array.abandon((self.index + i) % capacity);
+        }
+    }
+}
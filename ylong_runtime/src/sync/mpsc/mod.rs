@@ -16,8 +16,11 @@
 pub mod bounded;
 pub mod unbounded;
 
-pub use bounded::{bounded_channel, BoundedReceiver, BoundedSender};
-pub use unbounded::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+pub use bounded::{
+    bounded_channel, BoundedReceiver, BoundedSender, OwnedPermit, Permit, PermitIterator,
+    WeakBoundedSender,
+};
+pub use unbounded::{unbounded_channel, UnboundedReceiver, UnboundedSender, WeakUnboundedSender};
 
 pub(crate) trait Container {
     fn close(&self);
@@ -29,8 +32,8 @@ pub(crate) trait Container {
 
 use std::ops::Deref;
 use std::sync::atomic::AtomicUsize;
-use std::sync::atomic::Ordering::{AcqRel, Relaxed};
-use std::sync::Arc;
+use std::sync::atomic::Ordering::{AcqRel, Acquire, Relaxed};
+use std::sync::{Arc, Weak};
 
 pub(crate) struct Channel<C: Container> {
     chan: C,
@@ -80,6 +83,47 @@ impl<C: Container> Tx<C> {
     }
 }
 
+impl<C: Container> Tx<C> {
+    pub(crate) fn downgrade(&self) -> WeakTx<C> {
+        WeakTx {
+            inner: Arc::downgrade(&self.inner),
+        }
+    }
+}
+
+/// A sender that does not keep the channel open.
+pub(crate) struct WeakTx<C: Container> {
+    inner: Weak<Channel<C>>,
+}
+
+impl<C: Container> Clone for WeakTx<C> {
+    fn clone(&self) -> Self {
+        WeakTx {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<C: Container> WeakTx<C> {
+    pub(crate) fn upgrade(&self) -> Option<Tx<C>> {
+        let inner = self.inner.upgrade()?;
+        let mut tx_cnt = inner.tx_cnt.load(Acquire);
+        loop {
+            // All senders have been dropped, the channel is closed.
+            if tx_cnt == 0 {
+                return None;
+            }
+            match inner
+                .tx_cnt
+                .compare_exchange(tx_cnt, tx_cnt + 1, AcqRel, Acquire)
+            {
+                Ok(_) => return Some(Tx { inner }),
+                Err(actual) => tx_cnt = actual,
+            }
+        }
+    }
+}
+
 impl<C: Container> Deref for Tx<C> {
     type Target = C;
 
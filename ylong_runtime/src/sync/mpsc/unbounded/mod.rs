@@ -17,12 +17,14 @@ pub(crate) mod queue;
 
 use std::task::{Context, Poll};
 
+use crate::executor::block_on::blocking_wait;
 use crate::futures::poll_fn;
 use crate::sync::error::{RecvError, SendError, TryRecvError};
 use crate::sync::mpsc::unbounded::queue::Queue;
-use crate::sync::mpsc::{channel, Container, Rx, Tx};
+use crate::sync::mpsc::{channel, Container, Rx, Tx, WeakTx};
 
 cfg_time!(
+    use crate::executor::block_on::blocking_wait_timeout;
     use crate::time::timeout;
     use std::time::Duration;
     use crate::sync::error::RecvTimeoutError;
@@ -102,7 +104,7 @@ pub fn unbounded_channel<T>() -> (UnboundedSender<T>, UnboundedReceiver<T>) {
 }
 
 impl<T> UnboundedSender<T> {
-    fn new(channel: Tx<Queue<T>>) -> UnboundedSender<T> {
+    pub(crate) fn new(channel: Tx<Queue<T>>) -> UnboundedSender<T> {
         UnboundedSender { channel }
     }
 
@@ -185,6 +187,24 @@ impl<T> UnboundedSender<T> {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Creates a [`WeakUnboundedSender`] which does not keep the channel open.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::mpsc::unbounded::unbounded_channel;
+    /// let (tx, rx) = unbounded_channel::<i32>();
+    /// let weak = tx.downgrade();
+    /// assert!(weak.upgrade().is_some());
+    /// drop(tx);
+    /// assert!(weak.upgrade().is_none());
+    /// ```
+    pub fn downgrade(&self) -> WeakUnboundedSender<T> {
+        WeakUnboundedSender {
+            channel: self.channel.downgrade(),
+        }
+    }
 }
 
 impl<T> Drop for UnboundedSender<T> {
@@ -193,6 +213,56 @@ impl<T> Drop for UnboundedSender<T> {
     }
 }
 
+/// A sender of unbounded channel that does not keep the channel open.
+///
+/// The channel is closed once all [`UnboundedSender`]s have been dropped, even
+/// if there are weak senders left. A weak sender can be upgraded to an
+/// [`UnboundedSender`] while the channel is still open.
+///
+/// # Examples
+///
+/// ```
+/// use ylong_runtime::sync::mpsc::unbounded::unbounded_channel;
+/// let (tx, mut rx) = unbounded_channel();
+/// let weak = tx.downgrade();
+/// let tx2 = weak.upgrade().unwrap();
+/// tx2.send(1).unwrap();
+/// drop(tx);
+/// drop(tx2);
+/// assert_eq!(rx.try_recv(), Ok(1));
+/// assert!(rx.try_recv().is_err());
+/// ```
+pub struct WeakUnboundedSender<T> {
+    channel: WeakTx<Queue<T>>,
+}
+
+impl<T> Clone for WeakUnboundedSender<T> {
+    fn clone(&self) -> Self {
+        WeakUnboundedSender {
+            channel: self.channel.clone(),
+        }
+    }
+}
+
+impl<T> WeakUnboundedSender<T> {
+    /// Tries to convert the weak sender into an [`UnboundedSender`]. Returns
+    /// `None` if all senders have been dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::mpsc::unbounded::unbounded_channel;
+    /// let (tx, rx) = unbounded_channel::<i32>();
+    /// let weak = tx.downgrade();
+    /// assert!(weak.upgrade().is_some());
+    /// drop(tx);
+    /// assert!(weak.upgrade().is_none());
+    /// ```
+    pub fn upgrade(&self) -> Option<UnboundedSender<T>> {
+        self.channel.upgrade().map(UnboundedSender::new)
+    }
+}
+
 impl<T> UnboundedReceiver<T> {
     fn new(channel: Rx<Queue<T>>) -> UnboundedReceiver<T> {
         UnboundedReceiver { channel }
@@ -320,6 +390,50 @@ impl<T> UnboundedReceiver<T> {
         poll_fn(|cx| self.channel.poll_recv(cx)).await
     }
 
+    /// Receives values from the associated [`UnboundedSender`] and appends
+    /// them to `buf`, at most `limit` values at a time.
+    ///
+    /// It waits until at least one value is available, then takes the values
+    /// that are already in the channel without waiting for more.
+    ///
+    /// # Return value
+    /// The number of values received, `0` is returned if `limit` is zero or
+    /// all senders have been dropped and no messages remaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::mpsc::unbounded::unbounded_channel;
+    /// async fn io_func() {
+    ///     let (tx, mut rx) = unbounded_channel();
+    ///     for i in 0..3 {
+    ///         tx.send(i).unwrap();
+    ///     }
+    ///     let mut buf = Vec::new();
+    ///     assert_eq!(rx.recv_many(&mut buf, 2).await, 2);
+    ///     assert_eq!(rx.recv_many(&mut buf, 2).await, 1);
+    ///     assert_eq!(buf, vec![0, 1, 2]);
+    /// }
+    /// ```
+    pub async fn recv_many(&mut self, buf: &mut Vec<T>, limit: usize) -> usize {
+        if limit == 0 {
+            return 0;
+        }
+        match self.recv().await {
+            Ok(value) => buf.push(value),
+            Err(_) => return 0,
+        }
+        let mut count = 1;
+        while count < limit {
+            match self.channel.try_recv() {
+                Ok(value) => buf.push(value),
+                Err(_) => break,
+            }
+            count += 1;
+        }
+        count
+    }
+
     /// Attempts to receive a value from the associated [`UnboundedSender`] in a
     /// limited amount of time.
     ///
@@ -353,6 +467,86 @@ impl<T> UnboundedReceiver<T> {
         }
     }
 
+    /// Receives a value from the associated [`UnboundedSender`], blocking the
+    /// current thread until a value arrives.
+    ///
+    /// This method is meant to be used by ordinary threads that communicate
+    /// with asynchronous tasks.
+    ///
+    /// # Return value
+    /// * `Ok(T)` if receiving a value successfully.
+    /// * `Err(RecvError)` if all senders have been dropped or the channel is
+    ///   closed, and no messages remaining.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called in a runtime context, use [`recv`] there instead.
+    ///
+    /// [`recv`]: UnboundedReceiver::recv
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::mpsc::unbounded::unbounded_channel;
+    /// let (tx, mut rx) = unbounded_channel();
+    /// let handle = ylong_runtime::spawn(async move {
+    ///     assert!(tx.send(1).is_ok());
+    /// });
+    /// let thread = std::thread::spawn(move || {
+    ///     assert_eq!(rx.blocking_recv(), Ok(1));
+    /// });
+    /// thread.join().unwrap();
+    /// let _ = ylong_runtime::block_on(handle);
+    /// ```
+    pub fn blocking_recv(&mut self) -> Result<T, RecvError> {
+        blocking_wait(
+            "UnboundedReceiver::blocking_recv",
+            poll_fn(|cx| self.channel.poll_recv(cx)),
+        )
+    }
+
+    /// Receives a value from the associated [`UnboundedSender`], blocking the
+    /// current thread for at most the given amount of time.
+    ///
+    /// # Return value
+    /// * `Ok(T)` if receiving a value successfully.
+    /// * `Err(RecvTimeoutError::Closed)` if all senders have been dropped.
+    /// * `Err(RecvTimeoutError::TimeOut)` if time limit has been passed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called in a runtime context, use [`recv_timeout`] there
+    /// instead.
+    ///
+    /// [`recv_timeout`]: UnboundedReceiver::recv_timeout
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use ylong_runtime::sync::error::RecvTimeoutError;
+    /// use ylong_runtime::sync::mpsc::unbounded::unbounded_channel;
+    /// let (tx, mut rx) = unbounded_channel::<i32>();
+    /// assert_eq!(
+    ///     rx.blocking_recv_timeout(Duration::from_millis(10)),
+    ///     Err(RecvTimeoutError::Timeout)
+    /// );
+    /// tx.send(1).unwrap();
+    /// assert_eq!(rx.blocking_recv_timeout(Duration::from_millis(10)), Ok(1));
+    /// ```
+    #[cfg(feature = "time")]
+    pub fn blocking_recv_timeout(&mut self, time: Duration) -> Result<T, RecvTimeoutError> {
+        match blocking_wait_timeout(
+            "UnboundedReceiver::blocking_recv_timeout",
+            poll_fn(|cx| self.channel.poll_recv(cx)),
+            time,
+        ) {
+            Some(res) => res.map_err(|_| RecvTimeoutError::Closed),
+            None => Err(RecvTimeoutError::Timeout),
+        }
+    }
+
     /// Closes the channel, prevents the `Sender` from sending more values.
     ///
     /// The `Sender` will fail to call [`send`] after the `Receiver` called
@@ -18,11 +18,12 @@ use std::ptr::NonNull;
 use std::sync::atomic::Ordering::{AcqRel, Acquire, Release};
 use std::sync::atomic::{AtomicBool, AtomicPtr, AtomicUsize};
 use std::task::Poll::{Pending, Ready};
-use std::task::{Context, Poll};
+use std::task::{Context, Poll, Waker};
 
 use crate::sync::atomic_waker::AtomicWaker;
 use crate::sync::error::{RecvError, SendError, TryRecvError};
 use crate::sync::mpsc::Container;
+use crate::task::coop;
 
 /// The capacity of a block.
 const CAPACITY: usize = 32;
@@ -216,20 +217,30 @@ impl<T> Queue<T> {
         }
     }
 
-    pub(crate) fn poll_recv(&self, cx: &mut Context<'_>) -> Poll<Result<T, RecvError>> {
-        match self.try_recv() {
-            Ok(val) => return Ready(Ok(val)),
-            Err(TryRecvError::Closed) => return Ready(Err(RecvError)),
-            _ => {}
-        }
+    pub(crate) fn register_rx_waker(&self, waker: &Waker) {
+        self.rx_waker.register_by_ref(waker);
+    }
 
-        self.rx_waker.register_by_ref(cx.waker());
+    pub(crate) fn poll_recv(&self, cx: &mut Context<'_>) -> Poll<Result<T, RecvError>> {
+        let coop = match coop::poll_budget(cx) {
+            Ready(coop) => coop,
+            Pending => return Pending,
+        };
+        let res = match self.try_recv() {
+            Err(TryRecvError::Empty) => {
+                self.rx_waker.register_by_ref(cx.waker());
+                self.try_recv()
+            }
+            res => res,
+        };
 
-        match self.try_recv() {
-            Ok(val) => Ready(Ok(val)),
-            Err(TryRecvError::Closed) => Ready(Err(RecvError)),
-            Err(TryRecvError::Empty) => Pending,
-        }
+        let res = match res {
+            Ok(val) => Ok(val),
+            Err(TryRecvError::Closed) => Err(RecvError),
+            Err(TryRecvError::Empty) => return Pending,
+        };
+        coop.made_progress();
+        Ready(res)
     }
 }
 
@@ -15,11 +15,17 @@
 
 use std::cell::UnsafeCell;
 use std::error::Error;
-use std::fmt;
 use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
+use std::{fmt, mem};
 
 use crate::sync::semaphore_inner::SemaphoreInner;
+cfg_time!(
+    use crate::time::timeout;
+    use std::time::Duration;
+);
 
 /// An async version of [`std::sync::Mutex`]
 ///
@@ -100,6 +106,98 @@ impl<T: ?Sized> Mutex<T> {
         MutexGuard(self)
     }
 
+    /// Locks the mutex within a limited amount of time.
+    ///
+    /// The task waits in the same queue as the ones calling [`Mutex::lock`],
+    /// and gives up its place if the lock is not acquired before the time
+    /// limit.
+    ///
+    /// # Return value
+    /// * `Ok(MutexGuard)` if the lock is acquired in time.
+    /// * `Err(LockError)` if the time limit has been passed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use ylong_runtime::sync::mutex::Mutex;
+    ///
+    /// let handle = ylong_runtime::spawn(async {
+    ///     let lock = Mutex::new(2);
+    ///     let n = lock.lock().await;
+    ///     assert!(lock.lock_timeout(Duration::from_millis(10)).await.is_err());
+    ///     drop(n);
+    ///     assert!(lock.lock_timeout(Duration::from_millis(10)).await.is_ok());
+    /// });
+    /// let _ = ylong_runtime::block_on(handle);
+    /// ```
+    #[cfg(feature = "time")]
+    pub async fn lock_timeout(&self, time: Duration) -> Result<MutexGuard<'_, T>, LockError> {
+        match timeout(time, self.sem.acquire()).await {
+            Ok(res) => {
+                // `Mutex` will not close, so the result of `acquire()` must be `Ok(())`.
+                res.unwrap();
+                Ok(MutexGuard(self))
+            }
+            Err(_) => Err(LockError),
+        }
+    }
+
+    /// Locks the mutex, and returns a guard which holds the `Arc` of the mutex
+    /// instead of borrowing it.
+    ///
+    /// The returned guard is `'static`, so it can be moved into a spawned task.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use ylong_runtime::sync::mutex::Mutex;
+    ///
+    /// let _res = ylong_runtime::block_on(async {
+    ///     let lock = Arc::new(Mutex::new(2));
+    ///     let mut n = lock.clone().lock_owned().await;
+    ///     let handle = ylong_runtime::spawn(async move {
+    ///         *n += 1;
+    ///     });
+    ///     let _ = handle.await;
+    ///     assert_eq!(*lock.lock().await, 3);
+    /// });
+    /// ```
+    pub async fn lock_owned(self: Arc<Self>) -> OwnedMutexGuard<T> {
+        // The result of `acquire()` will be `Err()` only when the semaphore is closed.
+        // `Mutex` will not close, so the result of `acquire()` must be `Ok(())`.
+        self.sem.acquire().await.unwrap();
+        OwnedMutexGuard(self)
+    }
+
+    /// Attempts to get the mutex, and returns a guard which holds the `Arc` of
+    /// the mutex instead of borrowing it.
+    ///
+    /// If the lock is already held by others, LockError will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use ylong_runtime::sync::mutex::Mutex;
+    ///
+    /// let lock = Arc::new(Mutex::new(0));
+    /// let n = lock.clone().try_lock_owned().unwrap();
+    /// assert!(lock.clone().try_lock_owned().is_err());
+    /// drop(n);
+    /// assert!(lock.try_lock_owned().is_ok());
+    /// ```
+    pub fn try_lock_owned(self: Arc<Self>) -> Result<OwnedMutexGuard<T>, LockError> {
+        match self.sem.try_acquire() {
+            Ok(_) => Ok(OwnedMutexGuard(self)),
+            Err(_) => Err(LockError),
+        }
+    }
+
     /// Attempts to get the mutex.
     ///
     /// If the lock is already held by others, LockError will be returned.
@@ -150,6 +248,71 @@ impl<T: ?Sized> MutexGuard<'_, T> {
 
 unsafe impl<T: ?Sized + Send + Sync> Sync for MutexGuard<'_, T> {}
 
+impl<'a, T: ?Sized> MutexGuard<'a, T> {
+    /// Makes a guard for a component of the locked data, the mutex stays
+    /// locked until the new guard is dropped.
+    ///
+    /// This is an associated function, so that it won't conflict with a method
+    /// of the locked data. Use it as `MutexGuard::map(guard, f)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::mutex::{Mutex, MutexGuard};
+    ///
+    /// let lock = Mutex::new((1, 2));
+    /// let mut second = MutexGuard::map(lock.try_lock().unwrap(), |n| &mut n.1);
+    /// *second += 1;
+    /// drop(second);
+    /// assert_eq!(*lock.try_lock().unwrap(), (1, 3));
+    /// ```
+    pub fn map<U, F>(this: Self, f: F) -> MappedMutexGuard<'a, U>
+    where
+        U: ?Sized,
+        F: FnOnce(&mut T) -> &mut U,
+    {
+        let mutex = this.0;
+        let data = f(unsafe { &mut *mutex.data.get() }) as *mut U;
+        // The lock is released by the mapped guard from now on.
+        mem::forget(this);
+        MappedMutexGuard::new(&mutex.sem, data)
+    }
+
+    /// Attempts to make a guard for a component of the locked data. The
+    /// original guard is given back if the closure returns `None`.
+    ///
+    /// This is an associated function, so that it won't conflict with a method
+    /// of the locked data. Use it as `MutexGuard::try_map(guard, f)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::mutex::{Mutex, MutexGuard};
+    ///
+    /// let lock = Mutex::new(vec![1, 2]);
+    /// let guard = lock.try_lock().unwrap();
+    /// let guard = MutexGuard::try_map(guard, |v| v.get_mut(2)).unwrap_err();
+    /// let first = MutexGuard::try_map(guard, |v| v.get_mut(0)).ok().unwrap();
+    /// assert_eq!(*first, 1);
+    /// ```
+    pub fn try_map<U, F>(this: Self, f: F) -> Result<MappedMutexGuard<'a, U>, Self>
+    where
+        U: ?Sized,
+        F: FnOnce(&mut T) -> Option<&mut U>,
+    {
+        let mutex = this.0;
+        match f(unsafe { &mut *mutex.data.get() }) {
+            Some(data) => {
+                let data = data as *mut U;
+                // The lock is released by the mapped guard from now on.
+                mem::forget(this);
+                Ok(MappedMutexGuard::new(&mutex.sem, data))
+            }
+            None => Err(this),
+        }
+    }
+}
+
 /// The mutex will be released after the mutex guard is dropped.
 impl<T: ?Sized> Drop for MutexGuard<'_, T> {
     fn drop(&mut self) {
@@ -182,6 +345,97 @@ impl<T: ?Sized> DerefMut for MutexGuard<'_, T> {
     }
 }
 
+/// Mutex guard which holds the `Arc` of the mutex, created by
+/// [`Mutex::lock_owned`] and [`Mutex::try_lock_owned`].
+pub struct OwnedMutexGuard<T: ?Sized>(Arc<Mutex<T>>);
+
+unsafe impl<T: ?Sized + Send + Sync> Sync for OwnedMutexGuard<T> {}
+
+/// The mutex will be released after the mutex guard is dropped.
+impl<T: ?Sized> Drop for OwnedMutexGuard<T> {
+    fn drop(&mut self) {
+        self.0.sem.release();
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for OwnedMutexGuard<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: ?Sized + Display> Display for OwnedMutexGuard<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&**self, f)
+    }
+}
+
+impl<T: ?Sized> Deref for OwnedMutexGuard<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.0.data.get() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for OwnedMutexGuard<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.0.data.get() }
+    }
+}
+
+/// Mutex guard to access a component of the locked data, created by
+/// [`MutexGuard::map`] and [`MutexGuard::try_map`].
+pub struct MappedMutexGuard<'a, T: ?Sized> {
+    sem: &'a SemaphoreInner,
+    data: *mut T,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T: ?Sized> MappedMutexGuard<'a, T> {
+    fn new(sem: &'a SemaphoreInner, data: *mut T) -> MappedMutexGuard<'a, T> {
+        MappedMutexGuard {
+            sem,
+            data,
+            _marker: PhantomData,
+        }
+    }
+}
+
+unsafe impl<T: ?Sized + Send> Send for MappedMutexGuard<'_, T> {}
+unsafe impl<T: ?Sized + Sync> Sync for MappedMutexGuard<'_, T> {}
+
+/// The mutex will be released after the mutex guard is dropped.
+impl<T: ?Sized> Drop for MappedMutexGuard<'_, T> {
+    fn drop(&mut self) {
+        self.sem.release();
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for MappedMutexGuard<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: ?Sized + Display> Display for MappedMutexGuard<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&**self, f)
+    }
+}
+
+impl<T: ?Sized> Deref for MappedMutexGuard<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.data }
+    }
+}
+
+impl<T: ?Sized> DerefMut for MappedMutexGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.data }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::atomic::{AtomicBool, Ordering};
@@ -286,4 +540,84 @@ mod tests {
             assert!(mutex.try_lock().is_ok());
         });
     }
+
+    /// UT test cases for MutexGuard::map() and MutexGuard::try_map()
+    ///
+    /// # Brief
+    /// 1. Creating a Concurrent Mutual Exclusion Lock holding a tuple
+    /// 2. Call try_map() with a failing closure and check the guard is given
+    ///    back
+    /// 3. Call map() to get a guard of one component and modify it
+    /// 4. Check the mutex is held by the mapped guard and released after it is
+    ///    dropped
+    #[test]
+    fn ut_mutex_map_01() {
+        let mutex = Mutex::new((1, String::from("a")));
+        let lock = mutex.try_lock().unwrap();
+        let lock = MutexGuard::try_map(lock, |_| None::<&mut i32>).unwrap_err();
+        let mut first = MutexGuard::map(lock, |n| &mut n.0);
+        *first += 1;
+        assert!(mutex.try_lock().is_err());
+        drop(first);
+
+        let lock = mutex.try_lock().unwrap();
+        let mut second = MutexGuard::try_map(lock, |n| Some(n.1.as_mut_str()))
+            .ok()
+            .unwrap();
+        second.make_ascii_uppercase();
+        assert_eq!(&*second, "A");
+        drop(second);
+        assert_eq!(*mutex.try_lock().unwrap(), (2, String::from("A")));
+    }
+
+    /// UT test cases for Mutex::lock_owned() and Mutex::try_lock_owned()
+    ///
+    /// # Brief
+    /// 1. Creating a Concurrent Mutual Exclusion Lock inside an Arc
+    /// 2. Get an owned guard and move it into a spawned task
+    /// 3. Check try_lock_owned fails while the task holds the guard
+    /// 4. Check the value modified by the task after the guard is dropped
+    #[test]
+    fn ut_mutex_lock_owned_01() {
+        let mutex = Arc::new(Mutex::new(10));
+        let mut lock = block_on(mutex.clone().lock_owned());
+        assert!(mutex.clone().try_lock_owned().is_err());
+        let handle = spawn(async move {
+            *lock += 1;
+        });
+        block_on(handle).unwrap();
+        let lock = mutex.try_lock_owned().unwrap();
+        assert_eq!(*lock, 11);
+    }
+
+    /// UT test cases for Mutex::lock_timeout() interface
+    ///
+    /// # Brief
+    /// 1. Creating a Concurrent Mutual Exclusion Lock and hold it
+    /// 2. Call lock_timeout() and check it fails after the time limit
+    /// 3. Release the lock while another lock_timeout() is waiting and check it
+    ///    succeeds
+    #[test]
+    #[cfg(feature = "time")]
+    fn ut_mutex_lock_timeout_01() {
+        let mutex = Arc::new(Mutex::new(10));
+        let mutex1 = mutex.clone();
+        let handle = spawn(async move {
+            let lock = mutex1.lock().await;
+            assert!(mutex1
+                .lock_timeout(Duration::from_millis(10))
+                .await
+                .is_err());
+            let mutex2 = mutex1.clone();
+            let waiter = spawn(async move {
+                let mut lock = mutex2.lock_timeout(Duration::from_secs(10)).await.unwrap();
+                *lock += 1;
+            });
+            crate::time::sleep(Duration::from_millis(10)).await;
+            drop(lock);
+            waiter.await.unwrap();
+        });
+        block_on(handle).unwrap();
+        assert_eq!(*mutex.try_lock().unwrap(), 11);
+    }
 }
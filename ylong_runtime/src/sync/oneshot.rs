@@ -51,6 +51,12 @@ use std::task::{Context, Poll};
 
 use super::atomic_waker::AtomicWaker;
 use super::error::{RecvError, TryRecvError};
+use crate::executor::block_on::blocking_wait;
+cfg_time!(
+    use std::time::Duration;
+    use super::error::RecvTimeoutError;
+    use crate::executor::block_on::blocking_wait_timeout;
+);
 
 /// Initial state.
 const INIT: usize = 0b00;
@@ -335,6 +341,77 @@ impl<T> Receiver<T> {
         }
     }
 
+    /// Receives the value from the associated [`Sender`], blocking the current
+    /// thread until it arrives.
+    ///
+    /// This method is meant to be used by ordinary threads that communicate
+    /// with asynchronous tasks.
+    ///
+    /// # Return value
+    /// * `Ok(T)` if receiving the value successfully.
+    /// * `Err(RecvError)` if the sender has been dropped without sending a
+    ///   value, or the channel is closed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called in a runtime context, await the `Receiver` there
+    /// instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::oneshot;
+    /// let (tx, rx) = oneshot::channel();
+    /// let handle = ylong_runtime::spawn(async move {
+    ///     assert!(tx.send("Hello").is_ok());
+    /// });
+    /// let thread = std::thread::spawn(move || {
+    ///     assert_eq!(rx.blocking_recv(), Ok("Hello"));
+    /// });
+    /// thread.join().unwrap();
+    /// let _ = ylong_runtime::block_on(handle);
+    /// ```
+    pub fn blocking_recv(self) -> Result<T, RecvError> {
+        blocking_wait("oneshot::Receiver::blocking_recv", self)
+    }
+
+    /// Receives the value from the associated [`Sender`], blocking the current
+    /// thread for at most the given amount of time.
+    ///
+    /// # Return value
+    /// * `Ok(T)` if receiving the value successfully.
+    /// * `Err(RecvTimeoutError::Closed)` if the sender has been dropped without
+    ///   sending a value, or the channel is closed.
+    /// * `Err(RecvTimeoutError::TimeOut)` if time limit has been passed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called in a runtime context, await the `Receiver` there
+    /// instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use ylong_runtime::sync::error::RecvTimeoutError;
+    /// use ylong_runtime::sync::oneshot;
+    /// let (tx, mut rx) = oneshot::channel();
+    /// assert_eq!(
+    ///     rx.blocking_recv_timeout(Duration::from_millis(10)),
+    ///     Err(RecvTimeoutError::Timeout)
+    /// );
+    /// assert!(tx.send(1).is_ok());
+    /// assert_eq!(rx.blocking_recv_timeout(Duration::from_millis(10)), Ok(1));
+    /// ```
+    #[cfg(feature = "time")]
+    pub fn blocking_recv_timeout(&mut self, time: Duration) -> Result<T, RecvTimeoutError> {
+        match blocking_wait_timeout("oneshot::Receiver::blocking_recv_timeout", self, time) {
+            Some(res) => res.map_err(|_| RecvTimeoutError::Closed),
+            None => Err(RecvTimeoutError::Timeout),
+        }
+    }
+
     /// Closes the channel, prevents the `Sender` from sending a value.
     ///
     /// The `Sender` will fail to call [`send`] after the `Receiver` called
@@ -462,7 +539,7 @@ impl<T: Debug> Debug for Channel<T> {
 #[cfg(test)]
 mod tests {
     use crate::spawn;
-    use crate::sync::error::TryRecvError;
+    use crate::sync::error::{RecvError, TryRecvError};
     use crate::sync::oneshot;
 
     /// UT test cases for `send()` and `try_recv()`.
@@ -529,4 +606,63 @@ mod tests {
         let msg = rx.try_recv().unwrap();
         assert_eq!(msg, "will receive");
     }
+
+    /// UT test cases for `blocking_recv()`.
+    ///
+    /// # Brief
+    /// 1. Call channel to create a sender and a receiver handle pair.
+    /// 2. Receive the message on a plain thread while a task sends it.
+    /// 3. Receive on a plain thread after the sender drops without sending.
+    #[test]
+    fn blocking_recv() {
+        let (tx, rx) = oneshot::channel();
+        let thread = std::thread::spawn(move || rx.blocking_recv());
+        let handle = spawn(async move {
+            tx.send("hello").unwrap();
+        });
+        assert_eq!(thread.join().unwrap(), Ok("hello"));
+        let _ = crate::block_on(handle);
+
+        let (tx, rx) = oneshot::channel::<i32>();
+        let thread = std::thread::spawn(move || rx.blocking_recv());
+        drop(tx);
+        assert_eq!(thread.join().unwrap(), Err(RecvError));
+    }
+
+    /// UT test cases for `blocking_recv()` inside a runtime.
+    ///
+    /// # Brief
+    /// 1. Call channel to create a sender and a receiver handle pair.
+    /// 2. Call `blocking_recv` inside a task.
+    /// 3. Check the task panics.
+    #[test]
+    fn blocking_recv_in_runtime() {
+        let (_tx, rx) = oneshot::channel::<i32>();
+        let handle = spawn(async move {
+            let _ = rx.blocking_recv();
+        });
+        assert!(crate::block_on(handle).is_err());
+    }
+
+    /// UT test cases for `blocking_recv_timeout()`.
+    ///
+    /// # Brief
+    /// 1. Call channel to create a sender and a receiver handle pair.
+    /// 2. Receive with a time limit before the sender sends a message.
+    /// 3. Receive with a time limit after the sender sends a message.
+    #[test]
+    #[cfg(feature = "time")]
+    fn blocking_recv_timeout() {
+        use std::time::Duration;
+
+        use crate::sync::error::RecvTimeoutError;
+
+        let (tx, mut rx) = oneshot::channel();
+        assert_eq!(
+            rx.blocking_recv_timeout(Duration::from_millis(10)),
+            Err(RecvTimeoutError::Timeout)
+        );
+        tx.send(1).unwrap();
+        assert_eq!(rx.blocking_recv_timeout(Duration::from_millis(10)), Ok(1));
+    }
 }
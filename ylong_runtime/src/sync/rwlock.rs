@@ -14,10 +14,11 @@
 //! An asynchronous version of [`std::sync::RwLock`]
 
 use std::cell::UnsafeCell;
-use std::fmt;
 use std::ops::{Deref, DerefMut};
 use std::sync::atomic::AtomicI64;
 use std::sync::atomic::Ordering::{AcqRel, Acquire, Release};
+use std::sync::Arc;
+use std::{fmt, mem, ptr};
 
 use crate::sync::semaphore_inner::SemaphoreInner;
 use crate::sync::LockError;
@@ -112,12 +113,50 @@ impl<T: ?Sized> RwLock<T> {
     /// });
     /// ```
     pub async fn read(&self) -> RwLockReadGuard<'_, T> {
+        self.acquire_read().await;
+        RwLockReadGuard(self)
+    }
+
+    /// Asynchronously acquires the read lock, and returns a guard which holds
+    /// the `Arc` of the lock instead of borrowing it.
+    ///
+    /// The returned guard is `'static`, so it can be moved into a spawned task.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use ylong_runtime::sync::rwlock::RwLock;
+    ///
+    /// ylong_runtime::block_on(async {
+    ///     let lock = Arc::new(RwLock::new(0));
+    ///     let r1 = lock.clone().read_owned().await;
+    ///     let handle = ylong_runtime::spawn(async move {
+    ///         assert_eq!(*r1, 0);
+    ///     });
+    ///     let _ = handle.await;
+    /// });
+    /// ```
+    pub async fn read_owned(self: Arc<Self>) -> OwnedRwLockReadGuard<T> {
+        self.acquire_read().await;
+        OwnedRwLockReadGuard(self)
+    }
+
+    async fn acquire_read(&self) {
         if self.read_count.fetch_add(1, Release) < 0 {
             // The result of `acquire()` will be `Err()` only when the semaphore is closed.
             // `RwLock` will not close, so the result of `acquire()` must be `Ok(())`.
             self.read_sem.acquire().await.unwrap();
         }
-        RwLockReadGuard(self)
+    }
+
+    // Releases the read lock. Wakes any waiting writer if it's the last one
+    // holding the read lock.
+    fn release_read(&self) {
+        if self.read_count.fetch_sub(1, Release) < 0 && self.read_wait.fetch_sub(1, Release) == 1 {
+            self.write_sem.release();
+        }
     }
 
     /// Attempts to get the read lock. If another writer is holding the write
@@ -167,6 +206,38 @@ impl<T: ?Sized> RwLock<T> {
     /// });
     /// ```
     pub async fn write(&self) -> RwLockWriteGuard<'_, T> {
+        self.acquire_write().await;
+        RwLockWriteGuard(self)
+    }
+
+    /// Asynchronously acquires the write lock, and returns a guard which holds
+    /// the `Arc` of the lock instead of borrowing it.
+    ///
+    /// The returned guard is `'static`, so it can be moved into a spawned task.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use ylong_runtime::sync::rwlock::RwLock;
+    ///
+    /// ylong_runtime::block_on(async {
+    ///     let lock = Arc::new(RwLock::new(0));
+    ///     let mut w = lock.clone().write_owned().await;
+    ///     let handle = ylong_runtime::spawn(async move {
+    ///         *w += 1;
+    ///     });
+    ///     let _ = handle.await;
+    ///     assert_eq!(*lock.read().await, 1);
+    /// });
+    /// ```
+    pub async fn write_owned(self: Arc<Self>) -> OwnedRwLockWriteGuard<T> {
+        self.acquire_write().await;
+        OwnedRwLockWriteGuard(self)
+    }
+
+    async fn acquire_write(&self) {
         // The result of `acquire()` will be `Err()` only when the semaphore is closed.
         // `RwLock` will not close, so the result of `acquire()` must be `Ok(())`.
         self.write_mutex.acquire().await.unwrap();
@@ -177,7 +248,21 @@ impl<T: ?Sized> RwLock<T> {
         if read_count >= 0 && self.read_wait.fetch_add(read_count, Release) != -read_count {
             self.write_sem.acquire().await.unwrap();
         }
-        RwLockWriteGuard(self)
+    }
+
+    // Wakes all waiting readers first and releases the write lock.
+    fn release_write(&self) {
+        let read_count = self.read_count.fetch_add(MAX_READS, Release) + MAX_READS;
+        self.read_sem.release_multi(read_count as usize);
+        self.write_mutex.release();
+    }
+
+    // Turns the write lock into a read lock held by the caller, and wakes all
+    // waiting readers.
+    fn downgrade_write(&self) {
+        let read_count = self.read_count.fetch_add(MAX_READS + 1, Release) + MAX_READS;
+        self.read_sem.release_multi(read_count as usize);
+        self.write_mutex.release();
     }
 
     /// Attempts to acquire the write lock.
@@ -252,22 +337,10 @@ pub struct RwLockReadGuard<'a, T: ?Sized>(&'a RwLock<T>);
 unsafe impl<T: ?Sized + Send> Send for RwLockReadGuard<'_, T> {}
 unsafe impl<T: ?Sized + Sync> Sync for RwLockReadGuard<'_, T> {}
 
-/// Releases the read lock. Wakes any waiting writer if it's the last one
-/// holding the read lock.
-impl<T: ?Sized> RwLockReadGuard<'_, T> {
-    fn unlock(&mut self) {
-        if self.0.read_count.fetch_sub(1, Release) < 0
-            && self.0.read_wait.fetch_sub(1, Release) == 1
-        {
-            self.0.write_sem.release();
-        }
-    }
-}
-
 /// Unlock the read lock when ReadGuard is dropped.
 impl<T: ?Sized> Drop for RwLockReadGuard<'_, T> {
     fn drop(&mut self) {
-        self.unlock();
+        self.0.release_read();
     }
 }
 
@@ -301,9 +374,36 @@ unsafe impl<T: ?Sized + Sync> Sync for RwLockWriteGuard<'_, T> {}
 /// is dropped.
 impl<T: ?Sized> Drop for RwLockWriteGuard<'_, T> {
     fn drop(&mut self) {
-        let read_count = self.0.read_count.fetch_add(MAX_READS, Release) + MAX_READS;
-        self.0.read_sem.release_multi(read_count as usize);
-        self.0.write_mutex.release();
+        self.0.release_write();
+    }
+}
+
+impl<'a, T: ?Sized> RwLockWriteGuard<'a, T> {
+    /// Turns the write lock into a read lock without releasing it, so no other
+    /// writer can get the lock in between. Readers waiting for the lock are
+    /// woken at the same time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::rwlock::RwLock;
+    ///
+    /// ylong_runtime::block_on(async {
+    ///     let lock = RwLock::new(0);
+    ///     let mut w = lock.write().await;
+    ///     *w += 1;
+    ///     let r1 = w.downgrade();
+    ///     assert!(lock.try_write().is_err());
+    ///     let r2 = lock.try_read().unwrap();
+    ///     assert_eq!(*r1, 1);
+    ///     assert_eq!(*r2, 1);
+    /// });
+    /// ```
+    pub fn downgrade(self) -> RwLockReadGuard<'a, T> {
+        let lock = self.0;
+        lock.downgrade_write();
+        mem::forget(self);
+        RwLockReadGuard(lock)
     }
 }
 
@@ -332,6 +432,103 @@ impl<T: ?Sized> DerefMut for RwLockWriteGuard<'_, T> {
     }
 }
 
+/// Read guard which holds the `Arc` of the lock, created by
+/// [`RwLock::read_owned`].
+pub struct OwnedRwLockReadGuard<T: ?Sized>(Arc<RwLock<T>>);
+
+/// Unlock the read lock when ReadGuard is dropped.
+impl<T: ?Sized> Drop for OwnedRwLockReadGuard<T> {
+    fn drop(&mut self) {
+        self.0.release_read();
+    }
+}
+
+impl<T: ?Sized> Deref for OwnedRwLockReadGuard<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.0.data.get() }
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for OwnedRwLockReadGuard<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: ?Sized + fmt::Display> fmt::Display for OwnedRwLockReadGuard<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
+    }
+}
+
+/// Write guard which holds the `Arc` of the lock, created by
+/// [`RwLock::write_owned`].
+pub struct OwnedRwLockWriteGuard<T: ?Sized>(Arc<RwLock<T>>);
+
+/// Wakes all waiting readers first and releases the write lock when WriteGuard
+/// is dropped.
+impl<T: ?Sized> Drop for OwnedRwLockWriteGuard<T> {
+    fn drop(&mut self) {
+        self.0.release_write();
+    }
+}
+
+impl<T: ?Sized> OwnedRwLockWriteGuard<T> {
+    /// Turns the write lock into a read lock without releasing it, so no other
+    /// writer can get the lock in between.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use ylong_runtime::sync::rwlock::RwLock;
+    ///
+    /// ylong_runtime::block_on(async {
+    ///     let lock = Arc::new(RwLock::new(0));
+    ///     let mut w = lock.clone().write_owned().await;
+    ///     *w += 1;
+    ///     let r = w.downgrade();
+    ///     assert!(lock.try_write().is_err());
+    ///     assert_eq!(*r, 1);
+    /// });
+    /// ```
+    pub fn downgrade(self) -> OwnedRwLockReadGuard<T> {
+        // Moves the `Arc` out, the guard is forgotten so it is not dropped twice.
+        let lock = unsafe { ptr::read(&self.0) };
+        mem::forget(self);
+        lock.downgrade_write();
+        OwnedRwLockReadGuard(lock)
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for OwnedRwLockWriteGuard<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: ?Sized + fmt::Display> fmt::Display for OwnedRwLockWriteGuard<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
+    }
+}
+
+impl<T: ?Sized> Deref for OwnedRwLockWriteGuard<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.0.data.get() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for OwnedRwLockWriteGuard<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.0.data.get() }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
@@ -529,4 +726,57 @@ mod tests {
         let lock = RwLock::new(10);
         assert_eq!(lock.into_inner(), 10);
     }
+
+    /// UT test cases for RwLockWriteGuard::downgrade()
+    ///
+    /// # Brief
+    /// 1. Creating a concurrent read/write lock and hold the write lock
+    /// 2. Downgrade the write guard to a read guard
+    /// 3. Check other readers can get the lock but writers can't
+    /// 4. Check writers can get the lock after all readers are dropped
+    #[test]
+    fn ut_rwlock_downgrade_01() {
+        let lock = RwLock::new(1);
+        let mut w = lock.try_write().unwrap();
+        *w += 1;
+        let r1 = w.downgrade();
+        assert!(lock.try_write().is_err());
+        let r2 = lock.try_read().unwrap();
+        assert_eq!(*r1, 2);
+        assert_eq!(*r2, 2);
+        drop(r1);
+        assert!(lock.try_write().is_err());
+        drop(r2);
+        assert!(lock.try_write().is_ok());
+    }
+
+    /// UT test cases for Rwlock::read_owned() and Rwlock::write_owned()
+    ///
+    /// # Brief
+    /// 1. Creating a concurrent read/write lock inside an Arc
+    /// 2. Get an owned write guard and move it into a spawned task to modify
+    ///    the value
+    /// 3. Get an owned read guard and downgrade an owned write guard, check the
+    ///    value through them
+    #[test]
+    fn ut_rwlock_owned_01() {
+        let lock = Arc::new(RwLock::new(1));
+        let mut w = block_on(lock.clone().write_owned());
+        assert!(lock.try_read().is_err());
+        let handle = spawn(async move {
+            *w += 1;
+        });
+        block_on(handle).unwrap();
+
+        let r1 = block_on(lock.clone().read_owned());
+        assert!(lock.try_write().is_err());
+        drop(r1);
+        let w = block_on(lock.clone().write_owned());
+        let r2 = w.downgrade();
+        assert!(lock.try_write().is_err());
+        assert_eq!(*r2, 2);
+        assert_eq!(*lock.try_read().unwrap(), 2);
+        drop(r2);
+        assert!(lock.try_write().is_ok());
+    }
 }
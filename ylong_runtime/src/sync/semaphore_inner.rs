@@ -22,6 +22,7 @@ use std::task::Poll::{Pending, Ready};
 use std::task::{Context, Poll};
 
 use crate::sync::wake_list::{ListItem, WakerList};
+use crate::task::coop;
 
 /// Maximum capacity of `Semaphore`.
 const MAX_PERMITS: usize = usize::MAX >> 1;
@@ -190,6 +191,11 @@ impl SemaphoreInner {
         if curr & CLOSED == CLOSED {
             return Ready(Err(SemaphoreError::Closed));
         } else if *enqueue {
+            // The future may be polled again before it gets notified, e.g. by a
+            // timeout, so it only completes once a permit has been assigned.
+            if wait_permit.load(Acquire) != 0 {
+                return Pending;
+            }
             *enqueue = false;
             return Ready(Ok(()));
         }
@@ -240,6 +246,11 @@ impl Future for Permit<'_> {
     type Output = Result<(), SemaphoreError>;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let coop = match coop::poll_budget(cx) {
+            Ready(coop) => coop,
+            Pending => return Pending,
+        };
+
         let (semaphore, waker_index, enqueue, wait_permit) = unsafe {
             let me = self.get_unchecked_mut();
             (
@@ -250,7 +261,11 @@ impl Future for Permit<'_> {
             )
         };
 
-        semaphore.poll_acquire(cx, waker_index, enqueue, wait_permit)
+        let res = semaphore.poll_acquire(cx, waker_index, enqueue, wait_permit);
+        if res.is_ready() {
+            coop.made_progress();
+        }
+        res
     }
 }
 
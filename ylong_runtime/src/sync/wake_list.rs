@@ -68,6 +68,12 @@ impl WakerList {
         list.wake_list.push_back(waker)
     }
 
+    /// Gets the number of wakers inside the list.
+    #[cfg(test)]
+    pub(crate) fn len(&self) -> usize {
+        self.lock().wake_list.len
+    }
+
     /// Removes the waker corresponding to the key.
     #[allow(dead_code)]
     pub fn remove(&self, key: usize) -> Result<ListItem, SlotsError> {
@@ -174,6 +180,40 @@ pub(crate) struct Lock<'a> {
 }
 
 impl Lock<'_> {
+    /// Pushes a waker into the list and return its index in the list.
+    pub(crate) fn insert(&mut self, waker: ListItem) -> usize {
+        self.wake_list.push_back(waker)
+    }
+
+    /// Replaces the waker of the member corresponding to the key in place.
+    /// Returns false if the member has been woken up and left the list.
+    pub(crate) fn update_waker(
+        &mut self,
+        key: usize,
+        wait_permit: &Arc<AtomicUsize>,
+        waker: &Waker,
+    ) -> bool {
+        match self.wake_list.get_mut_by_index(key) {
+            // the key might have been reused by another member
+            Some(list_item) if Arc::ptr_eq(&list_item.wait_permit, wait_permit) => {
+                if !list_item.wake.will_wake(waker) {
+                    list_item.wake = waker.clone();
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Removes the member corresponding to the key if it's still waiting.
+    pub(crate) fn remove_waiting(&mut self, key: usize, wait_permit: &Arc<AtomicUsize>) {
+        if let Some(list_item) = self.wake_list.get_by_index(key) {
+            if Arc::ptr_eq(&list_item.wait_permit, wait_permit) {
+                let _ = self.wake_list.remove(key);
+            }
+        }
+    }
+
     pub(crate) fn remove_permit(&mut self, key: usize, wait_permit: usize) -> bool {
         if let Some(list_item) = self.wake_list.get_by_index(key) {
             let inner_wait_permit = list_item.get_wait_permit();
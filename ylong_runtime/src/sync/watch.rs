@@ -21,6 +21,7 @@ use std::sync::{Arc, RwLock, RwLockReadGuard};
 use std::task::Poll::{Pending, Ready};
 use std::task::{Context, Poll};
 
+use crate::executor::block_on::blocking_wait;
 use crate::futures::poll_fn;
 use crate::sync::error::{RecvError, SendError};
 use crate::sync::wake_list::{ListItem, WakerList};
@@ -404,6 +405,42 @@ impl<T> Receiver<T> {
         poll_fn(|cx| self.poll_notified(cx)).await
     }
 
+    /// Blocks the current thread until the value in the channel has not yet
+    /// been seen, then marks it as seen.
+    ///
+    /// This method is meant to be used by ordinary threads that communicate
+    /// with asynchronous tasks.
+    ///
+    /// # Return value
+    /// * `Ok(())` if receiving a new value or the value in channel has not yet
+    ///   seen.
+    /// * `Err(RecvError)` The sender has been dropped or the channel is closed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called in a runtime context, use [`notified`] there instead.
+    ///
+    /// [`notified`]: Receiver::notified
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::sync::watch;
+    /// let (tx, mut rx) = watch::channel(1);
+    /// let thread = std::thread::spawn(move || {
+    ///     assert!(rx.blocking_notified().is_ok());
+    ///     assert_eq!(*rx.borrow(), 2);
+    /// });
+    /// let _ = tx.send(2);
+    /// thread.join().unwrap();
+    /// ```
+    pub fn blocking_notified(&mut self) -> Result<(), RecvError> {
+        blocking_wait(
+            "watch::Receiver::blocking_notified",
+            poll_fn(|cx| self.poll_notified(cx)),
+        )
+    }
+
     /// Gets a reference to the inner value.
     ///
     /// This method doesn't mark the value as seen, which means call to
@@ -665,4 +702,30 @@ mod tests {
         drop(rx3);
         assert_eq!(tx.channel.rx_cnt.load(Acquire), 0);
     }
+
+    /// UT test cases for `blocking_notified()`.
+    ///
+    /// # Brief
+    /// 1. Call channel to create a sender and a receiver handle pair.
+    /// 2. Wait for a change notification on a plain thread and send a value.
+    /// 3. Wait for a change notification on a plain thread and drop the sender.
+    /// 4. Call `blocking_notified` inside a task and check the task panics.
+    #[test]
+    fn blocking_notified() {
+        let (tx, mut rx) = watch::channel(1);
+        let mut rx2 = rx.clone();
+        let thread = std::thread::spawn(move || {
+            assert_eq!(rx.blocking_notified(), Ok(()));
+            assert_eq!(*rx.borrow(), 2);
+            assert_eq!(rx.blocking_notified(), Err(RecvError));
+        });
+        assert!(tx.send(2).is_ok());
+        drop(tx);
+        thread.join().unwrap();
+
+        let handle = spawn(async move {
+            let _ = rx2.blocking_notified();
+        });
+        assert!(block_on(handle).is_err());
+    }
 }
@@ -17,6 +17,7 @@
 //! A task has following attributes:
 //! - qos
 //! - task name
+//! - whether to cancel the task when its JoinHandle is dropped
 
 use std::future::Future;
 
@@ -28,6 +29,7 @@ use crate::task::{JoinHandle, Qos};
 pub struct TaskBuilder {
     pub(crate) name: Option<String>,
     pub(crate) qos: Option<Qos>,
+    pub(crate) abort_on_drop: bool,
 }
 
 impl Default for TaskBuilder {
@@ -42,6 +44,7 @@ impl TaskBuilder {
         TaskBuilder {
             name: None,
             qos: None,
+            abort_on_drop: false,
         }
     }
 
@@ -57,6 +60,16 @@ impl TaskBuilder {
         self
     }
 
+    /// Sets whether to cancel the task when its [`JoinHandle`] is dropped. By
+    /// default it's false, and the task keeps executing in the background.
+    ///
+    /// A task spawned with it set could still be detached from its handle by
+    /// [`JoinHandle::detach`].
+    pub fn abort_on_drop(mut self, abort_on_drop: bool) -> Self {
+        self.abort_on_drop = abort_on_drop;
+        self
+    }
+
     /// todo: for multiple-instance runtime, should provide a spawn_on
     /// Using the current task setting, spawns a task onto the global runtime.
     pub fn spawn<T, R>(&self, task: T) -> JoinHandle<R>
@@ -0,0 +1,328 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Cooperative scheduling of tasks.
+//!
+//! Each poll of a task is given a budget. Operations on IO resources,
+//! channels, locks and timers consume the budget, and once it is used up,
+//! these operations return `Pending` and the task gets rescheduled. In this
+//! way, a task whose resources are always ready could not occupy a worker
+//! forever.
+
+use std::cell::Cell;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use crate::task::yield_now::wake_yielded;
+
+/// Default budget of each poll of a task.
+pub(crate) const DEFAULT_BUDGET: u32 = 128;
+
+thread_local! {
+    // Remaining budget of the task being polled on the current thread.
+    // `None` means the task is unconstrained.
+    static CURRENT_BUDGET: Cell<Option<u32>> = const { Cell::new(None) };
+    // Budget configured by the runtime whose task is being polled on the
+    // current thread. `None` means no runtime task is being polled.
+    static TASK_BUDGET: Cell<Option<Option<u32>>> = const { Cell::new(None) };
+}
+
+struct BudgetGuard(Option<u32>);
+
+impl Drop for BudgetGuard {
+    fn drop(&mut self) {
+        CURRENT_BUDGET.with(|cur| cur.set(self.0));
+    }
+}
+
+/// Runs the closure with the given budget. The previous budget of the thread
+/// gets restored afterwards.
+pub(crate) fn with_budget<R>(budget: Option<u32>, f: impl FnOnce() -> R) -> R {
+    let prev = CURRENT_BUDGET.with(|cur| cur.replace(budget));
+    let _guard = BudgetGuard(prev);
+    f()
+}
+
+/// Runs a task of the runtime with the budget configured by the runtime. The
+/// tasks driven inside it, such as the children of a scope, get the same
+/// budget through [`task_budget`].
+#[cfg_attr(feature = "ffrt", allow(unused))]
+pub(crate) fn with_task_budget<R>(budget: Option<u32>, f: impl FnOnce() -> R) -> R {
+    let prev = TASK_BUDGET.with(|cur| cur.replace(Some(budget)));
+    let _guard = TaskBudgetGuard(prev);
+    with_budget(budget, f)
+}
+
+#[cfg_attr(feature = "ffrt", allow(unused))]
+struct TaskBudgetGuard(Option<Option<u32>>);
+
+impl Drop for TaskBudgetGuard {
+    fn drop(&mut self) {
+        TASK_BUDGET.with(|cur| cur.set(self.0));
+    }
+}
+
+/// Gets the budget configured by the runtime whose task is being polled on the
+/// current thread, or the default budget if there is no such runtime.
+#[cfg_attr(feature = "ffrt", allow(unused))]
+pub(crate) fn task_budget() -> Option<u32> {
+    TASK_BUDGET
+        .with(|cur| cur.get())
+        .unwrap_or(Some(DEFAULT_BUDGET))
+}
+
+/// Consumes one unit of the current budget.
+///
+/// If the budget is used up, the task gets woken for a reschedule and
+/// `Pending` is returned. Otherwise, the returned guard gives the unit back
+/// when dropped, unless [`RestoreOnPending::made_progress`] is called. In this
+/// way, operations returning `Pending` do not use up the budget.
+#[cfg_attr(
+    not(any(feature = "net", feature = "sync", feature = "time")),
+    allow(unused)
+)]
+pub(crate) fn poll_budget(cx: &mut Context<'_>) -> Poll<RestoreOnPending> {
+    let consumed = CURRENT_BUDGET.with(|cur| match cur.get() {
+        Some(0) => None,
+        Some(remaining) => {
+            cur.set(Some(remaining - 1));
+            Some(true)
+        }
+        None => Some(false),
+    });
+
+    match consumed {
+        Some(consumed) => Poll::Ready(RestoreOnPending(Cell::new(consumed))),
+        None => {
+            wake_yielded(cx);
+            Poll::Pending
+        }
+    }
+}
+
+/// Guard returned by [`poll_budget`], which gives the consumed unit back to the
+/// budget when dropped, unless the operation has made progress.
+#[cfg_attr(
+    not(any(feature = "net", feature = "sync", feature = "time")),
+    allow(unused)
+)]
+pub(crate) struct RestoreOnPending(Cell<bool>);
+
+#[cfg_attr(
+    not(any(feature = "net", feature = "sync", feature = "time")),
+    allow(unused)
+)]
+impl RestoreOnPending {
+    /// Keeps the consumed unit, which should be called once the operation
+    /// returns `Ready`.
+    pub(crate) fn made_progress(&self) {
+        self.0.set(false);
+    }
+}
+
+impl Drop for RestoreOnPending {
+    fn drop(&mut self) {
+        if self.0.get() {
+            CURRENT_BUDGET.with(|cur| {
+                if let Some(remaining) = cur.get() {
+                    cur.set(Some(remaining + 1));
+                }
+            });
+        }
+    }
+}
+
+/// Future returned by [`unconstrained`].
+pub struct Unconstrained<F> {
+    future: F,
+}
+
+impl<F: Future> Future for Unconstrained<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let future = unsafe { self.map_unchecked_mut(|me| &mut me.future) };
+        with_budget(None, || future.poll(cx))
+    }
+}
+
+/// Turns off the cooperative budgeting for the given future.
+///
+/// Operations inside the future never get forced to yield because of the
+/// budget, so the future could starve other tasks on the same worker. Use it
+/// with care.
+///
+/// # Examples
+///
+/// ```
+/// use ylong_runtime::sync::mpsc::unbounded::unbounded_channel;
+/// use ylong_runtime::task::unconstrained;
+///
+/// let handle = ylong_runtime::spawn(unconstrained(async {
+///     let (tx, mut rx) = unbounded_channel();
+///     for i in 0..1000 {
+///         tx.send(i).unwrap();
+///     }
+///     // receives all the values in one poll
+///     for i in 0..1000 {
+///         assert_eq!(rx.recv().await, Ok(i));
+///     }
+/// }));
+/// ylong_runtime::block_on(handle).unwrap();
+/// ```
+pub fn unconstrained<F: Future>(future: F) -> Unconstrained<F> {
+    Unconstrained { future }
+}
+
+#[cfg(test)]
+mod test {
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    use crate::futures::poll_fn;
+    use crate::task::coop::{
+        poll_budget, task_budget, unconstrained, with_budget, with_task_budget, CURRENT_BUDGET,
+        DEFAULT_BUDGET,
+    };
+
+    struct Budgeted(usize);
+
+    impl Future for Budgeted {
+        type Output = usize;
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            for _ in 0..10 {
+                match poll_budget(cx) {
+                    Poll::Ready(coop) => coop.made_progress(),
+                    Poll::Pending => return Poll::Pending,
+                }
+                self.0 += 1;
+            }
+            Poll::Ready(self.0)
+        }
+    }
+
+    /// UT test cases for `poll_budget`
+    ///
+    /// # Brief
+    /// 1. Poll a future consuming 10 units of budget with a budget of 5
+    /// 2. Check that the future returns pending after consuming the budget
+    /// 3. Poll the future with no budget, check that it finishes
+    /// 4. Poll the future inside `unconstrained` with a budget of 5, check
+    ///    that it finishes
+    #[test]
+    fn ut_coop_poll_budget() {
+        crate::block_on(poll_fn(|cx| {
+            let mut fut = Budgeted(0);
+            let res = with_budget(Some(5), || Pin::new(&mut fut).poll(cx));
+            assert!(res.is_pending());
+            assert_eq!(fut.0, 5);
+
+            let res = with_budget(None, || Pin::new(&mut fut).poll(cx));
+            assert_eq!(res, Poll::Ready(15));
+
+            let mut fut = unconstrained(Budgeted(0));
+            let res = with_budget(Some(5), || Pin::new(&mut fut).poll(cx));
+            assert_eq!(res, Poll::Ready(10));
+            Poll::Ready(())
+        }));
+    }
+
+    /// UT test cases for `RestoreOnPending`
+    ///
+    /// # Brief
+    /// 1. Consume the budget without making progress, check that the unit is
+    ///    given back
+    /// 2. Consume the budget and make progress, check that the unit is kept
+    /// 3. Consume the budget without any limit, check that the budget is still
+    ///    unlimited
+    #[test]
+    fn ut_coop_restore_on_pending() {
+        fn remaining() -> Option<u32> {
+            CURRENT_BUDGET.with(|cur| cur.get())
+        }
+
+        crate::block_on(poll_fn(|cx| {
+            with_budget(Some(5), || {
+                match poll_budget(cx) {
+                    Poll::Ready(_coop) => assert_eq!(remaining(), Some(4)),
+                    Poll::Pending => unreachable!(),
+                };
+                assert_eq!(remaining(), Some(5));
+
+                match poll_budget(cx) {
+                    Poll::Ready(coop) => coop.made_progress(),
+                    Poll::Pending => unreachable!(),
+                };
+                assert_eq!(remaining(), Some(4));
+            });
+            with_budget(None, || {
+                assert!(poll_budget(cx).is_ready());
+                assert_eq!(remaining(), None);
+            });
+            Poll::Ready(())
+        }));
+    }
+
+    /// UT test cases for `task_budget`
+    ///
+    /// # Brief
+    /// 1. Check that the default budget is returned outside a runtime task
+    /// 2. Check that the budget configured by the runtime is returned inside a
+    ///    runtime task, even if the current budget is changed
+    /// 3. Poll a scope as a runtime task with a budget of 5, check that its
+    ///    child gets the same budget
+    /// 4. Poll a scope outside a runtime task, check that its child gets the
+    ///    default budget
+    #[cfg(not(feature = "ffrt"))]
+    #[test]
+    fn ut_coop_task_budget() {
+        use std::sync::atomic::AtomicUsize;
+        use std::sync::atomic::Ordering::{Acquire, Release};
+
+        assert_eq!(task_budget(), Some(DEFAULT_BUDGET));
+        with_task_budget(Some(5), || {
+            assert_eq!(task_budget(), Some(5));
+            with_budget(None, || assert_eq!(task_budget(), Some(5)));
+        });
+        with_task_budget(None, || assert_eq!(task_budget(), None));
+        assert_eq!(task_budget(), Some(DEFAULT_BUDGET));
+
+        fn child_budget(budget: Option<Option<u32>>) -> usize {
+            let consumed = AtomicUsize::new(0);
+            let consumed_ref = &consumed;
+            let mut scoped = Box::pin(crate::task::scope(|s| async move {
+                let child = s.spawn(poll_fn(|cx| {
+                    let mut n = 0;
+                    while let Poll::Ready(coop) = poll_budget(cx) {
+                        coop.made_progress();
+                        n += 1;
+                    }
+                    consumed_ref.store(n, Release);
+                    Poll::Ready(())
+                }));
+                child.await.unwrap();
+            }));
+            crate::block_on(poll_fn(|cx| match budget {
+                Some(budget) => with_task_budget(budget, || scoped.as_mut().poll(cx)),
+                None => scoped.as_mut().poll(cx),
+            }));
+            drop(scoped);
+            consumed.load(Acquire)
+        }
+        assert_eq!(child_budget(Some(Some(5))), 5);
+        assert_eq!(child_budget(None), DEFAULT_BUDGET as usize);
+    }
+}
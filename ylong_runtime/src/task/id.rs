@@ -0,0 +1,68 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering::Relaxed;
+
+/// An opaque ID that uniquely identifies a task among all the tasks spawned
+/// during the lifetime of the process.
+///
+/// IDs are never reused, even after the task with the ID completes.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct TaskId(u64);
+
+impl TaskId {
+    /// Generates a new unique ID.
+    pub(crate) fn next() -> TaskId {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+        TaskId(NEXT_ID.fetch_add(1, Relaxed))
+    }
+
+    /// Returns the ID as an integer.
+    pub fn as_u64(&self) -> u64 {
+        self.0
+    }
+}
+
+impl fmt::Display for TaskId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::task::TaskId;
+
+    /// UT test cases for `TaskId::next`
+    ///
+    /// # Brief
+    /// 1. Generate IDs from several threads
+    /// 2. Check that all of them are different and none of them is zero
+    #[test]
+    fn ut_task_id_unique() {
+        let handles: Vec<_> = (0..4)
+            .map(|_| std::thread::spawn(|| (0..100).map(|_| TaskId::next()).collect::<Vec<_>>()))
+            .collect();
+        let mut ids: Vec<_> = handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), 400);
+        assert!(ids.iter().all(|id| id.as_u64() != 0));
+        assert_eq!(ids[0].to_string(), ids[0].as_u64().to_string());
+    }
+}
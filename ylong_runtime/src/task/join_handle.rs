@@ -23,7 +23,7 @@ use std::task::{Context, Poll, Waker};
 
 use crate::error::ScheduleError;
 use crate::task::raw::RawTask;
-use crate::task::state;
+use crate::task::{state, TaskBuilder, TaskId};
 
 /// A handle to the actual spawned task.
 ///
@@ -33,7 +33,9 @@ use crate::task::state;
 /// It could be used to join the corresponding task or cancel it.
 /// If a `JoinHandle` is dropped, then the task continues executing in the
 /// background and its return value is lost. There is no way to join the task
-/// after its JoinHandle is dropped.
+/// after its JoinHandle is dropped. To cancel the task along with the handle
+/// instead, spawn it with `TaskBuilder::abort_on_drop` or turn the handle into
+/// an [`AbortOnDropHandle`].
 ///
 /// # Examples
 ///
@@ -46,6 +48,8 @@ use crate::task::state;
 /// ```
 pub struct JoinHandle<R> {
     pub(crate) raw: RawTask,
+    // Whether to cancel the task when the handle gets dropped
+    abort_on_drop: bool,
     marker: PhantomData<R>,
 }
 
@@ -53,9 +57,10 @@ unsafe impl<R: Send> Send for JoinHandle<R> {}
 unsafe impl<R: Send> Sync for JoinHandle<R> {}
 
 impl<R> JoinHandle<R> {
-    pub(crate) fn new(raw: RawTask) -> JoinHandle<R> {
+    pub(crate) fn new(raw: RawTask, builder: &TaskBuilder) -> JoinHandle<R> {
         JoinHandle {
             raw,
+            abort_on_drop: builder.abort_on_drop,
             marker: PhantomData,
         }
     }
@@ -70,15 +75,55 @@ impl<R> JoinHandle<R> {
             self.raw.cancel();
         }
     }
+
     /// Returns true if the task associated with this `JoinHandle` has finished.
     pub fn is_finished(&self) -> bool {
         state::is_finished(self.raw.header().state.get_current_state())
     }
 
-    pub(crate) fn get_cancel_handle(&self) -> CancelHandle {
+    /// Returns the unique ID of the task associated with this `JoinHandle`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let handle1 = ylong_runtime::spawn(async { 1 });
+    /// let handle2 = ylong_runtime::spawn(async { 2 });
+    /// assert_ne!(handle1.id(), handle2.id());
+    /// ```
+    pub fn id(&self) -> TaskId {
+        self.raw.header().id
+    }
+
+    /// Gets a [`CancelHandle`] of the task, which could cancel the task without
+    /// owning this `JoinHandle`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let handle = ylong_runtime::spawn(std::future::pending::<()>());
+    /// let cancel = handle.abort_handle();
+    /// let cancel2 = cancel.clone();
+    /// cancel2.cancel();
+    /// assert!(ylong_runtime::block_on(handle).is_err());
+    /// assert!(cancel.is_finished());
+    /// ```
+    pub fn abort_handle(&self) -> CancelHandle {
         CancelHandle::new(self.raw)
     }
 
+    /// Turns this handle into an [`AbortOnDropHandle`], which cancels the task
+    /// when it gets dropped.
+    pub fn abort_on_drop(mut self) -> AbortOnDropHandle<R> {
+        self.abort_on_drop = true;
+        AbortOnDropHandle { handle: self }
+    }
+
+    /// Drops this handle and lets the task keep executing in the background,
+    /// even if the task is spawned with `TaskBuilder::abort_on_drop`.
+    pub fn detach(mut self) {
+        self.abort_on_drop = false;
+    }
+
     pub(crate) fn set_waker(&mut self, waker: &Waker) {
         let cur = self.raw.header().state.get_current_state();
         unsafe {
@@ -130,13 +175,86 @@ impl<R> Future for JoinHandle<R> {
 
 impl<R> Drop for JoinHandle<R> {
     fn drop(&mut self) {
+        if self.abort_on_drop {
+            self.cancel();
+        }
         self.raw.drop_join_handle();
     }
 }
 
+/// A [`JoinHandle`] that cancels its task when it gets dropped.
+///
+/// It makes sure a child task does not outlive its owner, for example, the
+/// struct or the task holding the handle. It could still be awaited to get
+/// the result of the task like a `JoinHandle`.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// ylong_runtime::block_on(async {
+///     let handle = ylong_runtime::spawn(std::future::pending::<()>()).abort_on_drop();
+///     let cancel = handle.abort_handle();
+///     drop(handle);
+///     while !cancel.is_finished() {
+///         ylong_runtime::time::sleep(Duration::from_millis(1)).await;
+///     }
+/// });
+/// ```
+pub struct AbortOnDropHandle<R> {
+    handle: JoinHandle<R>,
+}
+
+impl<R> AbortOnDropHandle<R> {
+    /// Creates an `AbortOnDropHandle` from a [`JoinHandle`].
+    pub fn new(handle: JoinHandle<R>) -> Self {
+        handle.abort_on_drop()
+    }
+
+    /// Cancels the task associated with this handle. See
+    /// [`JoinHandle::cancel`].
+    pub fn cancel(&self) {
+        self.handle.cancel();
+    }
+
+    /// Returns true if the task associated with this handle has finished.
+    pub fn is_finished(&self) -> bool {
+        self.handle.is_finished()
+    }
+
+    /// Returns the unique ID of the task associated with this handle.
+    pub fn id(&self) -> TaskId {
+        self.handle.id()
+    }
+
+    /// Gets a [`CancelHandle`] of the task. See [`JoinHandle::abort_handle`].
+    pub fn abort_handle(&self) -> CancelHandle {
+        self.handle.abort_handle()
+    }
+
+    /// Gives back the plain [`JoinHandle`], so the task would no longer be
+    /// canceled when the handle is dropped.
+    pub fn detach(mut self) -> JoinHandle<R> {
+        self.handle.abort_on_drop = false;
+        self.handle
+    }
+}
+
+impl<R> Unpin for AbortOnDropHandle<R> {}
+
+impl<R> Future for AbortOnDropHandle<R> {
+    type Output = Result<R, ScheduleError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.handle).poll(cx)
+    }
+}
+
 /// A handle to cancel the spawned task.
 ///
 /// `CancelHandle` cannot await the task's completion, it can only terminate it.
+/// It could be cloned, and dropping it never affects the task.
 pub struct CancelHandle {
     raw: RawTask,
 }
@@ -161,6 +279,17 @@ impl CancelHandle {
         let state = self.raw.header().state.get_current_state();
         state::is_finished(state)
     }
+
+    /// Returns the unique ID of the task associated with this handle.
+    pub fn id(&self) -> TaskId {
+        self.raw.header().id
+    }
+}
+
+impl Clone for CancelHandle {
+    fn clone(&self) -> Self {
+        CancelHandle::new(self.raw)
+    }
 }
 
 impl Drop for CancelHandle {
@@ -206,7 +335,7 @@ mod test {
     #[test]
     fn ut_test_cancel_handle() {
         let handle = crate::spawn(async { 1 });
-        let cancel = handle.get_cancel_handle();
+        let cancel = handle.abort_handle();
         while !cancel.is_finished() {
             std::hint::spin_loop();
         }
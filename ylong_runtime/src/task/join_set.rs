@@ -24,7 +24,7 @@ use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
 use crate::error::ScheduleError;
 use crate::spawn::spawn_async;
 use crate::task::join_handle::CancelHandle;
-use crate::task::{JoinHandle, Qos, TaskBuilder};
+use crate::task::{JoinHandle, Qos, TaskBuilder, TaskId};
 
 /// A collection of tasks get spawned on a Ylong runtime
 ///
@@ -170,7 +170,7 @@ impl<R> JoinSet<R> {
             None => spawn_async(&self.builder, task),
             Some(builder) => builder.spawn(task),
         };
-        let cancel = handle.get_cancel_handle();
+        let cancel = handle.abort_handle();
         let entry = Arc::new(JoinEntry {
             handle: UnsafeCell::new(ManuallyDrop::new(handle)),
             list: self.list.clone(),
@@ -207,6 +207,27 @@ impl<R> JoinSet<R> {
     /// });
     /// ```
     pub async fn join_next(&mut self) -> Option<Result<R, ScheduleError>> {
+        self.join_next_with_id().await.map(|(_, res)| res)
+    }
+
+    /// Waits until one task inside the `JoinSet` completes and returns its ID
+    /// along with its output.
+    ///
+    /// Returns `None` if there is no task inside the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ylong_runtime::task::JoinSet;
+    /// ylong_runtime::block_on(async move {
+    ///     let mut set = JoinSet::new();
+    ///     let cancel_handle = set.spawn(async move { 1 });
+    ///     let (id, ret) = set.join_next_with_id().await.unwrap();
+    ///     assert_eq!(id, cancel_handle.id());
+    ///     assert_eq!(ret.unwrap(), 1);
+    /// });
+    /// ```
+    pub async fn join_next_with_id(&mut self) -> Option<(TaskId, Result<R, ScheduleError>)> {
         use crate::futures::poll_fn;
         poll_fn(|cx| self.poll_join_next(cx)).await
     }
@@ -281,7 +302,10 @@ impl<R> JoinSet<R> {
         Builder::new(self)
     }
 
-    fn poll_join_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<R, ScheduleError>>> {
+    fn poll_join_next(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<(TaskId, Result<R, ScheduleError>)>> {
         let mut list = self.list.lock().unwrap();
 
         // quick path: check if the set is empty, return none if true
@@ -316,8 +340,10 @@ impl<R> JoinSet<R> {
                         list.len -= 1;
                         drop(list);
                         // drop the JoinHandle and return it's result
-                        drop(ManuallyDrop::take(&mut *entry.handle.get()));
-                        Poll::Ready(Some(res))
+                        let handle = ManuallyDrop::take(&mut *entry.handle.get());
+                        let id = handle.id();
+                        drop(handle);
+                        Poll::Ready(Some((id, res)))
                     }
                     Poll::Pending => {
                         let mut list = self.list.lock().unwrap();
@@ -0,0 +1,517 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A set of `!Send` tasks that always run on the same thread.
+
+use std::cell::RefCell;
+use std::collections::{HashSet, VecDeque};
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+use crate::executor::{Runtime, Schedule};
+use crate::task::coop::{task_budget, with_task_budget};
+use crate::task::state;
+use crate::task::{JoinHandle, RawTask, Task, TaskBuilder};
+
+// Max number of tasks to run in one poll of the set, so that the future driving
+// the set could make progress as well.
+const MAX_TASKS_PER_TICK: usize = 64;
+
+thread_local! {
+    static CURRENT_LOCAL_SET: RefCell<Option<Rc<LocalContext>>> = const { RefCell::new(None) };
+}
+
+/// A set of tasks that are executed on the same thread.
+///
+/// Tasks spawned onto a `LocalSet` never get moved to other threads, so their
+/// futures do not need to be `Send`. The tasks only make progress while the set
+/// is being driven, either through [`LocalSet::run_until`], [`LocalSet::block_on`]
+/// or by awaiting the set itself. When the set gets dropped, all the tasks that
+/// are not finished get canceled.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// use ylong_runtime::task::{spawn_local, LocalSet};
+///
+/// let local = LocalSet::new();
+/// let shared = Rc::new(RefCell::new(0));
+/// let value = shared.clone();
+/// let res = ylong_runtime::block_on(local.run_until(async move {
+///     let handle = spawn_local(async move {
+///         *value.borrow_mut() += 1;
+///     });
+///     handle.await.unwrap();
+///     *shared.borrow()
+/// }));
+/// assert_eq!(res, 1);
+/// ```
+pub struct LocalSet {
+    context: Rc<LocalContext>,
+}
+
+struct LocalContext {
+    scheduler: Arc<LocalScheduler>,
+    // Tasks that are not finished yet. The set holds a reference count for
+    // each of them.
+    owned: RefCell<HashSet<RawTask>>,
+}
+
+/// Scheduler of a `LocalSet`. Tasks could be woken on any thread, but they only
+/// get popped and run by the thread owning the set.
+#[derive(Default)]
+pub(crate) struct LocalScheduler {
+    queue: Mutex<VecDeque<Task>>,
+    waker: Mutex<Option<Waker>>,
+}
+
+impl Schedule for LocalScheduler {
+    fn schedule(&self, task: Task, _lifo: bool) {
+        self.queue.lock().unwrap().push_back(task);
+        let waker = self.waker.lock().unwrap().clone();
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl LocalScheduler {
+    pub(crate) fn pop(&self) -> Option<Task> {
+        self.queue.lock().unwrap().pop_front()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.queue.lock().unwrap().is_empty()
+    }
+
+    pub(crate) fn set_waker(&self, waker: &Waker) {
+        let mut cur = self.waker.lock().unwrap();
+        match cur.as_ref() {
+            Some(cur) if cur.will_wake(waker) => {}
+            _ => *cur = Some(waker.clone()),
+        }
+    }
+}
+
+impl LocalContext {
+    fn spawn<T>(&self, builder: &TaskBuilder, task: T) -> JoinHandle<T::Output>
+    where
+        T: Future + 'static,
+        T::Output: 'static,
+    {
+        let scheduler = Arc::downgrade(&self.scheduler);
+        // the task only gets polled and dropped on this thread
+        let (task, handle) = unsafe { Task::create_local_task(builder, scheduler, task) };
+        task.0.header().state.inc_ref();
+        self.owned.borrow_mut().insert(task.0);
+        self.scheduler.schedule(task, false);
+        handle
+    }
+
+    // Runs the tasks inside the queue. Returns true if the queue is not empty
+    // after running `MAX_TASKS_PER_TICK` tasks.
+    fn tick(&self) -> bool {
+        for _ in 0..MAX_TASKS_PER_TICK {
+            match self.scheduler.pop() {
+                Some(task) => self.run_task(task),
+                None => return false,
+            }
+        }
+        !self.scheduler.is_empty()
+    }
+
+    fn run_task(&self, task: Task) {
+        let raw = task.0;
+        with_task_budget(task_budget(), || task.run());
+        // the reference count held by the set keeps the header valid
+        if state::is_finished(raw.header().state.get_current_state())
+            && self.owned.borrow_mut().remove(&raw)
+        {
+            raw.drop_ref();
+        }
+    }
+
+    // Cancels all the unfinished tasks and runs them, so that their futures get
+    // dropped on the current thread. Keeps going until the set holds no task,
+    // since a canceled task might still be pushed into the queue by a waker on
+    // another thread, and dropping a future might spawn new tasks.
+    fn shutdown(&self) {
+        loop {
+            let tasks: Vec<RawTask> = self.owned.borrow().iter().copied().collect();
+            if tasks.is_empty() {
+                return;
+            }
+            for task in &tasks {
+                unsafe { task.cancel() };
+            }
+            let mut ran = false;
+            while let Some(task) = self.scheduler.pop() {
+                self.run_task(task);
+                ran = true;
+            }
+            if !ran {
+                std::thread::yield_now();
+            }
+        }
+    }
+}
+
+struct EnterGuard(Option<Rc<LocalContext>>);
+
+impl Drop for EnterGuard {
+    fn drop(&mut self) {
+        let prev = self.0.take();
+        CURRENT_LOCAL_SET.with(|cur| *cur.borrow_mut() = prev);
+    }
+}
+
+fn enter(context: &Rc<LocalContext>) -> EnterGuard {
+    let prev = CURRENT_LOCAL_SET.with(|cur| cur.borrow_mut().replace(context.clone()));
+    EnterGuard(prev)
+}
+
+impl Default for LocalSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LocalSet {
+    /// Creates a new empty `LocalSet`.
+    pub fn new() -> Self {
+        LocalSet {
+            context: Rc::new(LocalContext {
+                scheduler: Arc::new(LocalScheduler::default()),
+                owned: RefCell::new(HashSet::new()),
+            }),
+        }
+    }
+
+    /// Spawns a `!Send` task onto the set. The task starts running once the
+    /// set gets driven.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    ///
+    /// use ylong_runtime::task::LocalSet;
+    ///
+    /// let local = LocalSet::new();
+    /// let value = Rc::new(1);
+    /// let handle = local.spawn_local(async move { *value + 1 });
+    /// let res = ylong_runtime::block_on(local.run_until(handle)).unwrap();
+    /// assert_eq!(res, 2);
+    /// ```
+    pub fn spawn_local<T, R>(&self, task: T) -> JoinHandle<R>
+    where
+        T: Future<Output = R> + 'static,
+        R: 'static,
+    {
+        self.context.spawn(&TaskBuilder::new(), task)
+    }
+
+    /// Runs the future to completion, while driving the tasks inside the set on
+    /// the current thread. [`spawn_local`] could be called inside the future to
+    /// spawn tasks onto this set.
+    ///
+    /// The returned future could be passed to any `block_on`, or be spawned
+    /// onto a current-thread runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    ///
+    /// use ylong_runtime::task::{spawn_local, LocalSet};
+    ///
+    /// let local = LocalSet::new();
+    /// let res = ylong_runtime::block_on(local.run_until(async {
+    ///     let value = Rc::new(1);
+    ///     spawn_local(async move { *value }).await.unwrap()
+    /// }));
+    /// assert_eq!(res, 1);
+    /// ```
+    pub fn run_until<F: Future>(&self, future: F) -> RunUntil<'_, F> {
+        RunUntil {
+            local_set: self,
+            future,
+        }
+    }
+
+    /// Blocks the current thread on the runtime until the future completes,
+    /// while driving the tasks inside the set on the current thread.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    ///
+    /// use ylong_runtime::builder::RuntimeBuilder;
+    /// use ylong_runtime::task::{spawn_local, LocalSet};
+    ///
+    /// let runtime = RuntimeBuilder::new_multi_thread().build().unwrap();
+    /// let local = LocalSet::new();
+    /// let res = local.block_on(&runtime, async {
+    ///     let value = Rc::new(1);
+    ///     // tasks could still be spawned onto the runtime
+    ///     let other = ylong_runtime::spawn(async { 2 }).await.unwrap();
+    ///     spawn_local(async move { *value + other }).await.unwrap()
+    /// });
+    /// assert_eq!(res, 3);
+    /// ```
+    pub fn block_on<F: Future>(&self, runtime: &Runtime, future: F) -> F::Output {
+        runtime.block_on(self.run_until(future))
+    }
+
+    fn poll_tasks(&self, cx: &mut Context<'_>) {
+        self.context.scheduler.set_waker(cx.waker());
+        if self.context.tick() {
+            cx.waker().wake_by_ref();
+        }
+    }
+}
+
+/// Waits for all the tasks inside the set to finish.
+impl Future for LocalSet {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let _guard = enter(&self.context);
+        self.poll_tasks(cx);
+        if self.context.owned.borrow().is_empty() {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl Drop for LocalSet {
+    fn drop(&mut self) {
+        let _guard = enter(&self.context);
+        self.context.shutdown();
+    }
+}
+
+/// Future returned by [`LocalSet::run_until`].
+pub struct RunUntil<'a, F> {
+    local_set: &'a LocalSet,
+    future: F,
+}
+
+impl<F: Future> Future for RunUntil<'_, F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = unsafe { self.get_unchecked_mut() };
+        let _guard = enter(&this.local_set.context);
+        let future = unsafe { Pin::new_unchecked(&mut this.future) };
+        if let Poll::Ready(output) = future.poll(cx) {
+            return Poll::Ready(output);
+        }
+        this.local_set.poll_tasks(cx);
+        Poll::Pending
+    }
+}
+
+/// Spawns a `!Send` task onto the [`LocalSet`] that is being driven on the
+/// current thread.
+///
+/// # Panics
+/// Panics if called outside of a [`LocalSet`].
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// use ylong_runtime::task::{spawn_local, LocalSet};
+///
+/// let local = LocalSet::new();
+/// ylong_runtime::block_on(local.run_until(async {
+///     let list = Rc::new(RefCell::new(Vec::new()));
+///     let mut handles = Vec::new();
+///     for i in 0..3 {
+///         let list = list.clone();
+///         handles.push(spawn_local(async move { list.borrow_mut().push(i) }));
+///     }
+///     for handle in handles {
+///         handle.await.unwrap();
+///     }
+///     assert_eq!(*list.borrow(), vec![0, 1, 2]);
+/// }));
+/// ```
+pub fn spawn_local<T, R>(task: T) -> JoinHandle<R>
+where
+    T: Future<Output = R> + 'static,
+    R: 'static,
+{
+    let context = CURRENT_LOCAL_SET.with(|cur| cur.borrow().clone());
+    match context {
+        Some(context) => context.spawn(&TaskBuilder::new(), task),
+        None => panic!("`spawn_local` called outside of a `LocalSet`"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+
+    use crate::task::local_set::{spawn_local, LocalSet};
+    use crate::task::yield_now;
+
+    /// UT test cases for `LocalSet::run_until`
+    ///
+    /// # Brief
+    /// 1. Spawn `!Send` tasks onto a `LocalSet` before and inside `run_until`
+    /// 2. Yield inside the tasks
+    /// 3. Check that all the tasks finish on the current thread
+    #[test]
+    fn ut_local_set_run_until() {
+        let local = LocalSet::new();
+        let thread_id = std::thread::current().id();
+        let count = Rc::new(Cell::new(0));
+
+        let count_clone = count.clone();
+        let handle = local.spawn_local(async move {
+            yield_now().await;
+            count_clone.set(count_clone.get() + 1);
+        });
+
+        let count_clone = count.clone();
+        let res = crate::block_on(local.run_until(async move {
+            let mut handles = vec![handle];
+            for _ in 0..10 {
+                let count = count_clone.clone();
+                handles.push(spawn_local(async move {
+                    yield_now().await;
+                    assert_eq!(std::thread::current().id(), thread_id);
+                    count.set(count.get() + 1);
+                }));
+            }
+            for handle in handles {
+                handle.await.unwrap();
+            }
+            count_clone.get()
+        }));
+        assert_eq!(res, 11);
+    }
+
+    /// UT test cases for awaiting a `LocalSet`
+    ///
+    /// # Brief
+    /// 1. Spawn tasks onto a `LocalSet`, which spawn more tasks inside
+    /// 2. Await the set
+    /// 3. Check that all the tasks are finished
+    #[test]
+    fn ut_local_set_await() {
+        let local = LocalSet::new();
+        let list = Rc::new(RefCell::new(Vec::new()));
+        for i in 0..3 {
+            let list = list.clone();
+            local.spawn_local(async move {
+                spawn_local(async move {
+                    yield_now().await;
+                    list.borrow_mut().push(i);
+                });
+            });
+        }
+        crate::block_on(local);
+        list.borrow_mut().sort();
+        assert_eq!(*list.borrow(), vec![0, 1, 2]);
+    }
+
+    /// UT test cases for dropping a `LocalSet`
+    ///
+    /// # Brief
+    /// 1. Spawn a task that never finishes onto a `LocalSet`
+    /// 2. Poll the task once and drop the set
+    /// 3. Check that the future of the task is dropped and its `JoinHandle`
+    ///    gets an error
+    #[test]
+    fn ut_local_set_drop() {
+        struct Guard(Rc<Cell<bool>>);
+
+        impl Drop for Guard {
+            fn drop(&mut self) {
+                self.0.set(true);
+            }
+        }
+
+        let local = LocalSet::new();
+        let dropped = Rc::new(Cell::new(false));
+        let guard = Guard(dropped.clone());
+        let handle = local.spawn_local(async move {
+            let _guard = guard;
+            std::future::pending::<()>().await;
+        });
+        crate::block_on(local.run_until(yield_now()));
+        assert!(!dropped.get());
+        drop(local);
+        assert!(dropped.get());
+        assert!(crate::block_on(handle).is_err());
+    }
+
+    /// UT test cases for dropping a `LocalSet` whose tasks spawn new tasks when
+    /// they get canceled
+    ///
+    /// # Brief
+    /// 1. Spawn a task that never finishes onto a `LocalSet`, whose future
+    ///    spawns another never-finishing task when getting dropped
+    /// 2. Poll the task once and drop the set
+    /// 3. Check that the futures of both tasks are dropped
+    #[test]
+    fn ut_local_set_drop_spawn_in_drop() {
+        struct Guard(Rc<Cell<usize>>);
+
+        impl Drop for Guard {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        struct SpawnOnDrop(Rc<Cell<usize>>);
+
+        impl Drop for SpawnOnDrop {
+            fn drop(&mut self) {
+                let guard = Guard(self.0.clone());
+                spawn_local(async move {
+                    let _guard = guard;
+                    std::future::pending::<()>().await;
+                });
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let local = LocalSet::new();
+        let dropped = Rc::new(Cell::new(0));
+        let spawner = SpawnOnDrop(dropped.clone());
+        local.spawn_local(async move {
+            let _spawner = spawner;
+            std::future::pending::<()>().await;
+        });
+        crate::block_on(local.run_until(yield_now()));
+        assert_eq!(dropped.get(), 0);
+        drop(local);
+        assert_eq!(dropped.get(), 2);
+    }
+}
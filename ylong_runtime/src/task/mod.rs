@@ -14,11 +14,15 @@
 //! Asynchronous tasks that wraps the future and get scheduled by the runtime.
 
 pub mod builder;
+pub(crate) mod coop;
+mod id;
 pub mod join_handle;
 mod join_set;
+mod panic_handler;
 mod raw;
 pub(crate) mod state;
 mod task_handle;
+mod task_local;
 mod waker;
 pub(crate) mod yield_now;
 use std::future::Future;
@@ -26,13 +30,30 @@ use std::ptr::NonNull;
 use std::sync::Weak;
 
 pub use builder::TaskBuilder;
-pub use join_handle::JoinHandle;
+pub use coop::{unconstrained, Unconstrained};
+pub use id::TaskId;
+pub use join_handle::{AbortOnDropHandle, CancelHandle, JoinHandle};
 pub use join_set::JoinSet;
+pub use panic_handler::{PanicPayload, TaskInfo};
+pub use task_local::{AccessError, LocalKey, TaskLocalFuture};
 pub use yield_now::yield_now;
 
 use crate::executor::Schedule;
 pub(crate) use crate::task::raw::{Header, RawTask, TaskMngInfo};
 
+cfg_not_ffrt! {
+    mod local_set;
+    mod scope;
+    pub use local_set::{spawn_local, LocalSet, RunUntil};
+    pub use scope::{scope, Scope, Scoped};
+    pub(crate) use panic_handler::PanicHandler;
+
+    use crate::error::ScheduleError;
+    use crate::executor::PlaceholderScheduler;
+    use crate::task::raw::with_current;
+    use crate::task::task_handle::TaskHandle;
+}
+
 pub(crate) enum VirtualTableType {
     #[cfg(not(feature = "ffrt"))]
     Ylong,
@@ -41,7 +62,7 @@ pub(crate) enum VirtualTableType {
 }
 
 #[cfg(not(feature = "ffrt"))]
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 /// Qos levels.
 pub enum Qos {
     /// Inherits parent's qos level
@@ -91,6 +112,57 @@ impl Task {
     pub(crate) unsafe fn from_raw(ptr: NonNull<Header>) -> Task {
         Task(RawTask::form_raw(ptr))
     }
+
+    /// Cancels a task that is still waiting inside a queue. The task gets
+    /// finished right away and its JoinHandle returns a `TaskCanceled` error.
+    #[inline]
+    pub(crate) fn cancel_queued(self) {
+        unsafe { self.0.cancel() };
+        self.0.run();
+    }
+
+    /// Creates a task that would never be polled. The returned JoinHandle
+    /// gets the given error directly.
+    pub(crate) fn create_rejected_task<T>(
+        builder: &TaskBuilder,
+        task: T,
+        err: ScheduleError,
+    ) -> JoinHandle<T::Output>
+    where
+        T: Future + Send + 'static,
+        T::Output: Send + 'static,
+    {
+        let scheduler: Weak<PlaceholderScheduler> = Weak::new();
+        let raw = Task::create_raw_task::<T, PlaceholderScheduler>(
+            builder,
+            scheduler,
+            task,
+            VirtualTableType::Ylong,
+        );
+        let join = JoinHandle::new(raw, builder);
+        unsafe { TaskHandle::<T, PlaceholderScheduler>::from_raw(raw.ptr).reject(err) };
+        join
+    }
+
+    /// Creates a task whose future is not `Send`.
+    ///
+    /// # Safety
+    /// The caller must make sure the task only gets polled and dropped on the
+    /// thread creating it.
+    pub(crate) unsafe fn create_local_task<T, S>(
+        builder: &TaskBuilder,
+        scheduler: Weak<S>,
+        task: T,
+    ) -> (Task, JoinHandle<T::Output>)
+    where
+        T: Future + 'static,
+        T::Output: 'static,
+        S: Schedule,
+    {
+        let raw = Task::create_raw_task::<T, S>(builder, scheduler, task, VirtualTableType::Ylong);
+        let join = JoinHandle::new(raw, builder);
+        (Task(raw), join)
+    }
 }
 
 impl Task {
@@ -107,7 +179,7 @@ impl Task {
     {
         let raw = Task::create_raw_task::<T, S>(builder, scheduler, task, virtual_table_type);
 
-        let join = JoinHandle::new(raw);
+        let join = JoinHandle::new(raw, builder);
         (Task(raw), join)
     }
 
@@ -121,6 +193,8 @@ impl Task {
         T: Future,
         S: Schedule,
     {
+        #[cfg(feature = "metrics")]
+        let owner = scheduler.upgrade();
         let ptr = Box::into_raw(TaskMngInfo::<T, S>::new(
             builder,
             scheduler,
@@ -133,7 +207,12 @@ impl Task {
         } else {
             panic!("task mem is null because not enough memory is available");
         };
-        RawTask { ptr }
+        let raw = RawTask { ptr };
+        #[cfg(feature = "metrics")]
+        if let Some(registry) = owner.as_deref().and_then(Schedule::registry) {
+            registry.insert(raw);
+        }
+        raw
     }
 }
 
@@ -165,3 +244,49 @@ where
     let rt = crate::executor::global_default_async();
     rt.block_on(task)
 }
+
+cfg_not_ffrt! {
+    /// Returns the ID of the task being polled on the current thread.
+    ///
+    /// # Panics
+    /// This function panics when calling outside of a task.
+    ///
+    /// # Example
+    /// ```
+    /// let handle = ylong_runtime::spawn(async { ylong_runtime::task::id() });
+    /// let id = handle.id();
+    /// assert_eq!(ylong_runtime::block_on(handle).unwrap(), id);
+    /// ```
+    pub fn id() -> TaskId {
+        try_id().expect("`task::id()` is called outside of a task")
+    }
+
+    /// Returns the ID of the task being polled on the current thread, or
+    /// `None` if calling outside of a task.
+    ///
+    /// # Example
+    /// ```
+    /// assert!(ylong_runtime::task::try_id().is_none());
+    /// ```
+    pub fn try_id() -> Option<TaskId> {
+        with_current(|header| header.id)
+    }
+
+    /// Returns the name set by [`TaskBuilder::name`] of the task being polled
+    /// on the current thread. Returns `None` if the task has no name or if
+    /// calling outside of a task.
+    ///
+    /// # Example
+    /// ```
+    /// use ylong_runtime::task::TaskBuilder;
+    ///
+    /// let handle = TaskBuilder::new()
+    ///     .name("hello".to_string())
+    ///     .spawn(async { ylong_runtime::task::name() });
+    /// let name = ylong_runtime::block_on(handle).unwrap();
+    /// assert_eq!(name.as_deref(), Some("hello"));
+    /// ```
+    pub fn name() -> Option<String> {
+        with_current(|header| header.name.clone()).flatten()
+    }
+}
@@ -0,0 +1,145 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Information passed to the panic hook of the tasks.
+
+use std::any::Any;
+
+use crate::task::TaskId;
+
+cfg_not_ffrt! {
+    use std::panic::{self, AssertUnwindSafe};
+    use std::process;
+    use std::sync::{RwLock, Weak};
+
+    use crate::builder::{CommonBuilder, PanicPolicy, TaskPanicHook};
+    use crate::executor::RuntimeInner;
+    use crate::task::Header;
+}
+
+/// Payload of a panic, which is the value passed to `panic!` or
+/// `std::panic::panic_any`.
+pub type PanicPayload = dyn Any + Send + 'static;
+
+/// Information of the task that panics, which is passed to the panic hook set
+/// by `task_panic_hook`.
+pub struct TaskInfo<'a> {
+    id: TaskId,
+    name: Option<&'a str>,
+}
+
+impl<'a> TaskInfo<'a> {
+    #[cfg(not(feature = "ffrt"))]
+    pub(crate) fn new(header: &'a Header) -> Self {
+        TaskInfo {
+            id: header.id,
+            name: header.name.as_deref(),
+        }
+    }
+
+    /// Gets the id of the task, same as the one returned by `JoinHandle::id`.
+    pub fn id(&self) -> TaskId {
+        self.id
+    }
+
+    /// Gets the name of the task set by `TaskBuilder::name`.
+    pub fn name(&self) -> Option<&str> {
+        self.name
+    }
+}
+
+/// Handles the panics of the tasks according to the settings of the runtime.
+#[cfg(not(feature = "ffrt"))]
+#[derive(Default)]
+pub(crate) struct PanicHandler {
+    policy: PanicPolicy,
+    hook: Option<TaskPanicHook>,
+    /// The runtime to shut down, set after the runtime is built
+    runtime: RwLock<Weak<RuntimeInner>>,
+}
+
+#[cfg(not(feature = "ffrt"))]
+impl PanicHandler {
+    pub(crate) fn new(builder: &CommonBuilder) -> Self {
+        PanicHandler {
+            policy: builder.panic_policy,
+            hook: builder.task_panic_hook.clone(),
+            runtime: RwLock::new(Weak::new()),
+        }
+    }
+
+    pub(crate) fn set_runtime(&self, runtime: Weak<RuntimeInner>) {
+        *self.runtime.write().unwrap() = runtime;
+    }
+
+    pub(crate) fn handle(&self, info: &TaskInfo, payload: &PanicPayload) {
+        if let Some(hook) = &self.hook {
+            // a panicking hook should not take down the thread running the task
+            let _ = panic::catch_unwind(AssertUnwindSafe(|| hook(info, payload)));
+        }
+        match self.policy {
+            PanicPolicy::Ignore => {}
+            PanicPolicy::Abort => process::abort(),
+            PanicPolicy::ShutdownRuntime => {
+                let runtime = self.runtime.read().unwrap().upgrade();
+                // the handler runs on the threads of the runtime, so it could
+                // not wait for them to exit
+                if let Some(runtime) = runtime {
+                    runtime.shutdown_background();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(test, not(feature = "ffrt")))]
+mod test {
+    use std::sync::{Arc, Mutex};
+
+    use crate::builder::RuntimeBuilder;
+    use crate::task::{Header, PanicHandler, TaskInfo};
+
+    /// UT test cases for the panic hook
+    ///
+    /// # Brief
+    /// 1. Create a panic handler with a hook
+    /// 2. Handle a panic of a named task
+    /// 3. Check that the hook gets the id and name of the task and the payload
+    /// 4. Handle a panic with a panicking hook, check that it is caught
+    #[test]
+    fn ut_panic_handler_hook() {
+        let record = Arc::new(Mutex::new(None));
+        let record_clone = record.clone();
+        let builder = RuntimeBuilder::new_multi_thread().task_panic_hook(move |info, payload| {
+            let name = info.name().map(String::from);
+            let msg = payload.downcast_ref::<&str>().map(|s| s.to_string());
+            *record_clone.lock().unwrap() = Some((info.id(), name, msg));
+        });
+        let handler = PanicHandler::new(&builder.common);
+        let mut header = Header::default();
+        header.name = Some(String::from("task"));
+        handler.handle(&TaskInfo::new(&header), &"boom");
+        assert_eq!(
+            record.lock().unwrap().take(),
+            Some((
+                header.id,
+                Some(String::from("task")),
+                Some(String::from("boom"))
+            ))
+        );
+
+        let builder = RuntimeBuilder::new_multi_thread().task_panic_hook(|_, _| panic!("hook"));
+        let handler = PanicHandler::new(&builder.common);
+        handler.handle(&TaskInfo::new(&Header::default()), &"boom");
+    }
+}
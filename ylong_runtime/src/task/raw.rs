@@ -23,13 +23,23 @@ use crate::error::ScheduleError;
 use crate::executor::Schedule;
 use crate::task::state::TaskState;
 use crate::task::task_handle::TaskHandle;
-use crate::task::{TaskBuilder, VirtualTableType};
+use crate::task::{TaskBuilder, TaskId, VirtualTableType};
 use crate::util::linked_list::{Link, Node};
 
+cfg_metrics! {
+    use crate::metrics::TaskStats;
+}
+
 cfg_ffrt! {
     use crate::ffrt::ffrt_task::FfrtTaskCtx;
 }
 
+cfg_not_ffrt! {
+    use std::cell::Cell;
+    use std::ptr;
+    use crate::task::Qos;
+}
+
 pub(crate) struct TaskVirtualTable {
     /// Task running method
     pub(crate) run: unsafe fn(NonNull<Header>) -> bool,
@@ -54,6 +64,18 @@ pub(crate) struct TaskVirtualTable {
 pub(crate) struct Header {
     pub(crate) state: TaskState,
     pub(crate) vtable: &'static TaskVirtualTable,
+    /// Unique ID of the task
+    pub(crate) id: TaskId,
+    /// Qos level of the task, `Qos::Inherent` is resolved when the task is
+    /// created
+    #[cfg(not(feature = "ffrt"))]
+    pub(crate) qos: Qos,
+    /// Name of the task set by the task builder
+    #[cfg(not(feature = "ffrt"))]
+    pub(crate) name: Option<String>,
+    /// Poll statistics of the task
+    #[cfg(feature = "metrics")]
+    pub(crate) stats: TaskStats,
     // Node inside the global queue
     node: Node<Header>,
 }
@@ -94,10 +116,57 @@ cfg_not_ffrt! {
             Self {
                 state: TaskState::new(),
                 vtable: get_default_vtable(),
+                id: TaskId::next(),
+                qos: Qos::Default,
+                name: None,
+                #[cfg(feature = "metrics")]
+                stats: TaskStats::new(),
                 node: Default::default(),
             }
         }
     }
+
+    thread_local! {
+        static CURRENT_TASK: Cell<*const Header> = const { Cell::new(ptr::null()) };
+    }
+
+    /// Marks a task as the one being polled on the current thread. The
+    /// previous task gets restored when the guard is dropped.
+    pub(crate) struct CurrentTaskGuard(*const Header);
+
+    impl CurrentTaskGuard {
+        pub(crate) fn new(header: &Header) -> Self {
+            let prev = CURRENT_TASK.with(|cur| cur.replace(header));
+            CurrentTaskGuard(prev)
+        }
+    }
+
+    impl Drop for CurrentTaskGuard {
+        fn drop(&mut self) {
+            CURRENT_TASK.with(|cur| cur.set(self.0));
+        }
+    }
+
+    /// Accesses the header of the task being polled on the current thread.
+    pub(crate) fn with_current<F, R>(f: F) -> Option<R>
+    where
+        F: FnOnce(&Header) -> R,
+    {
+        CURRENT_TASK.with(|cur| {
+            let header = cur.get();
+            if header.is_null() {
+                None
+            } else {
+                // the header stays valid while the task is being polled
+                Some(f(unsafe { &*header }))
+            }
+        })
+    }
+
+    /// Gets the qos level of the task being polled on the current thread.
+    pub(crate) fn current_qos() -> Option<Qos> {
+        with_current(|header| header.qos)
+    }
 }
 
 unsafe impl Link for Header {
@@ -440,10 +509,9 @@ where
     S: Schedule,
 {
     /// Creates non-stackful task info.
-    // TODO: builder information currently is not used yet. Might use in the future
-    // (e.g. qos),   so keep it now.
+    #[cfg_attr(feature = "ffrt", allow(unused_variables))]
     pub(crate) fn new(
-        _builder: &TaskBuilder,
+        builder: &TaskBuilder,
         scheduler: Weak<S>,
         task: T,
         virtual_table_type: VirtualTableType,
@@ -454,10 +522,25 @@ where
             #[cfg(feature = "ffrt")]
             VirtualTableType::Ffrt => create_ffrt_vtable::<T, S>(),
         };
+        // A task without qos runs at the default level, while an inherent one
+        // takes the level of the task spawning it
+        #[cfg(not(feature = "ffrt"))]
+        let qos = match builder.qos {
+            None => Qos::Default,
+            Some(Qos::Inherent) => current_qos().unwrap_or(Qos::Default),
+            Some(qos) => qos,
+        };
         // Create the common header
         let header = Header {
             state: TaskState::new(),
             vtable,
+            id: TaskId::next(),
+            #[cfg(not(feature = "ffrt"))]
+            qos,
+            #[cfg(not(feature = "ffrt"))]
+            name: builder.name.clone(),
+            #[cfg(feature = "metrics")]
+            stats: TaskStats::new(),
             node: Node::new(),
         };
         // Create task private info
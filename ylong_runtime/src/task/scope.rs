@@ -0,0 +1,228 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Scopes of tasks that could borrow from the stack of their parent.
+
+use std::collections::HashSet;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+use crate::executor::Schedule;
+use crate::task::local_set::LocalScheduler;
+use crate::task::{coop, state, JoinHandle, RawTask, Task, TaskBuilder, VirtualTableType};
+
+// Max number of tasks to run in one poll of the scope, so that the body of the
+// scope could make progress as well.
+const MAX_TASKS_PER_TICK: usize = 64;
+
+/// Creates a scope for spawning tasks that could borrow non-`'static` data
+/// from the enclosing function.
+///
+/// The closure gets a [`Scope`] for spawning the child tasks, and returns the
+/// body of the scope. The returned future completes with the output of the
+/// body after all the child tasks have finished. If the future gets dropped
+/// before completing, all the unfinished child tasks get canceled and dropped
+/// before the drop returns. Therefore, no child task outlives the scope.
+///
+/// The child tasks are driven by the future of the scope rather than the
+/// worker threads of the runtime, so they run concurrently with the body but
+/// not in parallel with it. If the future is leaked without being dropped, the
+/// child tasks never run again.
+///
+/// # Examples
+///
+/// ```
+/// use ylong_runtime::task;
+///
+/// let mut list = vec![1, 2, 3];
+/// let items = &list;
+/// let total = ylong_runtime::block_on(task::scope(|s| async move {
+///     let first = s.spawn(async move { items[0] });
+///     let sum = s.spawn(async move { items.iter().sum::<i32>() });
+///     first.await.unwrap() + sum.await.unwrap()
+/// }));
+/// assert_eq!(total, 7);
+/// list.push(4);
+/// ```
+pub fn scope<'env, F, Fut>(f: F) -> Scoped<'env, Fut>
+where
+    F: FnOnce(Scope<'env>) -> Fut,
+    Fut: Future + 'env,
+{
+    let context = Arc::new(ScopeContext {
+        scheduler: Arc::new(LocalScheduler::default()),
+        owned: Mutex::new(Owned {
+            tasks: HashSet::new(),
+            closed: false,
+        }),
+    });
+    let body = f(Scope {
+        context: context.clone(),
+        _marker: PhantomData,
+    });
+    Scoped {
+        context,
+        body: Some(body),
+        output: None,
+        _marker: PhantomData,
+    }
+}
+
+/// A handle for spawning tasks inside a scope created by [`scope`].
+#[derive(Clone)]
+pub struct Scope<'env> {
+    context: Arc<ScopeContext>,
+    // Makes the scope invariant over 'env
+    _marker: PhantomData<&'env mut &'env ()>,
+}
+
+struct ScopeContext {
+    scheduler: Arc<LocalScheduler>,
+    owned: Mutex<Owned>,
+}
+
+struct Owned {
+    // Tasks that are not finished yet. The scope holds a reference count for
+    // each of them.
+    tasks: HashSet<RawTask>,
+    // Whether the scope has completed or been dropped
+    closed: bool,
+}
+
+// The tasks are only polled and dropped by the scope, which requires them to
+// be `Send`.
+unsafe impl Send for ScopeContext {}
+unsafe impl Sync for ScopeContext {}
+
+impl<'env> Scope<'env> {
+    /// Spawns a child task onto the scope. The task could borrow data that
+    /// outlives the scope, and it starts running once the scope gets polled.
+    ///
+    /// If the scope has already completed or been dropped, the task gets
+    /// canceled right away and its `JoinHandle` returns a `TaskCanceled` error.
+    pub fn spawn<T, R>(&self, task: T) -> JoinHandle<R>
+    where
+        T: Future<Output = R> + Send + 'env,
+        R: Send + 'env,
+    {
+        let builder = TaskBuilder::new();
+        let scheduler = Arc::downgrade(&self.context.scheduler);
+        let raw = Task::create_raw_task(&builder, scheduler, task, VirtualTableType::Ylong);
+        let handle = JoinHandle::new(raw, &builder);
+        let task = Task(raw);
+
+        let mut owned = self.context.owned.lock().unwrap();
+        if owned.closed {
+            drop(owned);
+            task.cancel_queued();
+            return handle;
+        }
+        raw.header().state.inc_ref();
+        owned.tasks.insert(raw);
+        drop(owned);
+        self.context.scheduler.schedule(task, false);
+        handle
+    }
+}
+
+impl ScopeContext {
+    fn is_empty(&self) -> bool {
+        self.owned.lock().unwrap().tasks.is_empty()
+    }
+
+    // Runs the tasks inside the queue. Returns true if the queue is not empty
+    // after running `MAX_TASKS_PER_TICK` tasks.
+    fn tick(&self) -> bool {
+        for _ in 0..MAX_TASKS_PER_TICK {
+            match self.scheduler.pop() {
+                Some(task) => self.run_task(task),
+                None => return false,
+            }
+        }
+        !self.scheduler.is_empty()
+    }
+
+    fn run_task(&self, task: Task) {
+        let raw = task.0;
+        // the children share the budget configured by the runtime polling the
+        // scope
+        coop::with_budget(coop::task_budget(), || task.run());
+        // the reference count held by the scope keeps the header valid
+        if state::is_finished(raw.header().state.get_current_state())
+            && self.owned.lock().unwrap().tasks.remove(&raw)
+        {
+            raw.drop_ref();
+        }
+    }
+
+    // Stops accepting new tasks, then cancels all the unfinished tasks and runs
+    // them, so that their futures get dropped before the scope ends.
+    fn shutdown(&self) {
+        self.owned.lock().unwrap().closed = true;
+        loop {
+            let tasks: Vec<RawTask> = self.owned.lock().unwrap().tasks.iter().copied().collect();
+            if tasks.is_empty() {
+                return;
+            }
+            for task in &tasks {
+                unsafe { task.cancel() };
+            }
+            while let Some(task) = self.scheduler.pop() {
+                self.run_task(task);
+            }
+        }
+    }
+}
+
+/// Future returned by [`scope`].
+pub struct Scoped<'env, Fut: Future> {
+    context: Arc<ScopeContext>,
+    body: Option<Fut>,
+    output: Option<Fut::Output>,
+    _marker: PhantomData<&'env mut &'env ()>,
+}
+
+impl<Fut: Future> Future for Scoped<'_, Fut> {
+    type Output = Fut::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = unsafe { self.get_unchecked_mut() };
+        if let Some(body) = this.body.as_mut() {
+            let body = unsafe { Pin::new_unchecked(body) };
+            if let Poll::Ready(output) = body.poll(cx) {
+                this.body = None;
+                this.output = Some(output);
+            }
+        }
+
+        this.context.scheduler.set_waker(cx.waker());
+        let busy = this.context.tick();
+        if this.output.is_some() && this.context.is_empty() {
+            this.context.shutdown();
+            return Poll::Ready(this.output.take().unwrap());
+        }
+        if busy {
+            cx.waker().wake_by_ref();
+        }
+        Poll::Pending
+    }
+}
+
+impl<Fut: Future> Drop for Scoped<'_, Fut> {
+    fn drop(&mut self) {
+        self.context.shutdown();
+    }
+}
@@ -24,7 +24,8 @@ use crate::task::state::StateAction;
 use crate::task::waker::WakerRefHeader;
 
 cfg_not_ffrt! {
-    use crate::task::Task;
+    use crate::task::raw::CurrentTaskGuard;
+    use crate::task::{Task, TaskInfo};
 }
 
 pub(crate) struct TaskHandle<T: Future, S: Schedule> {
@@ -76,6 +77,17 @@ where
     }
 
     pub(crate) fn release(self) {
+        // The task must leave the bookkeeping of its scheduler before its memory
+        // gets freed
+        #[cfg(any(feature = "metrics", feature = "current_thread_runtime"))]
+        if let Some(scheduler) = self.inner().scheduler.upgrade() {
+            #[cfg(feature = "metrics")]
+            if let Some(registry) = scheduler.registry() {
+                registry.remove(self.header());
+            }
+            #[cfg(feature = "current_thread_runtime")]
+            scheduler.on_task_release(self.header().id);
+        }
         unsafe { drop(Box::from_raw(self.task.as_ptr())) };
     }
 
@@ -182,13 +194,18 @@ where
             _ => unreachable!(),
         };
 
+        #[cfg(feature = "metrics")]
+        self.header().stats.record_poll();
+
         // turn the task header into a waker
         let waker = WakerRefHeader::<'_>::new::<T>(self.header());
         let mut context = Context::from_waker(&waker);
 
+        let guard = CurrentTaskGuard::new(self.header());
         let res = panic::catch_unwind(panic::AssertUnwindSafe(|| {
             self.inner().poll(&mut context).map(Ok)
         }));
+        drop(guard);
 
         let cur = self.header().state.get_current_state();
         match res {
@@ -209,13 +226,26 @@ where
                 _ => {}
             },
 
-            Err(_) => {
-                let output = Err(ScheduleError::new(ErrorKind::Panic, "panic happen"));
-                self.finish(cur, output);
+            Err(payload) => {
+                if let Some(scheduler) = self.inner().scheduler.upgrade() {
+                    scheduler.on_task_panic(&TaskInfo::new(self.header()), &*payload);
+                }
+                self.finish(cur, Err(ScheduleError::panic(payload)));
             }
         }
     }
 
+    // Finishes the task with the given error without polling it
+    pub(crate) fn reject(self, err: ScheduleError) {
+        match self.header().state.turning_to_running() {
+            StateAction::Success => {}
+            StateAction::Failed(state) => panic!("task state invalid: {state}"),
+            _ => unreachable!(),
+        };
+        let cur = self.header().state.get_current_state();
+        self.finish(cur, Err(err));
+    }
+
     pub(crate) unsafe fn shutdown(self) {
         // Check if the JoinHandle gets dropped already. If JoinHandle is still there,
         // wakes the JoinHandle.
@@ -257,6 +287,8 @@ where
     }
 
     fn get_scheduled(&self, lifo: bool) {
+        #[cfg(feature = "metrics")]
+        self.header().stats.record_schedule();
         // the scheduler must exist when calling this method
         self.inner()
             .scheduler
@@ -315,9 +347,8 @@ where
                 _ => false,
             },
 
-            Err(_) => {
-                let output = Err(ScheduleError::new(ErrorKind::Panic, "panic happen"));
-                self.finish(cur, output);
+            Err(payload) => {
+                self.finish(cur, Err(ScheduleError::panic(payload)));
                 true
             }
         }
@@ -0,0 +1,357 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Task-local storage.
+//!
+//! The value of a task-local key is stored inside the future returned by
+//! [`LocalKey::scope`], and is moved into the thread-local slot of the key
+//! only while the future is being polled. Therefore the value follows the task
+//! when it moves between workers.
+
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::{fmt, mem, thread};
+
+/// Declares new task-local keys of type [`LocalKey`].
+///
+/// The values of the keys are set by [`LocalKey::scope`], and could be
+/// accessed by [`LocalKey::with`] inside the scope.
+///
+/// # Examples
+///
+/// ```
+/// ylong_runtime::task_local! {
+///     static REQUEST_ID: u32;
+///     pub static TRACE: String;
+/// }
+///
+/// let handle = ylong_runtime::spawn(REQUEST_ID.scope(1, async {
+///     ylong_runtime::task::yield_now().await;
+///     REQUEST_ID.with(|id| assert_eq!(*id, 1));
+/// }));
+/// ylong_runtime::block_on(handle).unwrap();
+/// ```
+#[macro_export]
+macro_rules! task_local {
+    () => {};
+
+    ($(#[$attr:meta])* $vis:vis static $name:ident: $t:ty; $($rest:tt)*) => {
+        $crate::__task_local_inner!($(#[$attr])* $vis $name, $t);
+        $crate::task_local!($($rest)*);
+    };
+
+    ($(#[$attr:meta])* $vis:vis static $name:ident: $t:ty) => {
+        $crate::__task_local_inner!($(#[$attr])* $vis $name, $t);
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __task_local_inner {
+    ($(#[$attr:meta])* $vis:vis $name:ident, $t:ty) => {
+        $(#[$attr])*
+        $vis static $name: $crate::task::LocalKey<$t> = {
+            std::thread_local! {
+                static __KEY: std::cell::RefCell<Option<$t>> = const { std::cell::RefCell::new(None) };
+            }
+
+            $crate::task::LocalKey { inner: __KEY }
+        };
+    };
+}
+
+/// A key for task-local data, declared by [`task_local!`].
+///
+/// [`task_local!`]: crate::task_local
+pub struct LocalKey<T: 'static> {
+    #[doc(hidden)]
+    pub inner: thread::LocalKey<RefCell<Option<T>>>,
+}
+
+impl<T: 'static> LocalKey<T> {
+    /// Sets the value of the key for the given future. The value could be
+    /// accessed by the future through [`LocalKey::with`], no matter which
+    /// thread it is polled on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// ylong_runtime::task_local! {
+    ///     static NUMBER: u32;
+    /// }
+    ///
+    /// let res = ylong_runtime::block_on(NUMBER.scope(1, async {
+    ///     NUMBER.with(|n| *n + 1)
+    /// }));
+    /// assert_eq!(res, 2);
+    /// ```
+    pub fn scope<F: Future>(&'static self, value: T, future: F) -> TaskLocalFuture<T, F> {
+        TaskLocalFuture {
+            local: self,
+            slot: Some(value),
+            future: Some(future),
+        }
+    }
+
+    /// Sets the value of the key while the given closure runs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// ylong_runtime::task_local! {
+    ///     static NUMBER: u32;
+    /// }
+    ///
+    /// let res = NUMBER.sync_scope(1, || NUMBER.with(|n| *n + 1));
+    /// assert_eq!(res, 2);
+    /// ```
+    pub fn sync_scope<F, R>(&'static self, value: T, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        let mut slot = Some(value);
+        self.scope_inner(&mut slot, f)
+    }
+
+    /// Accesses the current value of the key.
+    ///
+    /// # Panics
+    /// Panics if the key is not set by a [`LocalKey::scope`] or
+    /// [`LocalKey::sync_scope`] around the caller.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// ylong_runtime::task_local! {
+    ///     static NAME: String;
+    /// }
+    ///
+    /// ylong_runtime::block_on(NAME.scope("ylong".to_string(), async {
+    ///     NAME.with(|name| assert_eq!(name, "ylong"));
+    /// }));
+    /// ```
+    pub fn with<F, R>(&'static self, f: F) -> R
+    where
+        F: FnOnce(&T) -> R,
+    {
+        match self.try_with(f) {
+            Ok(res) => res,
+            Err(_) => panic!("cannot access a task-local value without setting it"),
+        }
+    }
+
+    /// Accesses the current value of the key. Returns an [`AccessError`] if
+    /// the key is not set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// ylong_runtime::task_local! {
+    ///     static NUMBER: u32;
+    /// }
+    ///
+    /// assert!(NUMBER.try_with(|n| *n).is_err());
+    /// let res = NUMBER.sync_scope(1, || NUMBER.try_with(|n| *n));
+    /// assert_eq!(res, Ok(1));
+    /// ```
+    pub fn try_with<F, R>(&'static self, f: F) -> Result<R, AccessError>
+    where
+        F: FnOnce(&T) -> R,
+    {
+        let res = self.inner.try_with(|cell| {
+            let value = cell.borrow();
+            value.as_ref().map(f)
+        });
+        match res {
+            Ok(Some(res)) => Ok(res),
+            _ => Err(AccessError { _private: () }),
+        }
+    }
+
+    // Moves the value into the thread-local slot while the closure runs, and
+    // moves it back afterwards, even if the closure panics.
+    fn scope_inner<F, R>(&'static self, slot: &mut Option<T>, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        struct Guard<'a, T: 'static> {
+            local: &'static LocalKey<T>,
+            slot: &'a mut Option<T>,
+        }
+
+        impl<T: 'static> Drop for Guard<'_, T> {
+            fn drop(&mut self) {
+                self.local.inner.with(|cell| {
+                    let mut value = cell.borrow_mut();
+                    mem::swap(self.slot, &mut *value);
+                });
+            }
+        }
+
+        self.inner.with(|cell| {
+            let mut value = cell
+                .try_borrow_mut()
+                .expect("cannot set a task-local value while it is being accessed");
+            mem::swap(slot, &mut *value);
+        });
+        let _guard = Guard { local: self, slot };
+        f()
+    }
+}
+
+impl<T: 'static> Debug for LocalKey<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.pad("LocalKey { .. }")
+    }
+}
+
+/// A future that sets a task-local value while it is being polled. It is
+/// returned by [`LocalKey::scope`].
+pub struct TaskLocalFuture<T: 'static, F: Future> {
+    local: &'static LocalKey<T>,
+    slot: Option<T>,
+    future: Option<F>,
+}
+
+impl<T: 'static, F: Future> Future for TaskLocalFuture<T, F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = unsafe { self.get_unchecked_mut() };
+        let local = this.local;
+        let future = &mut this.future;
+        local.scope_inner(&mut this.slot, || match future.as_mut() {
+            // the future will not be moved since it's inside a pinned struct
+            Some(future) => unsafe { Pin::new_unchecked(future) }.poll(cx),
+            None => panic!("`TaskLocalFuture` polled after completion"),
+        })
+    }
+}
+
+impl<T: 'static, F: Future> Drop for TaskLocalFuture<T, F> {
+    fn drop(&mut self) {
+        // Drops the future inside the scope, so that its destructor could still
+        // access the value.
+        // If the thread local is already destroyed, the future is dropped
+        // outside the scope.
+        if self.future.is_some() && self.local.inner.try_with(|_| ()).is_ok() {
+            let future = &mut self.future;
+            self.local.scope_inner(&mut self.slot, || {
+                *future = None;
+            });
+        }
+    }
+}
+
+impl<T: 'static, F: Future> Debug for TaskLocalFuture<T, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.pad("TaskLocalFuture { .. }")
+    }
+}
+
+/// Error returned by [`LocalKey::try_with`] when the task-local value is not
+/// set.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct AccessError {
+    _private: (),
+}
+
+impl Debug for AccessError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AccessError").finish()
+    }
+}
+
+impl Display for AccessError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("task-local value not set")
+    }
+}
+
+impl Error for AccessError {}
+
+#[cfg(test)]
+mod test {
+    use std::cell::Cell;
+    use std::future::Future;
+    use std::rc::Rc;
+    use std::task::Poll;
+
+    use crate::futures::poll_fn;
+    use crate::task::yield_now;
+
+    crate::task_local! {
+        static NUMBER: u32;
+        static DROPPED: Rc<Cell<bool>>;
+    }
+
+    /// UT test cases for `LocalKey::scope`
+    ///
+    /// # Brief
+    /// 1. Access the key outside of any scope, check that it fails
+    /// 2. Access the key inside a scope across yields, check the value
+    /// 3. Set the key again inside a nested scope, check that the inner value
+    ///    is used inside and the outer value is restored afterwards
+    #[test]
+    fn ut_task_local_scope() {
+        assert!(NUMBER.try_with(|n| *n).is_err());
+
+        let res = crate::block_on(NUMBER.scope(1, async {
+            yield_now().await;
+            let outer = NUMBER.with(|n| *n);
+            let inner = NUMBER
+                .scope(2, async {
+                    yield_now().await;
+                    NUMBER.with(|n| *n)
+                })
+                .await;
+            (outer, inner, NUMBER.with(|n| *n))
+        }));
+        assert_eq!(res, (1, 2, 1));
+        assert!(NUMBER.try_with(|n| *n).is_err());
+    }
+
+    /// UT test cases for dropping a `TaskLocalFuture`
+    ///
+    /// # Brief
+    /// 1. Create a scope whose future accesses the key in its destructor
+    /// 2. Drop the scope before it completes
+    /// 3. Check that the value is accessible in the destructor
+    #[test]
+    fn ut_task_local_drop_in_scope() {
+        struct Guard;
+
+        impl Drop for Guard {
+            fn drop(&mut self) {
+                DROPPED.with(|dropped| dropped.set(true));
+            }
+        }
+
+        let dropped = Rc::new(Cell::new(false));
+        let mut fut = Box::pin(DROPPED.scope(dropped.clone(), async {
+            let _guard = Guard;
+            yield_now().await;
+        }));
+        crate::block_on(poll_fn(|cx| {
+            assert!(fut.as_mut().poll(cx).is_pending());
+            Poll::Ready(())
+        }));
+        drop(fut);
+        assert!(dropped.get());
+        assert!(DROPPED.try_with(|_| ()).is_err());
+    }
+}
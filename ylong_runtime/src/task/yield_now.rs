@@ -41,40 +41,40 @@ struct YieldTask(bool);
 impl Future for YieldTask {
     type Output = ();
 
-    #[cfg(not(feature = "ffrt"))]
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         if !self.0 {
             self.0 = true;
-            let ctx = worker::get_current_ctx();
-
-            // Under worker context, we push the waker into the yielded list owned by the
-            // worker to avoid waking the waker immediately. This is because
-            // waking the waker in a worker context will put the task in the
-            // lifo slot, we don't want that.
-            if let Some(ctx) = ctx {
-                let mut yielded = ctx.worker.yielded.borrow_mut();
-                yielded.push(cx.waker().clone());
-            } else {
-                cx.waker().wake_by_ref();
-            }
+            wake_yielded(cx);
             Poll::Pending
         } else {
             Poll::Ready(())
         }
     }
+}
 
-    #[cfg(feature = "ffrt")]
-    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        if !self.0 {
-            self.0 = true;
-            cx.waker().wake_by_ref();
-            Poll::Pending
-        } else {
-            Poll::Ready(())
-        }
+// Wakes the current task for a reschedule after it yields.
+#[cfg(not(feature = "ffrt"))]
+pub(crate) fn wake_yielded(cx: &mut Context<'_>) {
+    let ctx = worker::get_current_ctx();
+
+    // Under worker context, we push the waker into the yielded list owned by the
+    // worker to avoid waking the waker immediately. This is because
+    // waking the waker in a worker context will put the task in the
+    // lifo slot, we don't want that.
+    if let Some(ctx) = ctx {
+        let mut yielded = ctx.worker.yielded.borrow_mut();
+        yielded.push(cx.waker().clone());
+    } else {
+        cx.waker().wake_by_ref();
     }
 }
 
+// Wakes the current task for a reschedule after it yields.
+#[cfg(feature = "ffrt")]
+pub(crate) fn wake_yielded(cx: &mut Context<'_>) {
+    cx.waker().wake_by_ref();
+}
+
 #[cfg(test)]
 mod test {
     use crate::task::yield_now;
@@ -26,6 +26,33 @@ use crate::time::Clock;
 pub(crate) struct TimeDriver {
     start_time: Instant,
     pub(crate) wheel: Mutex<Wheel>,
+    source: Mutex<TimeSource>,
+}
+
+// Where the driver reads the current time from. The time could be paused and
+// then only moves forward when advanced manually or when the runtime is idle.
+struct TimeSource {
+    // The time of the source when it gets paused or resumed.
+    base: Instant,
+    // The real time when the source gets resumed, `None` if it's paused.
+    resumed_at: Option<Instant>,
+}
+
+impl TimeSource {
+    fn new() -> Self {
+        let now = Instant::now();
+        TimeSource {
+            base: now,
+            resumed_at: Some(now),
+        }
+    }
+
+    fn now(&self) -> Instant {
+        match self.resumed_at {
+            Some(resumed_at) => self.base + resumed_at.elapsed(),
+            None => self.base,
+        }
+    }
 }
 
 pub(crate) struct TimeHandle {
@@ -34,9 +61,11 @@ pub(crate) struct TimeHandle {
 
 impl TimeDriver {
     pub(crate) fn initialize() -> (TimeHandle, Arc<TimeDriver>) {
+        let source = TimeSource::new();
         let driver = Arc::new(TimeDriver {
-            start_time: Instant::now(),
+            start_time: source.base,
             wheel: Mutex::new(Wheel::new()),
+            source: Mutex::new(source),
         });
         (
             TimeHandle {
@@ -50,6 +79,32 @@ impl TimeDriver {
         self.start_time
     }
 
+    pub(crate) fn now(&self) -> Instant {
+        self.source.lock().unwrap().now()
+    }
+
+    pub(crate) fn pause(&self) {
+        let mut source = self.source.lock().unwrap();
+        source.base = source.now();
+        source.resumed_at = None;
+    }
+
+    pub(crate) fn resume(&self) {
+        let mut source = self.source.lock().unwrap();
+        if source.resumed_at.is_none() {
+            source.resumed_at = Some(Instant::now());
+        }
+    }
+
+    pub(crate) fn advance(&self, duration: Duration) {
+        let mut source = self.source.lock().unwrap();
+        assert!(
+            source.resumed_at.is_none(),
+            "time can only be advanced while it's paused"
+        );
+        source.base += duration;
+    }
+
     pub(crate) fn timer_register(&self, clock_entry: NonNull<Clock>) -> Result<u64, Error> {
         let mut lock = self.wheel.lock().unwrap();
         lock.insert(clock_entry)
@@ -87,8 +142,8 @@ impl TimeDriver {
     }
 
     pub(crate) fn run(&self) -> Option<Duration> {
-        let now = Instant::now();
-        let now = now
+        let now = self
+            .now()
             .checked_duration_since(self.start_time())
             .expect("driver's start time is later than the current time")
             .as_millis()
@@ -129,6 +184,27 @@ impl TimeDriver {
         }
         timeout
     }
+
+    // Runs the driver when the runtime is idle. If the time is paused, there is
+    // nothing else that could happen before the next timer expires, so the time
+    // jumps straight to its deadline instead of waiting for it.
+    pub(crate) fn run_idle(&self) -> Option<Duration> {
+        loop {
+            let timeout = self.run();
+            match timeout {
+                Some(duration) if !duration.is_zero() => {
+                    let mut source = self.source.lock().unwrap();
+                    if source.resumed_at.is_some() {
+                        return timeout;
+                    }
+                    // The wheel might return an intermediate deadline where its
+                    // entries cascade down, so keep advancing until one fires.
+                    source.base += duration;
+                }
+                _ => return timeout,
+            }
+        }
+    }
 }
 
 impl Deref for TimeHandle {
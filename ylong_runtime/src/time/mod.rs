@@ -14,6 +14,7 @@
 //! Utilities for tracking time.
 
 mod error;
+mod pause;
 mod sleep;
 mod timeout;
 mod timer;
@@ -24,8 +25,10 @@ cfg_not_ffrt!(
 
     pub(crate) use driver::{TimeDriver, TimeHandle};
     pub(crate) use wheel::Clock;
+    pub use pause::{advance, pause, resume};
 );
 
+pub use pause::now;
 pub use sleep::{sleep, sleep_until, Sleep};
 pub use timeout::timeout;
 pub use timer::{periodic_schedule, timer, timer_at, Timer};
@@ -0,0 +1,123 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Instant;
+
+cfg_not_ffrt!(
+    use std::time::Duration;
+
+    use crate::executor::driver::Handle;
+    use crate::task::yield_now;
+);
+
+/// Gets the current time of the runtime's clock.
+///
+/// It's the same as [`Instant::now`] unless the time of the runtime is paused,
+/// in which case the returned time only moves forward when the runtime advances
+/// it. Outside of a runtime, the real time is returned.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use ylong_runtime::builder::RuntimeBuilder;
+/// use ylong_runtime::time;
+///
+/// let runtime = RuntimeBuilder::new_current_thread()
+///     .start_paused(true)
+///     .build()
+///     .unwrap();
+/// runtime.block_on(async {
+///     let start = time::now();
+///     time::sleep(Duration::from_secs(60)).await;
+///     assert!(time::now() - start >= Duration::from_secs(60));
+/// });
+/// ```
+pub fn now() -> Instant {
+    #[cfg(not(feature = "ffrt"))]
+    if let Ok(handle) = Handle::get_handle() {
+        return handle.time.now();
+    }
+    Instant::now()
+}
+
+cfg_not_ffrt!(
+    /// Pauses the time of the current runtime.
+    ///
+    /// Once paused, the time only moves forward through [`advance`], or
+    /// automatically when the runtime has no task to run. In the latter case,
+    /// the time jumps straight to the deadline of the next timer, so sleeps and
+    /// timeouts complete immediately and in a deterministic order. This is
+    /// meant for testing time related logic on a current-thread runtime,
+    /// see `CurrentThreadBuilder::start_paused`. On a multi-thread runtime,
+    /// the time could also jump while tasks are running on other workers.
+    ///
+    /// Pausing the time that is already paused has no effect.
+    ///
+    /// # Panic
+    /// Calling this method outside of a Ylong Runtime causes panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use ylong_runtime::builder::RuntimeBuilder;
+    /// use ylong_runtime::time;
+    ///
+    /// let runtime = RuntimeBuilder::new_current_thread().build().unwrap();
+    /// runtime.block_on(async {
+    ///     time::pause();
+    ///     let start = time::now();
+    ///     time::advance(Duration::from_secs(5)).await;
+    ///     assert_eq!(time::now() - start, Duration::from_secs(5));
+    ///     time::resume();
+    /// });
+    /// ```
+    pub fn pause() {
+        let handle = Handle::get_handle()
+            .unwrap_or_else(|e| panic!("pause time out of worker ctx, error: {e}"));
+        handle.time.pause();
+    }
+
+    /// Resumes the time of the current runtime, it moves forward at the real
+    /// pace again from where it was paused.
+    ///
+    /// Resuming the time that is not paused has no effect.
+    ///
+    /// # Panic
+    /// Calling this method outside of a Ylong Runtime causes panic.
+    pub fn resume() {
+        let handle = Handle::get_handle()
+            .unwrap_or_else(|e| panic!("resume time out of worker ctx, error: {e}"));
+        handle.time.resume();
+    }
+
+    /// Advances the paused time of the current runtime by `duration`.
+    ///
+    /// All the timers expired during the advancement get fired, and the
+    /// current task yields once so that the tasks woken by them could run
+    /// before this method returns.
+    ///
+    /// # Panic
+    /// Calling this method outside of a Ylong Runtime, or while the time is not
+    /// paused, causes panic.
+    pub async fn advance(duration: Duration) {
+        let handle = Handle::get_handle()
+            .unwrap_or_else(|e| panic!("advance time out of worker ctx, error: {e}"));
+        handle.time.advance(duration);
+        handle.time.run();
+        yield_now().await;
+    }
+);
@@ -18,6 +18,9 @@ use std::pin::Pin;
 use std::task::{Context, Poll};
 use std::time::{Duration, Instant};
 
+use crate::task::coop;
+use crate::time;
+
 const TEN_YEARS: Duration = Duration::from_secs(86400 * 365 * 10);
 
 /// Waits until 'instant' has reached.
@@ -43,9 +46,10 @@ pub fn sleep_until(instant: Instant) -> Sleep {
 pub fn sleep(duration: Duration) -> Sleep {
     // If the time reaches the maximum value,
     // then set the default timing time to 10 years.
-    match Instant::now().checked_add(duration) {
+    let now = time::now();
+    match now.checked_add(duration) {
         Some(deadline) => Sleep::new_timeout(deadline),
-        None => Sleep::new_timeout(Instant::now() + TEN_YEARS),
+        None => Sleep::new_timeout(now + TEN_YEARS),
     }
 }
 
@@ -134,6 +138,10 @@ cfg_ffrt!(
         type Output = ();
 
         fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let coop = match coop::poll_budget(cx) {
+                Poll::Ready(coop) => coop,
+                Poll::Pending => return Poll::Pending,
+            };
             let this = self.get_mut();
 
             if this.need_insert {
@@ -153,6 +161,7 @@ cfg_ffrt!(
                     this.inner.timer = Some(FfrtTimerEntry::timer_register(waker_ptr, ms));
                     this.need_insert = false;
                 } else {
+                    coop.made_progress();
                     return Poll::Ready(());
                 }
             }
@@ -160,6 +169,7 @@ cfg_ffrt!(
             // this unwrap is safe since we have already insert the timer into the entry
             let timer = this.inner.timer.as_ref().unwrap();
             if timer.result() {
+                coop.made_progress();
                 Poll::Ready(())
             } else {
                 Poll::Pending
@@ -228,6 +238,10 @@ cfg_not_ffrt!(
         type Output = ();
 
         fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let coop = match coop::poll_budget(cx) {
+                Poll::Ready(coop) => coop,
+                Poll::Pending => return Poll::Pending,
+            };
             let this = unsafe { self.get_unchecked_mut() };
             let driver = &this.inner.handle;
 
@@ -255,6 +269,7 @@ cfg_not_ffrt!(
             }
 
             if this.inner.timer.result() {
+                coop.made_progress();
                 Poll::Ready(())
             } else {
                 Poll::Pending
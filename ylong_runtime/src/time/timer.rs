@@ -17,6 +17,7 @@ use std::task::{Context, Poll};
 use std::time::{Duration, Instant};
 
 use crate::futures::poll_fn;
+use crate::time;
 use crate::time::sleep::{sleep_until, Sleep};
 
 const TEN_YEARS: Duration = Duration::from_secs(86400 * 365 * 10);
@@ -45,7 +46,7 @@ const TEN_YEARS: Duration = Duration::from_secs(86400 * 365 * 10);
 /// ylong_runtime::block_on(handle).unwrap();
 /// ```
 pub fn timer(period: Duration) -> Timer {
-    timer_at(Instant::now(), period)
+    timer_at(time::now(), period)
 }
 
 /// Creates new [`Timer`] that yields with interval of `period`.
@@ -157,7 +158,7 @@ impl Timer {
 
     /// Resets Timer from now on.
     pub fn reset(&mut self) {
-        self.start.as_mut().reset(Instant::now() + self.period);
+        self.start.as_mut().reset(time::now() + self.period);
     }
 
     /// Gets period
@@ -13,10 +13,11 @@
 
 //! Wraps Linux core-affinity syscalls.
 
+use std::fs;
 use std::io::{Error, Result};
 use std::mem::{size_of, zeroed};
 
-use libc::{cpu_set_t, sched_setaffinity, CPU_SET};
+use libc::{cpu_set_t, sched_setaffinity, CPU_SET, CPU_SETSIZE};
 
 /// Sets the tied core cpu of the current thread.
 ///
@@ -40,6 +41,48 @@ pub fn set_current_affinity(cpu: usize) -> Result<()> {
     }
 }
 
+/// Restricts the current thread to the given cpus. Cpus beyond the size of
+/// `cpu_set_t` are ignored.
+///
+/// sched_setaffinity function under linux
+/// # Example
+///
+/// ```no run
+/// use ylong_runtime::util::core_affinity;
+///
+/// let ret = core_affinity::set_current_affinity_set(&[2, 3]).is_ok();
+/// ```
+pub fn set_current_affinity_set(cpus: &[usize]) -> Result<()> {
+    let res: i32 = unsafe {
+        let mut set = new_cpu_set();
+        for cpu in cpus {
+            if *cpu < CPU_SETSIZE as usize {
+                CPU_SET(*cpu, &mut set);
+            }
+        }
+        sched_setaffinity(0, size_of::<cpu_set_t>(), &set)
+    };
+    match res {
+        0 => Ok(()),
+        _ => Err(Error::last_os_error()),
+    }
+}
+
+/// Gets the NUMA node that the cpu belongs to, or `None` if it's unknown.
+///
+/// Reads the `nodeN` entry under `/sys/devices/system/cpu/cpuX` on linux.
+pub fn get_cpu_node(cpu: usize) -> Option<usize> {
+    let entries = fs::read_dir(format!("/sys/devices/system/cpu/cpu{cpu}")).ok()?;
+    entries.filter_map(|entry| entry.ok()).find_map(|entry| {
+        entry
+            .file_name()
+            .to_str()?
+            .strip_prefix("node")?
+            .parse::<usize>()
+            .ok()
+    })
+}
+
 /// Returns an empty cpu set
 fn new_cpu_set() -> cpu_set_t {
     unsafe { zeroed::<cpu_set_t>() }
@@ -51,3 +51,33 @@ pub fn set_current_affinity(cpu: usize) -> Result<()> {
         _ => Ok(()),
     }
 }
+
+/// Restricts the current thread to the given cpus. Cpus beyond the width of
+/// the affinity mask are ignored.
+///
+/// # Example
+///
+/// ```no run
+/// use ylong_runtime::util::core_affinity;
+///
+/// let ret = core_affinity::set_current_affinity_set(&[2, 3]).is_ok();
+/// ```
+pub fn set_current_affinity_set(cpus: &[usize]) -> Result<()> {
+    let mask = cpus
+        .iter()
+        .filter(|cpu| **cpu < usize::BITS as usize)
+        .fold(0usize, |mask, cpu| mask | (1 << cpu));
+    let res = unsafe {
+        let handle = GetCurrentThread();
+        SetThreadAffinityMask(handle, mask)
+    };
+    match res {
+        0 => Err(Error::last_os_error()),
+        _ => Ok(()),
+    }
+}
+
+/// Gets the NUMA node that the cpu belongs to, which is unknown on windows.
+pub fn get_cpu_node(_cpu: usize) -> Option<usize> {
+    None
+}
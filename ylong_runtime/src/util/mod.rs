@@ -25,4 +25,6 @@ pub(crate) mod slots;
 
 #[cfg(not(feature = "ffrt"))]
 pub(crate) mod core_affinity;
+#[cfg(not(feature = "ffrt"))]
+pub(crate) mod thread_name;
 pub(crate) mod num_cpus;
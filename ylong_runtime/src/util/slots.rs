@@ -239,6 +239,12 @@ impl<T> Slots<T> {
         }
         None
     }
+    pub(crate) fn get_mut_by_index(&mut self, key: usize) -> Option<&mut T> {
+        if let Some(entry) = self.entries.get_mut(key) {
+            return entry.data.as_mut();
+        }
+        None
+    }
     pub(crate) fn get_first(&mut self) -> Option<& T> {
         let curr = self.head;
         if let Some(entry) = self.entries.get_mut(curr) {
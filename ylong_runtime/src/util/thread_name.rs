@@ -0,0 +1,40 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Renames the current thread at the OS level.
+
+/// Sets the OS level name of the current thread, which is shown by tools such
+/// as `top` or `ps`. The name of the `std::thread::Thread` is not changed.
+///
+/// The name is truncated to 15 bytes under linux. Does nothing on the other
+/// platforms.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn set_current_name(name: &str) {
+    const MAX_LEN: usize = 15;
+
+    let mut len = name.len().min(MAX_LEN);
+    while !name.is_char_boundary(len) {
+        len -= 1;
+    }
+    let mut buf = [0u8; MAX_LEN + 1];
+    for (dst, src) in buf.iter_mut().zip(name[..len].bytes()) {
+        // an interior nul would cut the name short anyway
+        *dst = if src == 0 { b' ' } else { src };
+    }
+    unsafe {
+        libc::prctl(libc::PR_SET_NAME, buf.as_ptr() as libc::c_ulong, 0, 0, 0);
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+pub(crate) fn set_current_name(_name: &str) {}
@@ -0,0 +1,96 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(all(feature = "multi_instance_runtime", not(feature = "ffrt")))]
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering::{Acquire, Release};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use ylong_runtime::builder::RuntimeBuilder;
+use ylong_runtime::executor::{BlockingInfo, Handle};
+
+/// SDV test cases for the blocking detection
+///
+/// # Brief
+/// 1. Build a runtime with a blocking detection threshold of 10ms and a hook
+///    recording the reports
+/// 2. Spawn a task that returns immediately, and a task that blocks the worker
+///    for 100ms
+/// 3. Check that only the blocking task gets reported, and only once
+#[test]
+fn sdv_blocking_detection() {
+    let infos: Arc<Mutex<Vec<BlockingInfo>>> = Arc::new(Mutex::new(Vec::new()));
+    let infos_clone = infos.clone();
+    let runtime = RuntimeBuilder::new_multi_thread()
+        .worker_num(2)
+        .blocking_detection(Duration::from_millis(10))
+        .blocking_detection_hook(move |info| infos_clone.lock().unwrap().push(info.clone()))
+        .build()
+        .unwrap();
+
+    let quick = runtime.spawn(async { 1 });
+    assert_eq!(runtime.block_on(quick).unwrap(), 1);
+
+    let blocking = runtime.spawn(async {
+        thread::sleep(Duration::from_millis(100));
+    });
+    let id = blocking.id();
+    runtime.block_on(blocking).unwrap();
+
+    let infos = infos.lock().unwrap();
+    assert_eq!(infos.len(), 1);
+    assert_eq!(infos[0].task_id(), id);
+    assert!(infos[0].task_name().is_none());
+    assert!(infos[0].elapsed() >= Duration::from_millis(10));
+    assert!(infos[0].worker_index() < 2);
+}
+
+/// SDV test cases for handing the local queue of a blocked worker over to the
+/// other workers
+///
+/// # Brief
+/// 1. Build a runtime with two workers and the offloading enabled
+/// 2. Spawn a task that spawns 10 tasks onto its own worker and then blocks the
+///    worker until these tasks finish
+/// 3. Check that the tasks get finished by the other worker
+#[test]
+fn sdv_blocking_detection_offload() {
+    let runtime = RuntimeBuilder::new_multi_thread()
+        .worker_num(2)
+        .blocking_detection(Duration::from_millis(10))
+        .blocking_detection_offload(true)
+        .build()
+        .unwrap();
+
+    let handle = runtime.spawn(async {
+        let count = Arc::new(AtomicUsize::new(0));
+        for _ in 0..10 {
+            let count = count.clone();
+            Handle::current().spawn(async move {
+                count.fetch_add(1, Release);
+            });
+        }
+        let start = Instant::now();
+        while count.load(Acquire) < 10 {
+            if start.elapsed() > Duration::from_secs(5) {
+                return false;
+            }
+            thread::sleep(Duration::from_millis(1));
+        }
+        true
+    });
+    assert!(runtime.block_on(handle).unwrap());
+}
@@ -0,0 +1,135 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(all(feature = "time", feature = "sync"))]
+
+use std::time::Duration;
+
+use ylong_runtime::sync::broadcast::{self, RecvError, RecvTimeoutError, TryRecvError};
+
+/// SDV test cases for broadcast multi-subscriber delivery.
+///
+/// # Brief
+/// 1. Create a broadcast channel and spawn several subscriber tasks waiting for
+///    values.
+/// 2. Send values from two senders, then drop them.
+/// 3. Check that every subscriber receives all the values in order and then
+///    gets closed.
+#[test]
+fn sdv_broadcast_multi_subscriber_test() {
+    let (tx, rx) = broadcast::channel::<usize>(128);
+    let mut handles = vec![];
+    for _ in 0..4 {
+        let mut rx = tx.subscribe();
+        handles.push(ylong_runtime::spawn(async move {
+            let mut values = vec![];
+            loop {
+                match rx.recv().await {
+                    Ok(val) => values.push(val),
+                    Err(RecvError::Closed) => return values,
+                    Err(RecvError::Lagged(n)) => panic!("lagged by {n}"),
+                }
+            }
+        }));
+    }
+    drop(rx);
+
+    let tx2 = tx.clone();
+    for i in 0..50 {
+        assert_eq!(tx.send(i * 2), Ok(4));
+        assert_eq!(tx2.send(i * 2 + 1), Ok(4));
+    }
+    drop(tx);
+    drop(tx2);
+    for handle in handles {
+        let values = ylong_runtime::block_on(handle).unwrap();
+        assert_eq!(values, (0..100).collect::<Vec<_>>());
+    }
+}
+
+/// SDV test cases for broadcast `RecvError::Lagged`.
+///
+/// # Brief
+/// 1. Create a broadcast channel with capacity 4.
+/// 2. Send 8 values, subscribe another receiver, and send 2 more values.
+/// 3. Check that the first receiver lags by 6 values, and then receives the
+///    last 4 values in order.
+/// 4. Check that the new receiver only receives the values sent after it
+///    subscribes.
+#[test]
+fn sdv_broadcast_lagged_test() {
+    let (tx, mut rx) = broadcast::channel(4);
+    for i in 0..8 {
+        assert_eq!(tx.send(i), Ok(1));
+    }
+    let mut rx2 = tx.subscribe();
+    for i in 8..10 {
+        assert_eq!(tx.send(i), Ok(2));
+    }
+
+    ylong_runtime::block_on(async move {
+        assert_eq!(rx.len(), 10);
+        assert_eq!(rx.recv().await, Err(RecvError::Lagged(6)));
+        for i in 6..10 {
+            assert_eq!(rx.recv().await, Ok(i));
+        }
+        assert_eq!(rx.try_recv(), Err(TryRecvError::Empty));
+
+        assert_eq!(rx2.recv().await, Ok(8));
+        assert_eq!(rx2.recv().await, Ok(9));
+        assert_eq!(rx2.try_recv(), Err(TryRecvError::Empty));
+    });
+}
+
+/// SDV test cases for closing a broadcast channel.
+///
+/// # Brief
+/// 1. Create a broadcast channel and spawn a task waiting on a receiver.
+/// 2. Send a value with one sender, then drop all the senders.
+/// 3. Check that the waiting task gets the value and is then woken up with
+///    `RecvError::Closed`.
+/// 4. Check that another receiver still gets the value left in the channel
+///    before getting closed.
+/// 5. Check that sending fails after all the receivers are dropped.
+#[test]
+fn sdv_broadcast_close_test() {
+    let (tx, mut rx) = broadcast::channel(16);
+    let mut rx2 = tx.subscribe();
+    let tx2 = tx.clone();
+    let handle = ylong_runtime::spawn(async move {
+        assert_eq!(rx.recv().await, Ok(1));
+        assert_eq!(rx.recv().await, Err(RecvError::Closed));
+        rx
+    });
+    assert_eq!(tx.send(1), Ok(2));
+    drop(tx);
+    // lets the task wait for the next value before the channel gets closed
+    std::thread::sleep(Duration::from_millis(50));
+    drop(tx2);
+    let rx = ylong_runtime::block_on(handle).unwrap();
+
+    ylong_runtime::block_on(async move {
+        assert_eq!(rx2.recv().await, Ok(1));
+        assert_eq!(rx2.recv().await, Err(RecvError::Closed));
+        assert_eq!(
+            rx2.recv_timeout(Duration::from_millis(10)).await,
+            Err(RecvTimeoutError::Closed)
+        );
+        assert_eq!(rx2.try_recv(), Err(TryRecvError::Closed));
+    });
+    drop(rx);
+
+    let (tx, rx) = broadcast::channel(16);
+    drop(rx);
+    assert!(tx.send(1).is_err());
+}
@@ -13,9 +13,12 @@
 
 #[cfg(feature = "multi_instance_runtime")]
 mod multi_test {
+    use std::sync::atomic::{AtomicUsize, Ordering};
     use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::Duration;
 
-    use ylong_runtime::builder::RuntimeBuilder;
+    use ylong_runtime::builder::{RuntimeBuilder, WorkerKind};
 
     // async task
     async fn test_future(num: usize) -> usize {
@@ -79,6 +82,124 @@ mod multi_test {
         let a = x.lock().unwrap();
         assert_eq!(*a, 1);
     }
+
+    /// SDV test cases for `on_worker_start()` and `on_worker_stop()`.
+    ///
+    /// # Brief
+    /// 1. Create Runtime with 4 workers, whose hooks record the information of
+    ///    the started and stopped threads.
+    /// 2. Executing an async task and a blocking task.
+    /// 3. Drop the runtime.
+    /// 4. Check if every async worker and the blocking thread are recorded.
+    #[test]
+    fn sdv_set_builder_worker_hooks() {
+        let started = Arc::new(Mutex::new(Vec::new()));
+        let stopped = Arc::new(Mutex::new(Vec::new()));
+        let started_cpy = started.clone();
+        let stopped_cpy = stopped.clone();
+
+        let runtime = RuntimeBuilder::new_multi_thread()
+            .worker_num(4)
+            .on_worker_start(move |info| started_cpy.lock().unwrap().push(*info))
+            .on_worker_stop(move |info| stopped_cpy.lock().unwrap().push(*info))
+            .build()
+            .unwrap();
+        let handle = runtime.spawn(test_future(1));
+        assert_eq!(runtime.block_on(handle).unwrap(), 1);
+        let handle = runtime.spawn_blocking(|| 2);
+        assert_eq!(runtime.block_on(handle).unwrap(), 2);
+        drop(runtime);
+
+        for infos in [started, stopped] {
+            let infos = infos.lock().unwrap();
+            let mut indexes = infos
+                .iter()
+                .filter(|info| info.kind() == WorkerKind::Async)
+                .map(|info| info.index())
+                .collect::<Vec<_>>();
+            indexes.sort_unstable();
+            assert_eq!(indexes, vec![0, 1, 2, 3]);
+            assert!(infos.iter().any(|info| info.kind() == WorkerKind::Blocking));
+        }
+    }
+
+    // Gets the cpus that the current thread is allowed to run on.
+    #[cfg(target_os = "linux")]
+    fn cpus_allowed() -> String {
+        let status = std::fs::read_to_string("/proc/thread-self/status").unwrap();
+        status
+            .lines()
+            .find_map(|line| line.strip_prefix("Cpus_allowed_list:"))
+            .unwrap()
+            .trim()
+            .to_string()
+    }
+
+    /// SDV test cases for `worker_cpu_set()` and `blocking_cpu_set()`.
+    ///
+    /// # Brief
+    /// 1. Create Runtime whose workers and blocking threads are placed on cpu
+    ///    0, with NUMA-aware stealing turned on.
+    /// 2. Executing an async task and a blocking task, which get the cpus their
+    ///    threads are allowed to run on.
+    /// 3. Check if the threads are restricted to cpu 0.
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn sdv_set_builder_cpu_set() {
+        let runtime = RuntimeBuilder::new_multi_thread()
+            .worker_num(2)
+            .is_affinity(true)
+            .worker_cpu_set(vec![0])
+            .blocking_cpu_set(vec![0])
+            .numa_aware_steal(true)
+            .build()
+            .unwrap();
+
+        let handle = runtime.spawn(async { cpus_allowed() });
+        assert_eq!(runtime.block_on(handle).unwrap(), "0");
+        let handle = runtime.spawn_blocking(cpus_allowed);
+        assert_eq!(runtime.block_on(handle).unwrap(), "0");
+
+        let handles = (0..100)
+            .map(|i| runtime.spawn(test_future(i)))
+            .collect::<Vec<_>>();
+        for (i, handle) in handles.into_iter().enumerate() {
+            assert_eq!(runtime.block_on(handle).unwrap(), i);
+        }
+    }
+
+    /// SDV test cases for `on_thread_park()` and `on_thread_unpark()`.
+    ///
+    /// # Brief
+    /// 1. Create Runtime with 2 workers, whose hooks count the parking and
+    ///    unparking.
+    /// 2. Executing an async task after the workers become idle.
+    /// 3. Check if the workers have parked and unparked.
+    #[test]
+    fn sdv_set_builder_park_hooks() {
+        let parked = Arc::new(AtomicUsize::new(0));
+        let unparked = Arc::new(AtomicUsize::new(0));
+        let parked_cpy = parked.clone();
+        let unparked_cpy = unparked.clone();
+
+        let runtime = RuntimeBuilder::new_multi_thread()
+            .worker_num(2)
+            .on_thread_park(move |info| {
+                assert_eq!(info.kind(), WorkerKind::Async);
+                parked_cpy.fetch_add(1, Ordering::SeqCst);
+            })
+            .on_thread_unpark(move |_| {
+                unparked_cpy.fetch_add(1, Ordering::SeqCst);
+            })
+            .build()
+            .unwrap();
+        thread::sleep(Duration::from_millis(50));
+        let handle = runtime.spawn(test_future(1));
+        assert_eq!(runtime.block_on(handle).unwrap(), 1);
+
+        assert!(parked.load(Ordering::SeqCst) > 0);
+        assert!(unparked.load(Ordering::SeqCst) > 0);
+    }
 }
 
 #[cfg(feature = "current_thread_runtime")]
@@ -0,0 +1,142 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(all(
+    not(feature = "ffrt"),
+    feature = "multi_instance_runtime",
+    feature = "sync"
+))]
+
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering::{Acquire, Release};
+use std::sync::Arc;
+
+use ylong_runtime::builder::RuntimeBuilder;
+use ylong_runtime::executor::Runtime;
+use ylong_runtime::sync::mpsc::unbounded_channel;
+use ylong_runtime::sync::Mutex;
+
+// Spawns a task which keeps receiving from a channel that is always ready, and
+// another task which stops it.
+fn channel_starvation(runtime: &Runtime) {
+    let stop = Arc::new(AtomicBool::new(false));
+    let stop_clone = stop.clone();
+    let busy = runtime.spawn(async move {
+        let (tx, mut rx) = unbounded_channel();
+        let mut count = 0;
+        while !stop_clone.load(Acquire) {
+            tx.send(1).unwrap();
+            count += rx.recv().await.unwrap();
+        }
+        count
+    });
+    let stopper = runtime.spawn(async move {
+        stop.store(true, Release);
+    });
+    runtime.block_on(stopper).unwrap();
+    assert!(runtime.block_on(busy).unwrap() > 0);
+}
+
+/// SDV test cases for the task budget of channels on a multi-thread runtime
+///
+/// # Brief
+/// 1. Create a multi-thread runtime with one worker
+/// 2. Spawn a task which keeps receiving from a channel that is always ready
+/// 3. Spawn another task which stops the first one
+/// 4. Check that the second task gets the chance to run
+#[test]
+fn sdv_coop_budget_channel_multi_thread() {
+    let runtime = RuntimeBuilder::new_multi_thread()
+        .worker_num(1)
+        .task_budget(32)
+        .build()
+        .unwrap();
+    channel_starvation(&runtime);
+}
+
+/// SDV test cases for the task budget of channels on a current-thread runtime
+///
+/// # Brief
+/// 1. Create a current-thread runtime
+/// 2. Spawn a task which keeps receiving from a channel that is always ready
+/// 3. Spawn another task which stops the first one
+/// 4. Check that the second task gets the chance to run
+#[test]
+#[cfg(feature = "current_thread_runtime")]
+fn sdv_coop_budget_channel_current_thread() {
+    let runtime = RuntimeBuilder::new_current_thread().build().unwrap();
+    channel_starvation(&runtime);
+}
+
+/// SDV test cases for the task budget of mutex
+///
+/// # Brief
+/// 1. Create a multi-thread runtime with one worker
+/// 2. Spawn a task which keeps locking a mutex without contention
+/// 3. Spawn another task which stops the first one
+/// 4. Check that the second task gets the chance to run
+#[test]
+fn sdv_coop_budget_mutex() {
+    let runtime = RuntimeBuilder::new_multi_thread()
+        .worker_num(1)
+        .build()
+        .unwrap();
+    let stop = Arc::new(AtomicBool::new(false));
+    let stop_clone = stop.clone();
+    let busy = runtime.spawn(async move {
+        let mutex = Mutex::new(0);
+        while !stop_clone.load(Acquire) {
+            *mutex.lock().await += 1;
+        }
+        let count = *mutex.lock().await;
+        count
+    });
+    let stopper = runtime.spawn(async move {
+        stop.store(true, Release);
+    });
+    runtime.block_on(stopper).unwrap();
+    assert!(runtime.block_on(busy).unwrap() > 0);
+}
+
+/// SDV test cases for the task budget of pending operations
+///
+/// # Brief
+/// 1. Create a multi-thread runtime with one worker and a budget of 2
+/// 2. Inside one poll of a task, poll the receiver of an empty channel several
+///    times
+/// 3. Send a value, check that the receiver gets it inside the same poll, since
+///    the pending polls do not use up the budget
+#[test]
+fn sdv_coop_budget_pending() {
+    use std::task::Poll;
+
+    use ylong_runtime::futures::poll_fn;
+
+    let runtime = RuntimeBuilder::new_multi_thread()
+        .worker_num(1)
+        .task_budget(2)
+        .build()
+        .unwrap();
+    let handle = runtime.spawn(async move {
+        let (tx, mut rx) = unbounded_channel();
+        poll_fn(|cx| {
+            for _ in 0..10 {
+                assert!(rx.poll_recv(cx).is_pending());
+            }
+            tx.send(1).unwrap();
+            Poll::Ready(rx.poll_recv(cx))
+        })
+        .await
+    });
+    assert_eq!(runtime.block_on(handle).unwrap(), Poll::Ready(Ok(1)));
+}
@@ -0,0 +1,126 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(all(
+    feature = "current_thread_runtime",
+    feature = "macros",
+    not(feature = "ffrt")
+))]
+
+use std::sync::{Arc, Mutex};
+
+use ylong_runtime::builder::RuntimeBuilder;
+use ylong_runtime::executor::{Runtime, ScheduleTrace};
+use ylong_runtime::task::yield_now;
+
+fn select_branches(seed: u64) -> Vec<usize> {
+    let runtime = RuntimeBuilder::new_current_thread()
+        .rng_seed(seed)
+        .build()
+        .unwrap();
+    runtime.block_on(async {
+        let mut branches = Vec::new();
+        for _ in 0..32 {
+            let branch = ylong_runtime::select! {
+                a = async { 0 } => a,
+                b = async { 1 } => b,
+                c = async { 2 } => c,
+                d = async { 3 } => d,
+            };
+            branches.push(branch);
+        }
+        branches
+    })
+}
+
+// Spawns three tasks that log their index, yield, and log again.
+fn run_logged(runtime: &Runtime) -> Vec<usize> {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let handles: Vec<_> = (0..3)
+        .map(|i| {
+            let log = log.clone();
+            runtime.spawn(async move {
+                log.lock().unwrap().push(i);
+                yield_now().await;
+                log.lock().unwrap().push(i);
+            })
+        })
+        .collect();
+    runtime.block_on(async {
+        for handle in handles {
+            handle.await.unwrap();
+        }
+    });
+    let log = log.lock().unwrap().clone();
+    log
+}
+
+/// SDV test cases for seeding the branch order of `select!`
+///
+/// # Brief
+/// 1. Runs a `select!` with four ready branches for several times on a
+///    current-thread runtime with a seed
+/// 2. Does it again on another runtime with the same seed
+/// 3. Checks that the branches get selected in the same order
+/// 4. Checks that the branches are still randomly selected
+#[test]
+fn sdv_rng_seed_select() {
+    let first = select_branches(7);
+    let second = select_branches(7);
+    assert_eq!(first, second);
+    assert!(first.iter().any(|branch| *branch != first[0]));
+}
+
+/// SDV test cases for recording the poll order of a current-thread runtime
+///
+/// # Brief
+/// 1. Builds a current-thread runtime that records its schedule
+/// 2. Spawns three tasks that yield once
+/// 3. Checks that the trace contains the polls in FIFO order
+/// 4. Checks that a runtime without recording has no trace
+#[test]
+fn sdv_record_schedule() {
+    let runtime = RuntimeBuilder::new_current_thread()
+        .record_schedule(true)
+        .build()
+        .unwrap();
+    assert_eq!(run_logged(&runtime), vec![0, 1, 2, 0, 1, 2]);
+    let trace = runtime.schedule_trace().unwrap();
+    assert_eq!(trace.polls(), &[0, 1, 2, 0, 1, 2]);
+    assert_eq!(trace.to_string(), "0,1,2,0,1,2");
+
+    let runtime = RuntimeBuilder::new_current_thread().build().unwrap();
+    assert!(runtime.schedule_trace().is_none());
+}
+
+/// SDV test cases for replaying a poll order on a current-thread runtime
+///
+/// # Brief
+/// 1. Parses a trace that polls the tasks in a different order than FIFO
+/// 2. Builds a current-thread runtime that replays the trace
+/// 3. Spawns three tasks that yield once
+/// 4. Checks that the tasks get polled in the order of the trace
+/// 5. Checks that the recorded trace of the replay is the same
+#[test]
+fn sdv_replay_schedule() {
+    let trace: ScheduleTrace = "2,0,0,1,2,1".parse().unwrap();
+    let runtime = RuntimeBuilder::new_current_thread()
+        .replay_schedule(trace.clone())
+        .build()
+        .unwrap();
+    assert_eq!(run_logged(&runtime), vec![2, 0, 0, 1, 2, 1]);
+    assert_eq!(runtime.schedule_trace().unwrap(), trace);
+
+    assert!("0,x".parse::<ScheduleTrace>().is_err());
+    assert!("".parse::<ScheduleTrace>().unwrap().polls().is_empty());
+}
@@ -22,10 +22,18 @@ mod async_fs;
 mod async_pool;
 mod async_read;
 mod block_on;
+mod blocking_detection;
+mod broadcast;
 mod builder;
 mod cancel_safe;
+mod coop;
+mod deterministic_schedule;
 mod error;
+mod handle;
 mod join_set;
+mod local_set;
+mod metrics;
+mod mpmc_test;
 mod mpsc_test;
 mod mutex;
 mod par_iter;
@@ -35,13 +43,21 @@ mod process;
 mod pty_process;
 mod select;
 mod semaphore_test;
+mod shutdown;
 mod signal;
 mod singleton_runtime;
 mod spawn;
 mod spawn_blocking;
 mod sync;
 mod task_cancel;
+mod task_dump;
+mod task_id;
+mod task_local;
+mod task_panic;
+mod task_scope;
 mod tcp_test;
+mod time_pause;
 mod timer_test;
 mod udp_test;
 mod uds_test;
+mod worker_num;
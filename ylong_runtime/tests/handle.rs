@@ -0,0 +1,91 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(all(not(feature = "ffrt"), feature = "multi_instance_runtime"))]
+
+use std::thread;
+
+use ylong_runtime::builder::RuntimeBuilder;
+use ylong_runtime::error::ErrorKind;
+use ylong_runtime::executor::Handle;
+
+/// SDV test cases for spawning through a cloned `Handle`
+///
+/// # Brief
+/// 1. Create a multi-thread runtime and clone its handle
+/// 2. Spawn async and blocking tasks through the handle on another thread
+/// 3. Check the results of the tasks
+#[test]
+fn sdv_handle_spawn_from_other_thread() {
+    let runtime = RuntimeBuilder::new_multi_thread()
+        .worker_num(2)
+        .build()
+        .unwrap();
+    let handle = runtime.handle().clone();
+
+    let join = thread::spawn(move || {
+        let task = handle.spawn(async { 1 });
+        let blocking = handle.spawn_blocking(|| 2);
+        handle.block_on(async move { task.await.unwrap() + blocking.await.unwrap() })
+    });
+    assert_eq!(join.join().unwrap(), 3);
+}
+
+/// SDV test cases for `Handle::current` inside spawned tasks
+///
+/// # Brief
+/// 1. Create a multi-thread runtime
+/// 2. Spawn a blocking task and get the current handle inside it
+/// 3. Spawn an async task through the current handle
+/// 4. Check that the task runs on the same runtime
+#[test]
+fn sdv_handle_current_in_spawn_blocking() {
+    let runtime = RuntimeBuilder::new_multi_thread()
+        .worker_num(1)
+        .build()
+        .unwrap();
+
+    let task = runtime.spawn_blocking(|| {
+        let handle = Handle::current();
+        let task = handle.spawn(async { Handle::current() });
+        handle.block_on(task).unwrap()
+    });
+    let handle = runtime.block_on(task).unwrap();
+    let task = handle.spawn(async { 1 });
+    assert_eq!(runtime.block_on(task).unwrap(), 1);
+}
+
+/// SDV test cases for using a `Handle` after its runtime is dropped
+///
+/// # Brief
+/// 1. Create a multi-thread runtime and clone its handle
+/// 2. Drop the runtime
+/// 3. Spawn tasks through the handle
+/// 4. Check that the tasks fail with `RuntimeShutdown`
+#[test]
+fn sdv_handle_after_runtime_dropped() {
+    let runtime = RuntimeBuilder::new_multi_thread()
+        .worker_num(1)
+        .build()
+        .unwrap();
+    let handle = runtime.handle().clone();
+    drop(runtime);
+
+    let task = handle.spawn(async { 1 });
+    let err = handle.block_on(task).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::RuntimeShutdown);
+
+    let task = handle.spawn_blocking(|| 1);
+    let err = handle.block_on(task).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::RuntimeShutdown);
+}
@@ -300,3 +300,29 @@ fn sdv_join_set_drop() {
         drop(set);
     });
 }
+
+/// SDV test cases for `JoinSet::join_next_with_id`
+///
+/// # Brief
+/// 1. Create a JoinSet.
+/// 2. Spawn 10 tasks that return their own task IDs.
+/// 3. Wait for the tasks using `join_next_with_id`.
+/// 4. Check that each returned ID matches the one of its `CancelHandle` and the
+///    task output.
+#[test]
+fn sdv_join_set_join_next_with_id() {
+    ylong_runtime::block_on(async move {
+        let mut set = JoinSet::new();
+        let mut ids = Vec::new();
+        for _ in 0..10 {
+            let handle = set.spawn(async move { ylong_runtime::task::id() });
+            ids.push(handle.id());
+        }
+        while let Some((id, ret)) = set.join_next_with_id().await {
+            assert_eq!(ret.unwrap(), id);
+            let pos = ids.iter().position(|x| *x == id).unwrap();
+            ids.remove(pos);
+        }
+        assert!(ids.is_empty());
+    });
+}
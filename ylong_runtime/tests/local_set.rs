@@ -0,0 +1,142 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(not(feature = "ffrt"))]
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::thread;
+
+use ylong_runtime::task::{spawn_local, yield_now, LocalSet};
+
+const TASK_NUM: usize = 100;
+
+// Spawns tasks sharing a `Rc<RefCell<_>>` and checks that they all run on the
+// current thread.
+async fn spawn_shared_tasks() -> usize {
+    let thread_id = thread::current().id();
+    let shared = Rc::new(RefCell::new(0));
+    let mut handles = Vec::with_capacity(TASK_NUM);
+    for _ in 0..TASK_NUM {
+        let shared = shared.clone();
+        handles.push(spawn_local(async move {
+            yield_now().await;
+            assert_eq!(thread::current().id(), thread_id);
+            *shared.borrow_mut() += 1;
+        }));
+    }
+    for handle in handles {
+        handle.await.unwrap();
+    }
+    let res = *shared.borrow();
+    res
+}
+
+/// SDV test cases for `LocalSet` with the global `block_on`
+///
+/// # Brief
+/// 1. Drive a `LocalSet` by the global `block_on`
+/// 2. Spawn `!Send` tasks inside the set, and a `Send` task onto the runtime
+/// 3. Check that all the tasks finish
+#[test]
+fn sdv_local_set_block_on() {
+    let local = LocalSet::new();
+    let res = ylong_runtime::block_on(local.run_until(async {
+        let other = ylong_runtime::spawn(async { 1 }).await.unwrap();
+        spawn_shared_tasks().await + other
+    }));
+    assert_eq!(res, TASK_NUM + 1);
+}
+
+/// SDV test cases for `LocalSet` on runtimes built by `RuntimeBuilder`
+///
+/// # Brief
+/// 1. Create a multi-thread runtime and a current-thread runtime
+/// 2. Drive a `LocalSet` on each of them through `LocalSet::block_on`
+/// 3. Check that all the `!Send` tasks finish on the current thread
+#[test]
+#[cfg(feature = "multi_instance_runtime")]
+fn sdv_local_set_runtime() {
+    use ylong_runtime::builder::RuntimeBuilder;
+
+    let runtime = RuntimeBuilder::new_multi_thread()
+        .worker_num(2)
+        .build()
+        .unwrap();
+    let local = LocalSet::new();
+    assert_eq!(local.block_on(&runtime, spawn_shared_tasks()), TASK_NUM);
+
+    #[cfg(feature = "current_thread_runtime")]
+    {
+        let runtime = RuntimeBuilder::new_current_thread().build().unwrap();
+        let local = LocalSet::new();
+        assert_eq!(local.block_on(&runtime, spawn_shared_tasks()), TASK_NUM);
+    }
+}
+
+/// SDV test cases for waking local tasks from other threads
+///
+/// # Brief
+/// 1. Spawn a `!Send` task that waits on a channel
+/// 2. Send values through the channel from another thread
+/// 3. Check that the task receives all the values
+#[test]
+#[cfg(feature = "sync")]
+fn sdv_local_set_wake_from_other_thread() {
+    use ylong_runtime::sync::mpsc::unbounded_channel;
+
+    let (tx, mut rx) = unbounded_channel();
+    let local = LocalSet::new();
+    let received = Rc::new(RefCell::new(Vec::new()));
+    let received_clone = received.clone();
+    let handle = local.spawn_local(async move {
+        while let Ok(value) = rx.recv().await {
+            received_clone.borrow_mut().push(value);
+        }
+    });
+
+    let sender = thread::spawn(move || {
+        for i in 0..TASK_NUM {
+            tx.send(i).unwrap();
+        }
+    });
+    ylong_runtime::block_on(local.run_until(handle)).unwrap();
+    sender.join().unwrap();
+    assert_eq!(*received.borrow(), (0..TASK_NUM).collect::<Vec<_>>());
+}
+
+/// SDV test cases for awaiting a `LocalSet`
+///
+/// # Brief
+/// 1. Spawn tasks onto a `LocalSet` that sleep for a while
+/// 2. Await the set on the current thread
+/// 3. Check that the set completes after all the tasks finish
+#[test]
+#[cfg(feature = "time")]
+fn sdv_local_set_await_all() {
+    use std::time::Duration;
+
+    use ylong_runtime::time::sleep;
+
+    let local = LocalSet::new();
+    let shared = Rc::new(RefCell::new(0));
+    for i in 0..10 {
+        let shared = shared.clone();
+        local.spawn_local(async move {
+            sleep(Duration::from_millis(i)).await;
+            *shared.borrow_mut() += 1;
+        });
+    }
+    ylong_runtime::block_on(local);
+    assert_eq!(*shared.borrow(), 10);
+}
@@ -0,0 +1,88 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(all(
+    feature = "metrics",
+    feature = "multi_instance_runtime",
+    not(feature = "ffrt")
+))]
+
+use std::thread;
+use std::time::Duration;
+
+use ylong_runtime::builder::RuntimeBuilder;
+
+/// SDV test cases for the poll-time and scheduling-latency histograms
+///
+/// # Brief
+/// 1. Build a runtime with one worker, the histograms and a long-poll threshold
+///    of 20ms
+/// 2. Spawn a task that returns immediately and a task that blocks the worker
+///    for 30ms
+/// 3. Check that both polls are counted in the histograms, and the blocking
+///    poll is counted as a long poll
+#[test]
+fn sdv_metrics_poll_histogram() {
+    let runtime = RuntimeBuilder::new_multi_thread()
+        .worker_num(1)
+        .metrics_poll_histogram(vec![Duration::from_millis(50), Duration::from_millis(1)])
+        .metrics_long_poll_threshold(Duration::from_millis(20))
+        .build()
+        .unwrap();
+
+    let quick = runtime.spawn(async { 1 });
+    let blocking = runtime.spawn(async { thread::sleep(Duration::from_millis(30)) });
+    runtime.block_on(quick).unwrap();
+    runtime.block_on(blocking).unwrap();
+
+    let metrics = runtime.metrics();
+    // the poll gets recorded right after the JoinHandle is woken up
+    for _ in 0..100 {
+        if metrics.worker_long_poll_count(0) == Some(1) {
+            break;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+    assert_eq!(metrics.worker_long_poll_count(0), Some(1));
+
+    let poll_time = metrics.worker_poll_time_histogram(0).unwrap();
+    assert_eq!(poll_time.num_buckets(), 3);
+    assert_eq!(
+        poll_time.bucket_range(1),
+        Duration::from_millis(1)..Duration::from_millis(50)
+    );
+    assert_eq!(poll_time.total_count(), 2);
+    assert_eq!(poll_time.bucket_count(1), 1);
+
+    let latency = metrics.worker_schedule_latency_histogram(0).unwrap();
+    assert_eq!(latency.total_count(), 2);
+}
+
+/// SDV test cases for the poll statistics when they are not enabled
+///
+/// # Brief
+/// 1. Build a runtime without enabling the poll statistics
+/// 2. Run a task, check that the histograms and the long-poll count are None
+#[test]
+fn sdv_metrics_poll_histogram_disabled() {
+    let runtime = RuntimeBuilder::new_multi_thread()
+        .worker_num(1)
+        .build()
+        .unwrap();
+    runtime.block_on(runtime.spawn(async { 1 })).unwrap();
+
+    let metrics = runtime.metrics();
+    assert!(metrics.worker_poll_time_histogram(0).is_none());
+    assert!(metrics.worker_schedule_latency_histogram(0).is_none());
+    assert!(metrics.worker_long_poll_count(0).is_none());
+}
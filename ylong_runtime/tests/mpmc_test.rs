@@ -0,0 +1,195 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(all(feature = "time", feature = "sync"))]
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering::SeqCst;
+use std::sync::Arc;
+use std::time::Duration;
+
+use ylong_runtime::sync::error::{RecvError, RecvTimeoutError, TryRecvError};
+use ylong_runtime::sync::mpmc::{bounded_channel, unbounded_channel};
+
+/// SDV test cases for mpmc `UnboundedReceiver`.
+///
+/// # Brief
+/// 1. Create a unbounded mpmc channel and clone the receiver.
+/// 2. Try receiving from both receivers before and after sending values.
+/// 3. Try receiving after the sender has been dropped.
+#[test]
+fn sdv_mpmc_unbounded_try_recv_test() {
+    let (tx, rx) = unbounded_channel();
+    let rx2 = rx.clone();
+    assert_eq!(rx.try_recv(), Err(TryRecvError::Empty));
+    assert!(tx.send(1).is_ok());
+    assert!(tx.send(2).is_ok());
+    assert_eq!(rx2.len(), 2);
+    assert_eq!(rx2.try_recv(), Ok(1));
+    assert_eq!(rx.try_recv(), Ok(2));
+    assert!(rx.is_empty());
+    drop(tx);
+    assert_eq!(rx.try_recv(), Err(TryRecvError::Closed));
+    assert_eq!(rx2.try_recv(), Err(TryRecvError::Closed));
+}
+
+/// SDV test cases for mpmc `UnboundedReceiver`.
+///
+/// # Brief
+/// 1. Create a unbounded mpmc channel and spawn several consumer tasks.
+/// 2. Send values from several producers then drop them.
+/// 3. Check every value is received exactly once and all consumers finish.
+#[test]
+fn sdv_mpmc_unbounded_multi_consumer_test() {
+    let (tx, rx) = unbounded_channel::<usize>();
+    let sum = Arc::new(AtomicUsize::new(0));
+    let count = Arc::new(AtomicUsize::new(0));
+    let mut handles = vec![];
+    for _ in 0..4 {
+        let rx = rx.clone();
+        let sum = sum.clone();
+        let count = count.clone();
+        handles.push(ylong_runtime::spawn(async move {
+            while let Ok(val) = rx.recv().await {
+                sum.fetch_add(val, SeqCst);
+                count.fetch_add(1, SeqCst);
+            }
+        }));
+    }
+    drop(rx);
+    for i in 0..4 {
+        let tx = tx.clone();
+        ylong_runtime::spawn(async move {
+            for j in 0..250 {
+                assert!(tx.send(i * 250 + j).is_ok());
+            }
+        });
+    }
+    drop(tx);
+    for handle in handles {
+        let _ = ylong_runtime::block_on(handle);
+    }
+    assert_eq!(count.load(SeqCst), 1000);
+    assert_eq!(sum.load(SeqCst), 999 * 1000 / 2);
+}
+
+/// SDV test cases for mpmc `BoundedReceiver`.
+///
+/// # Brief
+/// 1. Create a bounded mpmc channel with capacity 1 and spawn several consumer
+///    tasks.
+/// 2. Send values from a producer that waits for capacity, then drop it.
+/// 3. Check every value is received exactly once and all consumers finish.
+#[test]
+fn sdv_mpmc_bounded_multi_consumer_test() {
+    let (tx, rx) = bounded_channel::<usize>(1);
+    let sum = Arc::new(AtomicUsize::new(0));
+    let count = Arc::new(AtomicUsize::new(0));
+    let mut handles = vec![];
+    for _ in 0..4 {
+        let rx = rx.clone();
+        let sum = sum.clone();
+        let count = count.clone();
+        handles.push(ylong_runtime::spawn(async move {
+            while let Ok(val) = rx.recv().await {
+                sum.fetch_add(val, SeqCst);
+                count.fetch_add(1, SeqCst);
+            }
+        }));
+    }
+    drop(rx);
+    ylong_runtime::spawn(async move {
+        for i in 0..1000 {
+            assert!(tx.send(i).await.is_ok());
+        }
+    });
+    for handle in handles {
+        let _ = ylong_runtime::block_on(handle);
+    }
+    assert_eq!(count.load(SeqCst), 1000);
+    assert_eq!(sum.load(SeqCst), 999 * 1000 / 2);
+}
+
+/// SDV test cases for mpmc `BoundedReceiver`.
+///
+/// # Brief
+/// 1. Create a bounded mpmc channel.
+/// 2. Receive in a limited time while the channel is empty.
+/// 3. Send a value and receive it in the limited time.
+/// 4. Close the channel through a cloned receiver and receive error.
+#[test]
+fn sdv_mpmc_bounded_recv_timeout_close_test() {
+    let (tx, rx) = bounded_channel(1);
+    let rx2 = rx.clone();
+    let handle = ylong_runtime::spawn(async move {
+        assert_eq!(
+            rx.recv_timeout(Duration::from_millis(10)).await,
+            Err(RecvTimeoutError::Timeout)
+        );
+        assert!(tx.send(1).await.is_ok());
+        assert_eq!(rx.recv_timeout(Duration::from_millis(10)).await, Ok(1));
+        rx2.close();
+        assert!(tx.is_closed());
+        assert!(tx.try_send(2).is_err());
+        assert_eq!(rx.recv().await, Err(RecvError));
+    });
+    let _ = ylong_runtime::block_on(handle);
+}
+
+/// SDV test cases for mpmc `BoundedReceiver`.
+///
+/// # Brief
+/// 1. Create a bounded mpmc channel and drop all receivers.
+/// 2. Check the sender observes the channel closed.
+#[test]
+fn sdv_mpmc_bounded_drop_receivers_test() {
+    let (tx, rx) = bounded_channel::<i32>(1);
+    let rx2 = rx.clone();
+    drop(rx);
+    assert!(!tx.is_closed());
+    drop(rx2);
+    assert!(tx.is_closed());
+}
+
+/// SDV test cases for mpmc `UnboundedReceiver::blocking_recv`.
+///
+/// # Brief
+/// 1. Create a unbounded mpmc channel and receive on several plain threads.
+/// 2. Send values from a task then drop the sender.
+/// 3. Check every value is received exactly once and all threads finish.
+#[test]
+fn sdv_mpmc_unbounded_blocking_recv_test() {
+    let (tx, rx) = unbounded_channel::<usize>();
+    let sum = Arc::new(AtomicUsize::new(0));
+    let mut threads = vec![];
+    for _ in 0..4 {
+        let rx = rx.clone();
+        let sum = sum.clone();
+        threads.push(std::thread::spawn(move || {
+            while let Ok(val) = rx.blocking_recv() {
+                sum.fetch_add(val, SeqCst);
+            }
+        }));
+    }
+    drop(rx);
+    let handle = ylong_runtime::spawn(async move {
+        for i in 0..1000 {
+            assert!(tx.send(i).is_ok());
+        }
+    });
+    ylong_runtime::block_on(handle).unwrap();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+    assert_eq!(sum.load(SeqCst), 999 * 1000 / 2);
+}
@@ -313,3 +313,303 @@ fn sdv_multi_send_recv_test() {
     }
     let _ = ylong_runtime::block_on(handle);
 }
+
+/// SDV test cases for `BoundedSender::blocking_send` and
+/// `BoundedReceiver::blocking_recv`.
+///
+/// # Brief
+/// 1. Create a bounded mpsc channel with capacity.
+/// 2. Send values from a plain thread and receive them in a task.
+/// 3. Send values from a task and receive them on a plain thread.
+#[test]
+fn sdv_bounded_blocking_send_recv_test() {
+    let (tx, mut rx) = bounded_channel(1);
+    let handle = ylong_runtime::spawn(async move {
+        for i in 0..100 {
+            assert_eq!(rx.recv().await, Ok(i));
+        }
+        assert_eq!(rx.recv().await, Err(RecvError));
+    });
+    let thread = std::thread::spawn(move || {
+        for i in 0..100 {
+            assert!(tx.blocking_send(i).is_ok());
+        }
+    });
+    thread.join().unwrap();
+    ylong_runtime::block_on(handle).unwrap();
+
+    let (tx, mut rx) = bounded_channel(1);
+    let thread = std::thread::spawn(move || {
+        for i in 0..100 {
+            assert_eq!(rx.blocking_recv(), Ok(i));
+        }
+        assert_eq!(rx.blocking_recv(), Err(RecvError));
+    });
+    let handle = ylong_runtime::spawn(async move {
+        for i in 0..100 {
+            assert!(tx.send(i).await.is_ok());
+        }
+    });
+    ylong_runtime::block_on(handle).unwrap();
+    thread.join().unwrap();
+}
+
+/// SDV test cases for `UnboundedReceiver::blocking_recv_timeout` and
+/// `BoundedReceiver::blocking_recv_timeout`.
+///
+/// # Brief
+/// 1. Create an unbounded and a bounded mpsc channel.
+/// 2. Receive with a time limit while the channels are empty.
+/// 3. Receive with a time limit after a task sends a value.
+/// 4. Receive with a time limit after the senders are dropped.
+#[test]
+fn sdv_blocking_recv_timeout_test() {
+    let (tx, mut rx) = unbounded_channel();
+    assert_eq!(
+        rx.blocking_recv_timeout(Duration::from_millis(10)),
+        Err(RecvTimeoutError::Timeout)
+    );
+    let handle = ylong_runtime::spawn(async move {
+        assert!(tx.send(1).is_ok());
+    });
+    assert_eq!(rx.blocking_recv_timeout(Duration::from_secs(5)), Ok(1));
+    ylong_runtime::block_on(handle).unwrap();
+    assert_eq!(
+        rx.blocking_recv_timeout(Duration::from_millis(10)),
+        Err(RecvTimeoutError::Closed)
+    );
+
+    let (tx, mut rx) = bounded_channel(1);
+    assert_eq!(
+        rx.blocking_recv_timeout(Duration::from_millis(10)),
+        Err(RecvTimeoutError::Timeout)
+    );
+    let handle = ylong_runtime::spawn(async move {
+        assert!(tx.send(1).await.is_ok());
+    });
+    assert_eq!(rx.blocking_recv_timeout(Duration::from_secs(5)), Ok(1));
+    ylong_runtime::block_on(handle).unwrap();
+    assert_eq!(
+        rx.blocking_recv_timeout(Duration::from_millis(10)),
+        Err(RecvTimeoutError::Closed)
+    );
+}
+
+/// SDV test cases for blocking methods called in a runtime context.
+///
+/// # Brief
+/// 1. Create a bounded mpsc channel.
+/// 2. Call `blocking_send` and `blocking_recv` inside tasks.
+/// 3. Check both tasks panic.
+#[test]
+fn sdv_blocking_in_runtime_test() {
+    let (tx, mut rx) = bounded_channel::<i32>(1);
+    let handle = ylong_runtime::spawn(async move {
+        let _ = tx.blocking_send(1);
+    });
+    assert!(ylong_runtime::block_on(handle).is_err());
+    let handle = ylong_runtime::spawn(async move {
+        let _ = rx.blocking_recv();
+    });
+    assert!(ylong_runtime::block_on(handle).is_err());
+}
+
+/// SDV test cases for `BoundedSender::reserve` and
+/// `BoundedSender::try_reserve`.
+///
+/// # Brief
+/// 1. Create a bounded mpsc channel with capacity.
+/// 2. Reserve all slots and check the channel is full.
+/// 3. Drop one permit and send through the other, check the dropped slot is
+///    skipped by the receiver.
+/// 4. Wait for a slot while the channel is full, then receive to free it.
+#[test]
+fn sdv_bounded_reserve_test() {
+    let (tx, mut rx) = bounded_channel(2);
+    let permit = tx.try_reserve().unwrap();
+    let permit2 = tx.try_reserve().unwrap();
+    assert_eq!(tx.try_reserve().err(), Some(TrySendError::Full(())));
+    assert_eq!(tx.try_send(0), Err(TrySendError::Full(0)));
+    drop(permit);
+    permit2.send(1);
+    assert_eq!(rx.try_recv(), Ok(1));
+    assert_eq!(rx.try_recv(), Err(TryRecvError::Empty));
+
+    tx.try_send(2).unwrap();
+    tx.try_send(3).unwrap();
+    let handle = ylong_runtime::spawn(async move {
+        let permit = tx.reserve().await.unwrap();
+        permit.send(4);
+    });
+    let handle2 = ylong_runtime::spawn(async move {
+        for i in 2..5 {
+            assert_eq!(rx.recv().await, Ok(i));
+        }
+        assert_eq!(rx.recv().await, Err(RecvError));
+    });
+    ylong_runtime::block_on(handle).unwrap();
+    ylong_runtime::block_on(handle2).unwrap();
+}
+
+/// SDV test cases for `BoundedSender::reserve_many`.
+///
+/// # Brief
+/// 1. Create a bounded mpsc channel with capacity.
+/// 2. Reserve several slots while the channel is partly full.
+/// 3. Receive values to free the slots, check the values sent through the
+///    permits are received in order.
+/// 4. Drop an iterator with unused permits and check the channel is usable.
+#[test]
+fn sdv_bounded_reserve_many_test() {
+    let (tx, mut rx) = bounded_channel(3);
+    tx.try_send(0).unwrap();
+    tx.try_send(1).unwrap();
+    let handle = ylong_runtime::spawn(async move {
+        let permits = tx.reserve_many(3).await.unwrap();
+        assert_eq!(permits.len(), 3);
+        for (i, permit) in permits.enumerate() {
+            permit.send(i + 2);
+        }
+        let mut permits = tx.reserve_many(2).await.unwrap();
+        permits.next().unwrap().send(5);
+        drop(permits);
+        assert!(tx.send(6).await.is_ok());
+    });
+    let handle2 = ylong_runtime::spawn(async move {
+        for i in 0..7 {
+            assert_eq!(rx.recv().await, Ok(i));
+        }
+        assert_eq!(rx.recv().await, Err(RecvError));
+    });
+    ylong_runtime::block_on(handle).unwrap();
+    ylong_runtime::block_on(handle2).unwrap();
+}
+
+/// SDV test cases for `BoundedSender::reserve_owned` and
+/// `BoundedSender::try_reserve_owned`.
+///
+/// # Brief
+/// 1. Create a bounded mpsc channel with capacity.
+/// 2. Reserve an owned permit and send through it in another task.
+/// 3. Try reserving an owned permit while the channel is full and get the
+///    sender back.
+/// 4. Release an owned permit and send with the returned sender.
+#[test]
+fn sdv_bounded_reserve_owned_test() {
+    let (tx, mut rx) = bounded_channel(1);
+    let handle = ylong_runtime::spawn(async move {
+        let permit = tx.reserve_owned().await.unwrap();
+        let tx = ylong_runtime::spawn(async move { permit.send(1) })
+            .await
+            .unwrap();
+        let tx = match tx.try_reserve_owned() {
+            Err(TrySendError::Full(tx)) => tx,
+            _ => panic!("the channel should be full"),
+        };
+        assert_eq!(rx.recv().await, Ok(1));
+        let permit = match tx.try_reserve_owned() {
+            Ok(permit) => permit,
+            Err(_) => panic!("the channel should not be full"),
+        };
+        let tx = permit.release();
+        assert!(tx.send(2).await.is_ok());
+        assert_eq!(rx.recv().await, Ok(2));
+    });
+    ylong_runtime::block_on(handle).unwrap();
+}
+
+/// SDV test cases for `WeakBoundedSender` and `WeakUnboundedSender`.
+///
+/// # Brief
+/// 1. Create mpsc channels and downgrade the senders.
+/// 2. Upgrade the weak senders and send values.
+/// 3. Drop all senders, check the channels are closed and the weak senders
+///    cannot be upgraded.
+#[test]
+fn sdv_weak_sender_test() {
+    let (tx, mut rx) = bounded_channel(1);
+    let weak = tx.downgrade();
+    let weak2 = weak.clone();
+    weak2.upgrade().unwrap().try_send(1).unwrap();
+    drop(tx);
+    assert_eq!(rx.try_recv(), Ok(1));
+    assert_eq!(rx.try_recv(), Err(TryRecvError::Closed));
+    assert!(weak.upgrade().is_none());
+
+    let (tx, mut rx) = unbounded_channel();
+    let weak = tx.downgrade();
+    weak.upgrade().unwrap().send(1).unwrap();
+    drop(tx);
+    assert_eq!(rx.try_recv(), Ok(1));
+    assert_eq!(rx.try_recv(), Err(TryRecvError::Closed));
+    assert!(weak.upgrade().is_none());
+}
+
+/// SDV test cases for `BoundedReceiver::recv_many` and
+/// `UnboundedReceiver::recv_many`.
+///
+/// # Brief
+/// 1. Create mpsc channels and send several values.
+/// 2. Receive them in batches limited in size.
+/// 3. Receive after the senders are dropped and get zero.
+#[test]
+fn sdv_recv_many_test() {
+    let (tx, mut rx) = bounded_channel(4);
+    let (utx, mut urx) = unbounded_channel();
+    let handle = ylong_runtime::spawn(async move {
+        let mut buf = Vec::new();
+        for i in 0..4 {
+            tx.send(i).await.unwrap();
+            utx.send(i).unwrap();
+        }
+        assert_eq!(rx.recv_many(&mut buf, 0).await, 0);
+        assert_eq!(rx.recv_many(&mut buf, 3).await, 3);
+        assert_eq!(rx.recv_many(&mut buf, 3).await, 1);
+        assert_eq!(buf, vec![0, 1, 2, 3]);
+        drop(tx);
+        assert_eq!(rx.recv_many(&mut buf, 3).await, 0);
+
+        let mut buf = Vec::new();
+        assert_eq!(urx.recv_many(&mut buf, 10).await, 4);
+        assert_eq!(buf, vec![0, 1, 2, 3]);
+        drop(utx);
+        assert_eq!(urx.recv_many(&mut buf, 10).await, 0);
+    });
+    ylong_runtime::block_on(handle).unwrap();
+}
+
+/// SDV test cases for `BoundedSender::reserve` with several senders.
+///
+/// # Brief
+/// 1. Create a bounded mpsc channel with capacity.
+/// 2. Reserve slots concurrently, send through half of the permits and drop the
+///    others.
+/// 3. Check all the values sent are received.
+#[test]
+fn sdv_bounded_reserve_multi_sender_test() {
+    let (tx, mut rx) = bounded_channel(4);
+    let mut tasks = Vec::new();
+    for i in 0..8 {
+        let tx = tx.clone();
+        tasks.push(ylong_runtime::spawn(async move {
+            for j in 0..100 {
+                let permit = tx.reserve().await.unwrap();
+                if j % 2 == 0 {
+                    permit.send(i * 100 + j);
+                }
+            }
+        }));
+    }
+    drop(tx);
+    let handle = ylong_runtime::spawn(async move {
+        let mut count = 0;
+        while rx.recv().await.is_ok() {
+            count += 1;
+        }
+        count
+    });
+    for task in tasks {
+        ylong_runtime::block_on(task).unwrap();
+    }
+    assert_eq!(ylong_runtime::block_on(handle).unwrap(), 400);
+}
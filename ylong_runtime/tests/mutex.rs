@@ -55,3 +55,36 @@ fn sdv_mutex_lock_hold_longtime() {
     });
     let _ = ylong_runtime::block_on(handle);
 }
+
+/// SDV test cases for Mutex::lock_timeout() interface
+///
+/// # Brief
+/// 1. Create a Concurrent Mutual Exclusion Lock.
+/// 2. Make a task hold the lock for a long time.
+/// 3. Call lock_timeout() in another task and check it fails after the time
+///    limit.
+/// 4. Call lock_timeout() with a longer time limit and check it gets the lock
+///    once it is released.
+#[test]
+fn sdv_mutex_lock_timeout() {
+    let mutex = Arc::new(Mutex::new(10));
+    let mutex1 = mutex.clone();
+    let handle = ylong_runtime::spawn(async move {
+        let mut lock = mutex1.lock().await;
+        *lock += 1;
+        time::sleep(Duration::from_millis(200)).await;
+    });
+    thread::sleep(Duration::from_millis(50));
+    let mutex2 = mutex.clone();
+    let handle2 = ylong_runtime::spawn(async move {
+        assert!(mutex2
+            .lock_timeout(Duration::from_millis(10))
+            .await
+            .is_err());
+        let mut lock = mutex2.lock_timeout(Duration::from_secs(10)).await.unwrap();
+        *lock += 1;
+        assert_eq!(*lock, 12);
+    });
+    ylong_runtime::block_on(handle).unwrap();
+    ylong_runtime::block_on(handle2).unwrap();
+}
@@ -0,0 +1,134 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(all(not(feature = "ffrt"), feature = "multi_instance_runtime"))]
+
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering::{Acquire, Release};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::Duration;
+
+use ylong_runtime::builder::RuntimeBuilder;
+use ylong_runtime::error::ErrorKind;
+
+/// SDV test cases for `Runtime::shutdown_timeout`
+///
+/// # Brief
+/// 1. Create a multi-thread runtime and run some tasks on it
+/// 2. Shut down the runtime with a timeout
+/// 3. Check that all threads exit in time
+/// 4. Spawn tasks after the shutdown and check the `RuntimeShutdown` errors
+#[test]
+fn sdv_runtime_shutdown_timeout() {
+    let runtime = RuntimeBuilder::new_multi_thread()
+        .worker_num(2)
+        .build()
+        .unwrap();
+    let handle = runtime.spawn(async { 1 });
+    assert_eq!(runtime.block_on(handle).unwrap(), 1);
+    let handle = runtime.spawn_blocking(|| 2);
+    assert_eq!(runtime.block_on(handle).unwrap(), 2);
+
+    assert!(runtime.shutdown_timeout(Duration::from_secs(3)));
+    // shutting down twice
+    assert!(!runtime.shutdown_timeout(Duration::from_secs(3)));
+
+    let handle = runtime.spawn(async { 1 });
+    let err = runtime.block_on(handle).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::RuntimeShutdown);
+
+    let handle = runtime.spawn_blocking(|| 2);
+    let err = runtime.block_on(handle).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::RuntimeShutdown);
+}
+
+/// SDV test cases for canceling queued tasks during shutdown
+///
+/// # Brief
+/// 1. Create a runtime with one worker and occupy the worker with a busy task
+/// 2. Spawn another task which stays inside the queue
+/// 3. Shut down the runtime in the background and release the worker
+/// 4. Check that the queued task is canceled and the busy task finishes
+#[test]
+fn sdv_runtime_shutdown_cancel_queued() {
+    let runtime = RuntimeBuilder::new_multi_thread()
+        .worker_num(1)
+        .build()
+        .unwrap();
+
+    let (tx, rx) = mpsc::channel();
+    let release = Arc::new(AtomicBool::new(false));
+    let release_clone = release.clone();
+    let busy = runtime.spawn(async move {
+        tx.send(()).unwrap();
+        while !release_clone.load(Acquire) {
+            std::hint::spin_loop();
+        }
+        1
+    });
+    rx.recv().unwrap();
+    let queued = runtime.spawn(async { 2 });
+
+    runtime.shutdown_background();
+    release.store(true, Release);
+
+    let err = runtime.block_on(queued).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::TaskCanceled);
+    assert_eq!(runtime.block_on(busy).unwrap(), 1);
+}
+
+/// SDV test cases for `Runtime::shutdown_timeout` when timeout is reached
+///
+/// # Brief
+/// 1. Create a runtime and spawn a blocking task that takes a long time
+/// 2. Shut down the runtime with a short timeout
+/// 3. Check that the shutdown reports the timeout
+#[test]
+fn sdv_runtime_shutdown_timeout_reached() {
+    let runtime = RuntimeBuilder::new_multi_thread()
+        .worker_num(1)
+        .build()
+        .unwrap();
+    let (tx, rx) = mpsc::channel();
+    let handle = runtime.spawn_blocking(move || {
+        tx.send(()).unwrap();
+        thread::sleep(Duration::from_millis(500));
+        1
+    });
+    rx.recv().unwrap();
+
+    assert!(!runtime.shutdown_timeout(Duration::from_millis(10)));
+    assert_eq!(runtime.block_on(handle).unwrap(), 1);
+}
+
+/// SDV test cases for shutting down a current-thread runtime
+///
+/// # Brief
+/// 1. Create a current-thread runtime and spawn a task without running it
+/// 2. Shut down the runtime
+/// 3. Check that the spawned task is canceled and new tasks are rejected
+#[cfg(feature = "current_thread_runtime")]
+#[test]
+fn sdv_current_thread_runtime_shutdown() {
+    let runtime = RuntimeBuilder::new_current_thread().build().unwrap();
+    let queued = runtime.spawn(async { 1 });
+
+    assert!(runtime.shutdown_timeout(Duration::from_secs(3)));
+
+    let err = runtime.block_on(queued).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::TaskCanceled);
+    let handle = runtime.spawn(async { 1 });
+    let err = runtime.block_on(handle).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::RuntimeShutdown);
+}
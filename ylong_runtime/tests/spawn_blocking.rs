@@ -55,10 +55,14 @@ mod ffrt_test {
 
 #[cfg(not(feature = "ffrt"))]
 mod not_ffrt_test {
+    use std::sync::atomic::AtomicBool;
+    use std::sync::atomic::Ordering::{Acquire, Release};
+    use std::sync::Arc;
     use std::thread::sleep;
     use std::time;
 
-    use ylong_runtime::builder::RuntimeBuilder;
+    use ylong_runtime::builder::{BlockingRejectPolicy, RuntimeBuilder};
+    use ylong_runtime::error::ErrorKind;
     use ylong_runtime::executor::Runtime;
     use ylong_runtime::task::TaskBuilder;
 
@@ -200,4 +204,136 @@ mod not_ffrt_test {
 
         test_spawn(&runtime);
     }
+
+    // Spawns a blocking task that occupies the only thread of the pool until a
+    // message is sent through the returned sender.
+    fn occupy_pool(
+        runtime: &Runtime,
+    ) -> (
+        std::sync::mpsc::Sender<()>,
+        ylong_runtime::task::JoinHandle<()>,
+    ) {
+        let (start_tx, start_rx) = std::sync::mpsc::channel();
+        let (stop_tx, stop_rx) = std::sync::mpsc::channel::<()>();
+        let handle = runtime.spawn_blocking(move || {
+            start_tx.send(()).unwrap();
+            stop_rx.recv().unwrap();
+        });
+        start_rx.recv().unwrap();
+        (stop_tx, handle)
+    }
+
+    fn bounded_runtime(policy: BlockingRejectPolicy) -> Runtime {
+        RuntimeBuilder::new_multi_thread()
+            .worker_num(1)
+            .max_blocking_pool_size(1)
+            .blocking_queue_size(1)
+            .blocking_reject_policy(policy)
+            .build()
+            .unwrap()
+    }
+
+    /// SDV test cases for the `Error` reject policy of the blocking pool
+    ///
+    /// # Brief
+    /// 1. Create a runtime with one blocking thread and a queue of size one
+    /// 2. Occupy the thread and fill the queue
+    /// 3. Check that the next task gets rejected with `BlockQueueFull`
+    /// 4. Check the blocking metrics if the feature is on
+    /// 5. Release the thread, check that the queued task finishes
+    #[test]
+    fn sdv_blocking_reject_error() {
+        let runtime = bounded_runtime(BlockingRejectPolicy::Error);
+        let (stop_tx, first) = occupy_pool(&runtime);
+        let queued = runtime.spawn_blocking(|| 1);
+        let rejected = runtime.spawn_blocking(|| 2);
+        assert_eq!(
+            runtime.block_on(rejected).unwrap_err().kind(),
+            ErrorKind::BlockQueueFull
+        );
+
+        #[cfg(feature = "metrics")]
+        {
+            let metrics = runtime.metrics();
+            assert_eq!(metrics.blocking_queue_length(), 1);
+            assert_eq!(metrics.active_blocking_threads_num(), 1);
+        }
+
+        stop_tx.send(()).unwrap();
+        runtime.block_on(first).unwrap();
+        assert_eq!(runtime.block_on(queued).unwrap(), 1);
+    }
+
+    /// SDV test cases for the `CallerRuns` reject policy of the blocking pool
+    ///
+    /// # Brief
+    /// 1. Create a runtime with one blocking thread and a queue of size one
+    /// 2. Occupy the thread and fill the queue
+    /// 3. Check that the next task runs on the caller thread
+    #[test]
+    fn sdv_blocking_reject_caller_runs() {
+        let runtime = bounded_runtime(BlockingRejectPolicy::CallerRuns);
+        let (stop_tx, first) = occupy_pool(&runtime);
+        let queued = runtime.spawn_blocking(|| std::thread::current().id());
+        let caller = runtime.spawn_blocking(|| std::thread::current().id());
+        assert_eq!(
+            runtime.block_on(caller).unwrap(),
+            std::thread::current().id()
+        );
+
+        stop_tx.send(()).unwrap();
+        runtime.block_on(first).unwrap();
+        assert_ne!(
+            runtime.block_on(queued).unwrap(),
+            std::thread::current().id()
+        );
+    }
+
+    /// SDV test cases for the `Wait` reject policy of the blocking pool
+    ///
+    /// # Brief
+    /// 1. Create a runtime with one blocking thread and a queue of size one
+    /// 2. Occupy the thread and fill the queue
+    /// 3. Spawn another task in a new thread, check that the thread is blocked
+    /// 4. Release the blocking thread, check that all the tasks finish
+    #[test]
+    fn sdv_blocking_reject_wait() {
+        let runtime = Arc::new(bounded_runtime(BlockingRejectPolicy::Wait));
+        let (stop_tx, first) = occupy_pool(&runtime);
+        let queued = runtime.spawn_blocking(|| 1);
+
+        let spawned = Arc::new(AtomicBool::new(false));
+        let spawned_clone = spawned.clone();
+        let runtime_clone = runtime.clone();
+        let spawner = std::thread::spawn(move || {
+            let handle = runtime_clone.spawn_blocking(|| 2);
+            spawned_clone.store(true, Release);
+            handle
+        });
+        sleep(time::Duration::from_millis(100));
+        assert!(!spawned.load(Acquire));
+
+        stop_tx.send(()).unwrap();
+        let waited = spawner.join().unwrap();
+        assert!(spawned.load(Acquire));
+        runtime.block_on(first).unwrap();
+        assert_eq!(runtime.block_on(queued).unwrap(), 1);
+        assert_eq!(runtime.block_on(waited).unwrap(), 2);
+    }
+
+    /// SDV test cases for the names of blocking tasks
+    ///
+    /// # Brief
+    /// 1. Spawn a named blocking task
+    /// 2. Check that the thread is renamed to the task name while the task is
+    ///    running
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn sdv_blocking_task_name() {
+        let handle = TaskBuilder::new()
+            .name(String::from("blocking-name"))
+            .spawn_blocking(|| std::fs::read_to_string("/proc/thread-self/comm").unwrap());
+        let name = ylong_runtime::block_on(handle).unwrap();
+        assert_eq!(name.trim_end(), "blocking-name");
+    }
 }
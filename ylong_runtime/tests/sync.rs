@@ -483,3 +483,78 @@ fn sdv_waiter_with_wake_all() {
         waiter.wake_all();
     }
 }
+
+/// SDV test cases for `Mutex::lock_owned()`.
+///
+/// # Brief
+/// 1. Create a variable of Mutex inside an Arc.
+/// 2. Get owned guards of the mutex and move them into 200 spawned tasks.
+/// 3. Check if the test results are correct.
+#[test]
+fn sdv_mutex_lock_owned() {
+    let mutex = Arc::new(YlongMutex::new(0));
+    let mut handlers = Vec::with_capacity(NUM);
+    for _ in 0..NUM {
+        let mutex = mutex.clone();
+        handlers.push(ylong_runtime::spawn(async move {
+            let mut lock = mutex.lock_owned().await;
+            let handle = ylong_runtime::spawn(async move {
+                *lock += 1;
+            });
+            handle.await.unwrap();
+        }));
+    }
+    for handler in handlers {
+        ylong_runtime::block_on(handler).expect("block_on failed");
+    }
+    ylong_runtime::block_on(async {
+        assert_eq!(*mutex.lock().await, NUM);
+    });
+}
+
+/// SDV test cases for `RwLockWriteGuard::downgrade()`.
+///
+/// # Brief
+/// 1. Create a variable of Rwlock and hold the write lock.
+/// 2. Spawn readers and a writer waiting for the lock.
+/// 3. Downgrade the write guard and check all readers finish while the writer
+///    is still waiting.
+/// 4. Drop the read guard and check the writer finishes.
+#[test]
+fn sdv_rwlock_downgrade() {
+    let rwlock = Arc::new(RwLock::new(0));
+    let mut w = ylong_runtime::block_on(rwlock.clone().write_owned());
+    let readers = Arc::new(AtomicUsize::new(0));
+    let mut handlers = Vec::with_capacity(10);
+    for _ in 0..10 {
+        let rwlock = rwlock.clone();
+        let readers = readers.clone();
+        handlers.push(ylong_runtime::spawn(async move {
+            assert_eq!(*rwlock.read().await, 1);
+            readers.fetch_add(1, Release);
+        }));
+    }
+    thread::sleep(Duration::from_millis(10));
+    let rwlock2 = rwlock.clone();
+    let written = Arc::new(AtomicUsize::new(0));
+    let written2 = written.clone();
+    let writer = ylong_runtime::spawn(async move {
+        *rwlock2.write().await += 1;
+        written2.store(1, Release);
+    });
+    thread::sleep(Duration::from_millis(10));
+    assert_eq!(readers.load(Acquire), 0);
+
+    *w += 1;
+    let r = w.downgrade();
+    for handler in handlers {
+        ylong_runtime::block_on(handler).expect("block_on failed");
+    }
+    assert_eq!(readers.load(Acquire), 10);
+    thread::sleep(Duration::from_millis(10));
+    assert_eq!(written.load(Acquire), 0);
+    assert_eq!(*r, 1);
+    drop(r);
+    ylong_runtime::block_on(writer).expect("block_on failed");
+    assert_eq!(*rwlock.try_read().unwrap(), 2);
+}
@@ -103,3 +103,87 @@ fn sdv_task_cancel_multiple() {
         })
     }
 }
+
+// Waits until the task of the handle finishes
+async fn wait_finished(handle: &ylong_runtime::task::CancelHandle) {
+    while !handle.is_finished() {
+        sleep(Duration::from_millis(1)).await;
+    }
+}
+
+/// SDV test cases for `AbortOnDropHandle`.
+///
+/// # Brief
+/// 1. Spawn a task that takes 100 seconds and turn its handle into an
+///    `AbortOnDropHandle`
+/// 2. Drop the handle and check that the task gets canceled
+/// 3. Spawn another task and detach it from its `AbortOnDropHandle`
+/// 4. Check that the detached handle could still get the result
+#[test]
+fn sdv_task_abort_on_drop_handle() {
+    ylong_runtime::block_on(async {
+        let handle = ylong_runtime::spawn(sleep(Duration::from_secs(100))).abort_on_drop();
+        let cancel = handle.abort_handle();
+        drop(handle);
+        wait_finished(&cancel).await;
+
+        let handle = ylong_runtime::spawn(async {
+            sleep(Duration::from_millis(10)).await;
+            1
+        })
+        .abort_on_drop();
+        let handle = handle.detach();
+        assert_eq!(handle.await.unwrap(), 1);
+
+        let handle = ylong_runtime::spawn(async { 2 }).abort_on_drop();
+        assert_eq!(handle.await.unwrap(), 2);
+    });
+}
+
+/// SDV test cases for `TaskBuilder::abort_on_drop`.
+///
+/// # Brief
+/// 1. Spawn a task that takes 100 seconds by a builder with `abort_on_drop`
+/// 2. Drop the handle and check that the task gets canceled
+/// 3. Spawn a task by the same builder and detach its handle
+/// 4. Check that the detached task keeps running until it finishes
+#[test]
+fn sdv_task_builder_abort_on_drop() {
+    ylong_runtime::block_on(async {
+        let builder = ylong_runtime::task::TaskBuilder::new().abort_on_drop(true);
+        let handle = builder.spawn(sleep(Duration::from_secs(100)));
+        let cancel = handle.abort_handle();
+        drop(handle);
+        wait_finished(&cancel).await;
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let handle = builder.spawn(async move {
+            sleep(Duration::from_millis(10)).await;
+            tx.send(1).unwrap();
+        });
+        let cancel = handle.abort_handle();
+        handle.detach();
+        wait_finished(&cancel).await;
+        assert_eq!(rx.recv().unwrap(), 1);
+    });
+}
+
+/// SDV test cases for cloning the `CancelHandle` of a task.
+///
+/// # Brief
+/// 1. Spawn a task that takes 100 seconds and get its `CancelHandle`
+/// 2. Clone the handle and cancel the task by the clone
+/// 3. Check that the `JoinHandle` returns a `TaskCanceled` error
+#[test]
+fn sdv_task_abort_handle_clone() {
+    ylong_runtime::block_on(async {
+        let handle = ylong_runtime::spawn(sleep(Duration::from_secs(100)));
+        let cancel = handle.abort_handle();
+        let cancel2 = cancel.clone();
+        drop(cancel);
+        cancel2.cancel();
+        let err = handle.await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::TaskCanceled);
+        assert!(cancel2.is_finished());
+    });
+}
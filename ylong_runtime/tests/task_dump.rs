@@ -0,0 +1,189 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(all(feature = "metrics", not(feature = "ffrt")))]
+
+use std::future::pending;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering::{Acquire, Release};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use ylong_runtime::executor::get_global_runtime_dump;
+use ylong_runtime::task::{Qos, TaskBuilder};
+use ylong_runtime::{RuntimeDump, TaskDump, TaskStatus};
+
+// Takes dumps until one of them satisfies the check.
+fn wait_for<T>(dump: impl Fn() -> RuntimeDump, check: impl Fn(&RuntimeDump) -> Option<T>) -> T {
+    for _ in 0..500 {
+        if let Some(res) = check(&dump()) {
+            return res;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+    panic!("dump never satisfies the check: {:?}", dump());
+}
+
+// Waits until the task with the given name shows the expected status in the
+// dump of the global runtime.
+fn wait_for_status(name: &str, status: TaskStatus) -> TaskDump {
+    wait_for(get_global_runtime_dump, |dump| {
+        dump.tasks()
+            .iter()
+            .find(|task| task.name() == Some(name) && task.status() == status)
+            .cloned()
+    })
+}
+
+fn spin_until(stop: Arc<AtomicBool>) {
+    while !stop.load(Acquire) {
+        thread::sleep(Duration::from_millis(1));
+    }
+}
+
+/// SDV test cases for dumping the global runtime
+///
+/// # Brief
+/// 1. Spawn an idle task, a completed task, a canceled task, a running task and
+///    a blocking task onto the global runtime
+/// 2. Check that the dump reports the status, qos, poll count and last-poll
+///    time of each task
+/// 3. Let the tasks finish and drop their handles, check that they are gone
+///    from the dump
+#[test]
+fn sdv_global_runtime_dump() {
+    let stop = Arc::new(AtomicBool::new(false));
+
+    let idle = TaskBuilder::new()
+        .name("dump-idle".to_string())
+        .qos(Qos::Utility)
+        .spawn(pending::<()>());
+    let task = wait_for_status("dump-idle", TaskStatus::Idle);
+    assert_eq!(task.qos(), Qos::Utility);
+    assert_eq!(task.poll_count(), 1);
+    assert!(task.last_poll().unwrap() >= task.spawned_at());
+
+    let completed = TaskBuilder::new()
+        .name("dump-completed".to_string())
+        .spawn(async { 1 });
+    wait_for_status("dump-completed", TaskStatus::Completed);
+
+    let canceled = TaskBuilder::new()
+        .name("dump-canceled".to_string())
+        .spawn(pending::<()>());
+    wait_for_status("dump-canceled", TaskStatus::Idle);
+    canceled.cancel();
+    wait_for_status("dump-canceled", TaskStatus::Canceled);
+
+    // The running task occupies a worker, so it gets spawned at last
+    let stop_clone = stop.clone();
+    let running = TaskBuilder::new()
+        .name("dump-running".to_string())
+        .spawn(async move { spin_until(stop_clone) });
+    wait_for_status("dump-running", TaskStatus::Running);
+
+    let stop_clone = stop.clone();
+    let blocking = TaskBuilder::new()
+        .name("dump-blocking".to_string())
+        .spawn_blocking(move || spin_until(stop_clone));
+    wait_for_status("dump-blocking", TaskStatus::Running);
+
+    let dump = get_global_runtime_dump();
+    assert!(dump
+        .tasks()
+        .windows(2)
+        .all(|tasks| tasks[0].id() < tasks[1].id()));
+
+    stop.store(true, Release);
+    idle.cancel();
+    assert!(ylong_runtime::block_on(idle).is_err());
+    ylong_runtime::block_on(running).unwrap();
+    assert_eq!(ylong_runtime::block_on(completed).unwrap(), 1);
+    assert!(ylong_runtime::block_on(canceled).is_err());
+    ylong_runtime::block_on(blocking).unwrap();
+
+    wait_for(get_global_runtime_dump, |dump| {
+        let alive = dump.tasks().iter().any(|task| {
+            task.name()
+                .map(|name| name.starts_with("dump-"))
+                .unwrap_or(false)
+        });
+        (!alive).then_some(())
+    });
+}
+
+/// SDV test cases for `Runtime::dump`
+///
+/// # Brief
+/// 1. Spawn a pending task and a blocking task onto a runtime
+/// 2. Check that the dump only contains these two tasks with the right status
+/// 3. Let the tasks finish, check that the dump becomes empty
+#[test]
+#[cfg(feature = "multi_instance_runtime")]
+fn sdv_runtime_dump() {
+    use ylong_runtime::builder::RuntimeBuilder;
+
+    let runtime = Arc::new(
+        RuntimeBuilder::new_multi_thread()
+            .worker_num(1)
+            .build()
+            .unwrap(),
+    );
+    let dump = || runtime.dump();
+    assert!(dump().tasks().is_empty());
+
+    let stop = Arc::new(AtomicBool::new(false));
+    let idle = runtime.spawn(pending::<()>());
+    let stop_clone = stop.clone();
+    let blocking = runtime.spawn_blocking(move || spin_until(stop_clone));
+    wait_for(dump, |dump| {
+        let status: Vec<_> = dump.tasks().iter().map(TaskDump::status).collect();
+        (status == [TaskStatus::Idle, TaskStatus::Running]).then_some(())
+    });
+
+    stop.store(true, Release);
+    idle.cancel();
+    assert!(runtime.block_on(idle).is_err());
+    runtime.block_on(blocking).unwrap();
+    wait_for(dump, |dump| dump.tasks().is_empty().then_some(()));
+}
+
+/// SDV test cases for `Runtime::dump` on the current-thread runtime
+///
+/// # Brief
+/// 1. Spawn a task onto a current-thread runtime
+/// 2. Check that the task is scheduled and never polled before the runtime gets
+///    driven
+/// 3. Drive the runtime, check that the task gets idle after one poll
+#[test]
+#[cfg(all(feature = "current_thread_runtime", feature = "multi_instance_runtime"))]
+fn sdv_runtime_dump_current_thread() {
+    use ylong_runtime::builder::RuntimeBuilder;
+    use ylong_runtime::task::yield_now;
+
+    let runtime = RuntimeBuilder::new_current_thread().build().unwrap();
+    let handle = runtime.spawn(pending::<()>());
+    let dump = runtime.dump();
+    assert_eq!(dump.tasks().len(), 1);
+    assert_eq!(dump.tasks()[0].status(), TaskStatus::Scheduled);
+    assert_eq!(dump.tasks()[0].poll_count(), 0);
+    assert!(dump.tasks()[0].last_poll().is_none());
+
+    runtime.block_on(yield_now());
+    let dump = runtime.dump();
+    assert_eq!(dump.tasks()[0].status(), TaskStatus::Idle);
+    assert_eq!(dump.tasks()[0].poll_count(), 1);
+    assert!(dump.tasks()[0].last_poll().is_some());
+    handle.cancel();
+}
@@ -0,0 +1,74 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(not(feature = "ffrt"))]
+
+use ylong_runtime::task::{self, TaskBuilder};
+
+/// SDV test cases for `task::id` and `JoinHandle::id`
+///
+/// # Brief
+/// 1. Spawn several tasks that return their own task IDs
+/// 2. Check that the IDs match the ones of the JoinHandles and are all
+///    different
+/// 3. Check that `try_id` returns `None` outside of a task
+#[test]
+fn sdv_task_id() {
+    let handles: Vec<_> = (0..10)
+        .map(|_| ylong_runtime::spawn(async { task::id() }))
+        .collect();
+    let mut ids: Vec<_> = handles
+        .into_iter()
+        .map(|handle| {
+            let id = handle.id();
+            assert_eq!(ylong_runtime::block_on(handle).unwrap(), id);
+            id
+        })
+        .collect();
+    ids.sort();
+    ids.dedup();
+    assert_eq!(ids.len(), 10);
+    assert!(task::try_id().is_none());
+}
+
+/// SDV test cases for `task::id` inside blocking tasks
+///
+/// # Brief
+/// 1. Spawn a blocking task that returns its own task ID
+/// 2. Check that the ID matches the one of the JoinHandle
+#[test]
+fn sdv_task_id_blocking() {
+    let handle = ylong_runtime::spawn_blocking(task::try_id);
+    let id = handle.id();
+    assert_eq!(ylong_runtime::block_on(handle).unwrap(), Some(id));
+}
+
+/// SDV test cases for `task::name`
+///
+/// # Brief
+/// 1. Spawn a named task and an unnamed task, both return their names
+/// 2. Check the names
+/// 3. Check that `name` returns `None` outside of a task
+#[test]
+fn sdv_task_name() {
+    let named = TaskBuilder::new()
+        .name("task-name".to_string())
+        .spawn(async { task::name() });
+    let unnamed = ylong_runtime::spawn(async { task::name() });
+    assert_eq!(
+        ylong_runtime::block_on(named).unwrap().as_deref(),
+        Some("task-name")
+    );
+    assert!(ylong_runtime::block_on(unnamed).unwrap().is_none());
+    assert!(task::name().is_none());
+}
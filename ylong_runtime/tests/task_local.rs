@@ -0,0 +1,99 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(not(feature = "ffrt"))]
+
+use ylong_runtime::task::yield_now;
+
+ylong_runtime::task_local! {
+    static TASK_ID: usize;
+    static NAME: String;
+}
+
+const TASK_NUM: usize = 100;
+const YIELD_NUM: usize = 50;
+
+async fn check_task_local(id: usize) -> usize {
+    for _ in 0..YIELD_NUM {
+        yield_now().await;
+        TASK_ID.with(|cur| assert_eq!(*cur, id));
+    }
+    NAME.scope(format!("task-{id}"), async move {
+        yield_now().await;
+        NAME.with(|name| assert_eq!(*name, format!("task-{id}")));
+        TASK_ID.with(|cur| *cur)
+    })
+    .await
+}
+
+/// SDV test cases for task-local values on the multi-thread runtime
+///
+/// # Brief
+/// 1. Spawn many tasks onto the global runtime, each with its own value
+/// 2. Yield inside the tasks so that they could be stolen by other workers
+/// 3. Check that each task always sees its own value, including inside a
+///    nested scope
+/// 4. Check that the value is not visible outside the tasks
+#[test]
+fn sdv_task_local_multi_thread() {
+    let mut handles = Vec::with_capacity(TASK_NUM);
+    for i in 0..TASK_NUM {
+        handles.push(ylong_runtime::spawn(TASK_ID.scope(i, check_task_local(i))));
+    }
+    for (i, handle) in handles.into_iter().enumerate() {
+        assert_eq!(ylong_runtime::block_on(handle).unwrap(), i);
+    }
+    assert!(TASK_ID.try_with(|id| *id).is_err());
+    assert!(NAME.try_with(|name| name.clone()).is_err());
+}
+
+/// SDV test cases for task-local values on the current-thread runtime
+///
+/// # Brief
+/// 1. Create a current-thread runtime
+/// 2. Spawn many tasks onto it, each with its own value
+/// 3. Check that each task always sees its own value while they interleave on
+///    the same thread
+#[test]
+#[cfg(feature = "current_thread_runtime")]
+fn sdv_task_local_current_thread() {
+    use ylong_runtime::builder::RuntimeBuilder;
+
+    let runtime = RuntimeBuilder::new_current_thread().build().unwrap();
+    let mut handles = Vec::with_capacity(TASK_NUM);
+    for i in 0..TASK_NUM {
+        handles.push(runtime.spawn(TASK_ID.scope(i, check_task_local(i))));
+    }
+    let res = runtime.block_on(async move {
+        let mut res = Vec::with_capacity(TASK_NUM);
+        for handle in handles {
+            res.push(handle.await.unwrap());
+        }
+        res
+    });
+    assert_eq!(res, (0..TASK_NUM).collect::<Vec<_>>());
+}
+
+/// SDV test cases for accessing a task-local value outside its scope
+///
+/// # Brief
+/// 1. Spawn a task without setting the value
+/// 2. Check that `try_with` returns an error and `with` panics
+#[test]
+fn sdv_task_local_not_set() {
+    let handle = ylong_runtime::spawn(async { TASK_ID.try_with(|id| *id).is_err() });
+    assert!(ylong_runtime::block_on(handle).unwrap());
+
+    let handle = ylong_runtime::spawn(async { TASK_ID.with(|id| *id) });
+    assert!(ylong_runtime::block_on(handle).is_err());
+}
@@ -0,0 +1,173 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(not(feature = "ffrt"))]
+
+use std::panic;
+
+use ylong_runtime::error::ErrorKind;
+
+/// SDV test cases for getting the payload of a panicking task
+///
+/// # Brief
+/// 1. Spawn a task that panics with a message
+/// 2. Check that the JoinHandle returns a `Panic` error carrying the payload
+/// 3. Re-raise the payload by `resume_unwind`, check that it could be caught
+#[test]
+fn sdv_task_panic_payload() {
+    let handle = ylong_runtime::spawn(async { panic!("task panics") });
+    let err = ylong_runtime::block_on(handle).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Panic);
+    assert!(err.is_panic());
+    assert_eq!(format!("{err}"), "Panic: task panics");
+
+    let payload = err.into_panic();
+    let payload =
+        panic::catch_unwind(panic::AssertUnwindSafe(|| panic::resume_unwind(payload))).unwrap_err();
+    assert_eq!(*payload.downcast_ref::<&str>().unwrap(), "task panics");
+
+    let handle = ylong_runtime::spawn(async { 1 });
+    ylong_runtime::block_on(handle).unwrap();
+    let err: ylong_runtime::error::ScheduleError = ErrorKind::TaskCanceled.into();
+    assert!(!err.is_panic());
+    assert!(err.try_into_panic().is_err());
+}
+
+/// SDV test cases for the task panic hook
+///
+/// # Brief
+/// 1. Create a runtime with a panic hook
+/// 2. Spawn an async task and a blocking task which panic
+/// 3. Check that the hook is called for both of them with the ids and the
+///    payloads
+/// 4. Check that the runtime keeps running under the default policy
+#[test]
+#[cfg(feature = "multi_instance_runtime")]
+fn sdv_task_panic_hook() {
+    use std::sync::{Arc, Mutex};
+
+    use ylong_runtime::builder::RuntimeBuilder;
+
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    let messages_clone = messages.clone();
+    let runtime = RuntimeBuilder::new_multi_thread()
+        .worker_num(2)
+        .task_panic_hook(move |info, payload| {
+            assert!(info.name().is_none());
+            let msg = payload.downcast_ref::<&str>().unwrap();
+            messages_clone
+                .lock()
+                .unwrap()
+                .push((msg.to_string(), info.id()));
+        })
+        .build()
+        .unwrap();
+
+    let handle = runtime.spawn(async { panic!("async") });
+    let async_id = handle.id();
+    assert!(runtime.block_on(handle).unwrap_err().is_panic());
+    let handle = runtime.spawn_blocking(|| panic!("blocking"));
+    let blocking_id = handle.id();
+    assert!(runtime.block_on(handle).unwrap_err().is_panic());
+
+    let mut messages = messages.lock().unwrap().clone();
+    messages.sort();
+    assert_eq!(
+        messages,
+        vec![
+            (String::from("async"), async_id),
+            (String::from("blocking"), blocking_id)
+        ]
+    );
+
+    let handle = runtime.spawn(async { 1 });
+    assert_eq!(runtime.block_on(handle).unwrap(), 1);
+}
+
+/// SDV test cases for the `ShutdownRuntime` panic policy
+///
+/// # Brief
+/// 1. Create a multi-thread runtime with the `ShutdownRuntime` policy
+/// 2. Spawn a task that keeps yielding, and a task which panics
+/// 3. Check that the runtime gets shut down, and the yielding task gets
+///    canceled
+/// 4. Check that new tasks get rejected
+#[test]
+#[cfg(feature = "multi_instance_runtime")]
+fn sdv_task_panic_shutdown_runtime() {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering::{AcqRel, Acquire};
+    use std::sync::Arc;
+
+    use ylong_runtime::builder::{PanicPolicy, RuntimeBuilder};
+    use ylong_runtime::task::yield_now;
+
+    let hooked = Arc::new(AtomicUsize::new(0));
+    let hooked_clone = hooked.clone();
+    let runtime = RuntimeBuilder::new_multi_thread()
+        .worker_num(2)
+        .on_task_panic(PanicPolicy::ShutdownRuntime)
+        .task_panic_hook(move |_, _| {
+            hooked_clone.fetch_add(1, AcqRel);
+        })
+        .build()
+        .unwrap();
+
+    let running = runtime.spawn(async {
+        loop {
+            yield_now().await;
+        }
+    });
+    let handle = runtime.spawn(async { panic!("shutdown") });
+    assert!(runtime.block_on(handle).unwrap_err().is_panic());
+    assert_eq!(hooked.load(Acquire), 1);
+    assert_eq!(
+        runtime.block_on(running).unwrap_err().kind(),
+        ErrorKind::TaskCanceled
+    );
+
+    let handle = runtime.spawn(async { 1 });
+    assert_eq!(
+        runtime.block_on(handle).unwrap_err().kind(),
+        ErrorKind::RuntimeShutdown
+    );
+    let handle = runtime.spawn_blocking(|| 1);
+    assert_eq!(
+        runtime.block_on(handle).unwrap_err().kind(),
+        ErrorKind::RuntimeShutdown
+    );
+}
+
+/// SDV test cases for the `ShutdownRuntime` panic policy on a current-thread
+/// runtime
+///
+/// # Brief
+/// 1. Create a current-thread runtime with the `ShutdownRuntime` policy
+/// 2. Spawn a task which panics
+/// 3. Check that the runtime gets shut down, and new tasks get canceled
+#[test]
+#[cfg(feature = "current_thread_runtime")]
+fn sdv_task_panic_shutdown_current_thread() {
+    use ylong_runtime::builder::{PanicPolicy, RuntimeBuilder};
+
+    let runtime = RuntimeBuilder::new_current_thread()
+        .on_task_panic(PanicPolicy::ShutdownRuntime)
+        .build()
+        .unwrap();
+
+    let handle = runtime.spawn(async { panic!("shutdown") });
+    assert!(runtime.block_on(handle).unwrap_err().is_panic());
+
+    let handle = runtime.spawn(async { 1 });
+    assert!(runtime.block_on(handle).is_err());
+}
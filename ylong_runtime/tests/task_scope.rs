@@ -0,0 +1,135 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(all(feature = "time", not(feature = "ffrt")))]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use ylong_runtime::error::ErrorKind;
+use ylong_runtime::task::{self, yield_now};
+use ylong_runtime::time::{sleep, timeout};
+
+const TASK_NUM: usize = 100;
+
+/// SDV test cases for spawning tasks borrowing from the stack into a scope
+///
+/// # Brief
+/// 1. Creates a counter and a list on the stack of a task spawned onto the
+///    runtime
+/// 2. Spawns tasks into a scope that increase the counter and push into the
+///    list through references
+/// 3. Checks that all the tasks have finished once the scope returns
+/// 4. Checks that the stack values are usable again after the scope
+#[test]
+fn sdv_scope_borrow() {
+    let handle = ylong_runtime::spawn(async {
+        let counter = AtomicUsize::new(0);
+        let list = Mutex::new(Vec::new());
+        let counter_ref = &counter;
+        let list_ref = &list;
+        let res = task::scope(|s| async move {
+            for i in 0..TASK_NUM {
+                // drops the handle, the scope still waits for the task
+                s.spawn(async move {
+                    yield_now().await;
+                    counter_ref.fetch_add(1, Ordering::SeqCst);
+                    list_ref.lock().unwrap().push(i);
+                });
+            }
+            1
+        })
+        .await;
+        assert_eq!(res, 1);
+        assert_eq!(counter.load(Ordering::SeqCst), TASK_NUM);
+        let mut list = list.into_inner().unwrap();
+        list.sort();
+        list
+    });
+    let list = ylong_runtime::block_on(handle).unwrap();
+    assert_eq!(list, (0..TASK_NUM).collect::<Vec<_>>());
+}
+
+/// SDV test cases for joining the tasks of a scope
+///
+/// # Brief
+/// 1. Spawns tasks into a scope that compute on a borrowed slice
+/// 2. Awaits their `JoinHandle`s inside the body of the scope
+/// 3. Checks the results
+#[test]
+fn sdv_scope_join() {
+    let data: Vec<u64> = (1..=10).collect();
+    let slice = &data[..];
+    let res = ylong_runtime::block_on(task::scope(|s| async move {
+        let handles: Vec<_> = slice
+            .chunks(3)
+            .map(|chunk| s.spawn(async move { chunk.iter().sum::<u64>() }))
+            .collect();
+        let mut total = 0;
+        for handle in handles {
+            total += handle.await.unwrap();
+        }
+        total
+    }));
+    assert_eq!(res, 55);
+}
+
+struct DropFlag<'a>(&'a AtomicUsize);
+
+impl Drop for DropFlag<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+/// SDV test cases for dropping a scope before it completes
+///
+/// # Brief
+/// 1. Spawns tasks that sleep for a long time into a scope, each holding a
+///    guard borrowing a counter from the stack
+/// 2. Puts a timeout on the scope so that it gets dropped before completing
+/// 3. Checks that all the guards have been dropped once the timeout returns
+#[test]
+fn sdv_scope_drop_cancel() {
+    let dropped = AtomicUsize::new(0);
+    let dropped_ref = &dropped;
+    let res = ylong_runtime::block_on(async move {
+        let scope = task::scope(|s| async move {
+            for _ in 0..10 {
+                let guard = DropFlag(dropped_ref);
+                s.spawn(async move {
+                    let _guard = guard;
+                    sleep(Duration::from_secs(100)).await;
+                });
+            }
+        });
+        timeout(Duration::from_millis(50), scope).await
+    });
+    assert!(res.is_err());
+    assert_eq!(dropped.load(Ordering::SeqCst), 10);
+}
+
+/// SDV test cases for spawning onto a scope that has completed
+///
+/// # Brief
+/// 1. Returns a clone of the `Scope` out of the scope
+/// 2. Spawns a task through the clone after the scope completes
+/// 3. Checks that the task gets canceled
+#[test]
+fn sdv_scope_spawn_after_complete() {
+    let scope = ylong_runtime::block_on(task::scope(|s| async move { s.clone() }));
+    let handle = scope.spawn(async { 1 });
+    let err = ylong_runtime::block_on(handle).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::TaskCanceled);
+}
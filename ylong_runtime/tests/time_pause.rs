@@ -0,0 +1,140 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(all(
+    feature = "time",
+    feature = "current_thread_runtime",
+    not(feature = "ffrt")
+))]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use ylong_runtime::builder::RuntimeBuilder;
+use ylong_runtime::time;
+
+/// SDV test cases for sleeping on a runtime whose time starts paused
+///
+/// # Brief
+/// 1. Builds a current-thread runtime with `start_paused(true)`
+/// 2. Sleeps for an hour inside the runtime
+/// 3. Checks that the runtime's time has moved by an hour while the real time
+///    has barely moved
+#[test]
+fn sdv_start_paused_sleep() {
+    let runtime = RuntimeBuilder::new_current_thread()
+        .start_paused(true)
+        .build()
+        .unwrap();
+    let real_start = Instant::now();
+    runtime.block_on(async {
+        let start = time::now();
+        time::sleep(Duration::from_secs(3600)).await;
+        assert!(time::now() - start >= Duration::from_secs(3600));
+    });
+    assert!(real_start.elapsed() < Duration::from_secs(60));
+}
+
+/// SDV test cases for the auto-advance order of paused time
+///
+/// # Brief
+/// 1. Builds a current-thread runtime with `start_paused(true)`
+/// 2. Spawns tasks sleeping for different durations in reverse order
+/// 3. Checks that the tasks wake up in the order of their deadlines
+/// 4. Checks that a timeout on a pending future expires
+#[test]
+fn sdv_start_paused_order() {
+    let runtime = RuntimeBuilder::new_current_thread()
+        .start_paused(true)
+        .build()
+        .unwrap();
+    let order = Arc::new(Mutex::new(Vec::new()));
+    let mut handles = Vec::new();
+    for i in (1..=5).rev() {
+        let order = order.clone();
+        handles.push(runtime.spawn(async move {
+            time::sleep(Duration::from_secs(i * 10)).await;
+            order.lock().unwrap().push(i);
+        }));
+    }
+    runtime.block_on(async {
+        for handle in handles {
+            handle.await.unwrap();
+        }
+        let res = time::timeout(Duration::from_secs(60), std::future::pending::<()>()).await;
+        assert!(res.is_err());
+    });
+    assert_eq!(*order.lock().unwrap(), vec![1, 2, 3, 4, 5]);
+}
+
+/// SDV test cases for advancing the paused time manually
+///
+/// # Brief
+/// 1. Pauses the time inside a current-thread runtime
+/// 2. Spawns a task that sleeps for 10 seconds and yields to let it run
+/// 3. Advances the time by 5 seconds and checks that the task is still asleep
+/// 4. Advances the time by 5 seconds again and checks that the task has woken
+/// 5. Resumes the time and checks that it moves forward from where it was
+#[test]
+fn sdv_pause_and_advance() {
+    let runtime = RuntimeBuilder::new_current_thread().build().unwrap();
+    runtime.block_on(async {
+        time::pause();
+        let start = time::now();
+        let woken = Arc::new(AtomicUsize::new(0));
+        let woken_cpy = woken.clone();
+        let handle = ylong_runtime::executor::Handle::current().spawn(async move {
+            time::sleep(Duration::from_secs(10)).await;
+            woken_cpy.fetch_add(1, Ordering::SeqCst);
+        });
+        // lets the task register its sleep
+        ylong_runtime::task::yield_now().await;
+
+        time::advance(Duration::from_secs(5)).await;
+        assert_eq!(woken.load(Ordering::SeqCst), 0);
+        assert_eq!(time::now() - start, Duration::from_secs(5));
+
+        time::advance(Duration::from_secs(5)).await;
+        assert_eq!(woken.load(Ordering::SeqCst), 1);
+        handle.await.unwrap();
+
+        time::resume();
+        assert!(time::now() - start >= Duration::from_secs(10));
+    });
+}
+
+/// SDV test cases for a periodic timer under paused time
+///
+/// # Brief
+/// 1. Builds a current-thread runtime with `start_paused(true)`
+/// 2. Ticks a timer with a period of one minute for several times
+/// 3. Checks that every tick happens exactly one period after the last one
+#[test]
+fn sdv_start_paused_timer() {
+    let runtime = RuntimeBuilder::new_current_thread()
+        .start_paused(true)
+        .build()
+        .unwrap();
+    runtime.block_on(async {
+        let period = Duration::from_secs(60);
+        let mut timer = time::timer(period);
+        let mut last = timer.next_period().await;
+        for _ in 0..5 {
+            let next = timer.next_period().await;
+            assert_eq!(next - last, period);
+            assert!(time::now() >= last);
+            last = next;
+        }
+    });
+}
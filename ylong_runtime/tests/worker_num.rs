@@ -0,0 +1,130 @@
+// Copyright (c) 2023 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(all(not(feature = "ffrt"), feature = "multi_instance_runtime"))]
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering::{AcqRel, Acquire};
+use std::sync::{Arc, Barrier};
+
+use ylong_runtime::builder::RuntimeBuilder;
+use ylong_runtime::executor::Runtime;
+
+// Spawns tasks that block their workers until all of them are running at the
+// same time, so they could only finish if the runtime has enough workers.
+fn run_parallel(runtime: &Runtime, num: usize) {
+    let barrier = Arc::new(Barrier::new(num));
+    let handles: Vec<_> = (0..num)
+        .map(|_| {
+            let barrier = barrier.clone();
+            runtime.spawn(async move {
+                barrier.wait();
+            })
+        })
+        .collect();
+    for handle in handles {
+        runtime.block_on(handle).unwrap();
+    }
+}
+
+/// SDV test cases for `Runtime::set_worker_num`
+///
+/// # Brief
+/// 1. Create a runtime with one worker, which could grow to four workers
+/// 2. Grow the runtime to four workers, check that four tasks could run in
+///    parallel and three new threads are spawned
+/// 3. Shrink the runtime to one worker, check that tasks still get executed
+/// 4. Grow the runtime again, check that the retired workers get reused
+/// 5. Set a number out of the bound, check the returned value
+#[test]
+fn sdv_set_worker_num() {
+    let started = Arc::new(AtomicUsize::new(0));
+    let started_clone = started.clone();
+    let runtime = RuntimeBuilder::new_multi_thread()
+        .worker_num(1)
+        .max_worker_num(4)
+        .after_start(move || {
+            started_clone.fetch_add(1, AcqRel);
+        })
+        .build()
+        .unwrap();
+
+    assert_eq!(runtime.set_worker_num(4).unwrap(), 4);
+    run_parallel(&runtime, 4);
+    assert_eq!(started.load(Acquire), 4);
+
+    assert_eq!(runtime.set_worker_num(1).unwrap(), 1);
+    let handles: Vec<_> = (0..100).map(|i| runtime.spawn(async move { i })).collect();
+    for (i, handle) in handles.into_iter().enumerate() {
+        assert_eq!(runtime.block_on(handle).unwrap(), i);
+    }
+
+    assert_eq!(runtime.set_worker_num(4).unwrap(), 4);
+    run_parallel(&runtime, 4);
+    assert_eq!(started.load(Acquire), 4);
+
+    assert_eq!(runtime.set_worker_num(0).unwrap(), 1);
+    assert_eq!(runtime.set_worker_num(100).unwrap(), 4);
+}
+
+/// SDV test cases for `Runtime::set_worker_num` after shutdown
+///
+/// # Brief
+/// 1. Create a runtime and shut it down
+/// 2. Check that setting the worker number fails
+#[test]
+fn sdv_set_worker_num_after_shutdown() {
+    let runtime = RuntimeBuilder::new_multi_thread()
+        .worker_num(1)
+        .max_worker_num(2)
+        .build()
+        .unwrap();
+    runtime.shutdown_background();
+    assert!(runtime.set_worker_num(2).is_err());
+}
+
+/// SDV test cases for auto-scaling
+///
+/// # Brief
+/// 1. Create a runtime with one worker, which scales between one and four
+///    workers
+/// 2. Spawn four tasks which could only finish when running in parallel
+/// 3. Check that the runtime grows to finish the tasks
+/// 4. Keep the runtime idle, check that it shrinks back to one worker
+#[test]
+fn sdv_auto_scale() {
+    let runtime = RuntimeBuilder::new_multi_thread()
+        .worker_num(1)
+        .auto_scale(1, 4)
+        .build()
+        .unwrap();
+
+    run_parallel(&runtime, 4);
+
+    #[cfg(feature = "metrics")]
+    {
+        use std::time::Duration;
+
+        let metrics = runtime.metrics();
+        assert!(metrics.workers_num() > 1);
+        let mut shrunk = false;
+        for _ in 0..100 {
+            if metrics.workers_num() == 1 {
+                shrunk = true;
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(100));
+        }
+        assert!(shrunk);
+    }
+}